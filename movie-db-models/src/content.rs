@@ -0,0 +1,211 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+// Facteur appliqué au poids IDF de chaque type de caractéristique : les termes du résumé,
+// plus bruités, comptent moins que les genres et les mots-clés
+pub const GENRE_FACTOR: f64 = 1.0;
+pub const KEYWORD_FACTOR: f64 = 1.0;
+pub const TERM_FACTOR: f64 = 0.5;
+// Les termes du résumé présents dans une plus grande part des films sont ignorés
+pub const MAX_TERM_SHARE: f64 = 0.05;
+// Longueur minimale d'un terme du résumé
+pub const MIN_TERM_LENGTH: usize = 3;
+
+// Mots trop courants pour caractériser un film
+const STOP_WORDS: &[&str] = &[
+    "about", "after", "again", "against", "all", "also", "and", "any", "are", "around", "because", "been", "before",
+    "being", "between", "both", "but", "can", "could", "did", "does", "down", "during", "each", "even", "every", "for",
+    "from", "further", "had", "has", "have", "her", "here", "hers", "herself", "him", "himself", "his", "how", "into",
+    "its", "itself", "just", "more", "most", "not", "now", "off", "once", "one", "only", "other", "our", "out", "over",
+    "own", "same", "she", "should", "some", "such", "than", "that", "the", "their", "them", "themselves", "then", "there",
+    "these", "they", "this", "those", "through", "too", "two", "under", "until", "very", "was", "were", "what", "when",
+    "where", "which", "while", "who", "whom", "why", "will", "with", "would", "you", "your",
+];
+
+/// Film lu pour le calcul : `(tmdb_id, genres, keywords, overview)`
+pub type MovieContent = (i32, Option<String>, Option<String>, Option<String>);
+
+/// Caractéristique de contenu : genre ('G'), mot-clé ('K') ou terme du résumé ('T')
+pub struct ContentFeature {
+    pub kind: &'static str,
+    pub name: String,
+    pub movie_count: u32,
+    pub weight: f64,
+}
+
+/// Vecteurs de contenu des films : les identifiants des caractéristiques sont leur rang + 1
+pub struct ContentIndex {
+    pub features: Vec<ContentFeature>,
+    pub links: Vec<(i32, u32)>,
+    pub norms: Vec<(i32, f64)>,
+}
+
+impl ContentIndex {
+    /// Construit les vecteurs à partir de `(tmdb_id, genres, keywords, overview)`.
+    /// Chaque caractéristique pèse son IDF `ln(N / df)`, multiplié par le facteur de son type.
+    pub fn build(movies: &[MovieContent]) -> Self {
+        let movie_features: Vec<(i32, BTreeSet<(&'static str, String)>)> = movies
+            .iter()
+            .map(|(tmdb_id, genres, keywords, overview)| {
+                let mut features = BTreeSet::new();
+                for name in list_values(genres) {
+                    features.insert(("G", name));
+                }
+                for name in list_values(keywords) {
+                    features.insert(("K", name));
+                }
+                for term in terms(overview.as_deref().unwrap_or("")) {
+                    features.insert(("T", term));
+                }
+                (*tmdb_id, features)
+            })
+            .collect();
+
+        let mut counts: BTreeMap<(&'static str, String), u32> = BTreeMap::new();
+        for (_, features) in &movie_features {
+            for feature in features {
+                *counts.entry(feature.clone()).or_default() += 1;
+            }
+        }
+
+        // Une caractéristique d'un seul film ne rapproche aucun film
+        let total = movies.len() as f64;
+        let mut ids = BTreeMap::new();
+        let mut features = Vec::new();
+        for ((kind, name), movie_count) in counts {
+            if movie_count < 2 || (kind == "T" && movie_count as f64 > MAX_TERM_SHARE * total) {
+                continue;
+            }
+
+            let factor = match kind {
+                "G" => GENRE_FACTOR,
+                "K" => KEYWORD_FACTOR,
+                _ => TERM_FACTOR,
+            };
+            let weight = (factor * (total / movie_count as f64).ln() * 1e6).round() / 1e6;
+
+            ids.insert((kind, name.clone()), features.len() as u32 + 1);
+            features.push(ContentFeature {
+                kind,
+                name,
+                movie_count,
+                weight,
+            });
+        }
+
+        let mut links = Vec::new();
+        let mut norms = Vec::new();
+        for (tmdb_id, movie) in movie_features {
+            let mut total_weight = 0.0;
+            for feature in &movie {
+                if let Some(&feature_id) = ids.get(feature) {
+                    links.push((tmdb_id, feature_id));
+                    total_weight += features[feature_id as usize - 1].weight;
+                }
+            }
+            if total_weight > 0.0 {
+                norms.push((tmdb_id, total_weight));
+            }
+        }
+
+        Self { features, links, norms }
+    }
+}
+
+/// Valeurs distinctes d'une liste séparée par des virgules
+fn list_values(list: &Option<String>) -> Vec<String> {
+    list.as_deref()
+        .unwrap_or("")
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Termes distincts d'un résumé : minuscules, découpage sur la ponctuation,
+/// mots courts, nombres et mots vides ignorés
+pub fn terms(text: &str) -> BTreeSet<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= MIN_TERM_LENGTH)
+        .filter(|word| !word.chars().all(|c| c.is_numeric()))
+        .filter(|word| !STOP_WORDS.contains(word))
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn movie(tmdb_id: i32, genres: &str, keywords: &str, overview: &str) -> MovieContent {
+        (tmdb_id, Some(genres.to_string()), Some(keywords.to_string()), Some(overview.to_string()))
+    }
+
+    fn weight(index: &ContentIndex, kind: &str, name: &str) -> Option<f64> {
+        index
+            .features
+            .iter()
+            .find(|feature| feature.kind == kind && feature.name == name)
+            .map(|feature| feature.weight)
+    }
+
+    #[test]
+    fn two_movie_corpus_keeps_only_shared_features_without_weight() {
+        let index = ContentIndex::build(&[
+            movie(1, "Action, Drama", "robot, space", "A robot travels through space."),
+            movie(2, "Action", "robot", "A robot dreams."),
+        ]);
+
+        // Les caractéristiques d'un seul film sont ignorées ; celles des deux films pèsent ln(2 / 2)
+        let names: Vec<(&str, &str)> = index.features.iter().map(|f| (f.kind, f.name.as_str())).collect();
+        assert_eq!(names, [("G", "Action"), ("K", "robot")]);
+        assert!(index.features.iter().all(|f| f.movie_count == 2 && f.weight == 0.0));
+        assert_eq!(index.links, [(1, 1), (1, 2), (2, 1), (2, 2)]);
+
+        // Aucun film n'a de poids : aucun n'est rapproché par son contenu
+        assert!(index.norms.is_empty());
+    }
+
+    #[test]
+    fn weights_are_the_idf_of_each_feature() {
+        let index = ContentIndex::build(&[
+            movie(1, "Action, Drama", "robot", ""),
+            movie(2, "Action", "robot", ""),
+            movie(3, "Drama", "", ""),
+            movie(4, "Action", "", ""),
+        ]);
+
+        assert_eq!(weight(&index, "G", "Action"), Some(((4.0f64 / 3.0).ln() * 1e6).round() / 1e6));
+        assert_eq!(weight(&index, "G", "Drama"), Some((2f64.ln() * 1e6).round() / 1e6));
+        assert_eq!(weight(&index, "K", "robot"), Some((2f64.ln() * 1e6).round() / 1e6));
+
+        let norm = |tmdb_id: i32| index.norms.iter().find(|&&(id, _)| id == tmdb_id).map(|&(_, norm)| norm);
+        let (action, drama, robot) = (
+            weight(&index, "G", "Action").unwrap(),
+            weight(&index, "G", "Drama").unwrap(),
+            weight(&index, "K", "robot").unwrap(),
+        );
+        assert_eq!(norm(1), Some(action + drama + robot));
+        assert_eq!(norm(3), Some(drama));
+    }
+
+    #[test]
+    fn overview_terms_are_weighted_less_and_common_terms_ignored() {
+        // Un terme de 2 films sur 40 atteint tout juste MAX_TERM_SHARE
+        let mut movies: Vec<MovieContent> = (3..=40).map(|tmdb_id| movie(tmdb_id, "", "", "")).collect();
+        movies.push(movie(1, "", "", "The young wizard"));
+        movies.push(movie(2, "", "", "An old wizard"));
+        let index = ContentIndex::build(&movies);
+        assert_eq!(weight(&index, "T", "wizard"), Some((TERM_FACTOR * 20f64.ln() * 1e6).round() / 1e6));
+
+        movies.push(movie(41, "", "", "Another wizard"));
+        assert_eq!(weight(&ContentIndex::build(&movies), "T", "wizard"), None);
+    }
+
+    #[test]
+    fn terms_skip_short_words_numbers_and_stop_words() {
+        let terms: Vec<String> = terms("The Robot's 2049 journey, THROUGH space: a robot AI").into_iter().collect();
+        assert_eq!(terms, ["journey", "robot", "space"]);
+    }
+}
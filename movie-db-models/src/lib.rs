@@ -0,0 +1,6 @@
+//! Modèles calculés à partir des données MovieLens et TMDB, partagés par le script de migration
+//! (base Oracle) et l'application (base SQLite embarquée)
+
+pub mod content;
+pub mod recommendation;
+pub mod similarity;
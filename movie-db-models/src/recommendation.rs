@@ -0,0 +1,251 @@
+// Version de l'algorithme d'entraînement, à incrémenter dès qu'il change
+pub const MODEL_VERSION: u32 = 1;
+// Nombre de facteurs latents par utilisateur et par film
+pub const FACTOR_COUNT: usize = 20;
+// Nombre de passes sur l'ensemble des notes
+pub const EPOCHS: usize = 20;
+pub const LEARNING_RATE: f64 = 0.01;
+// Régularisation L2 des biais et des facteurs
+pub const REGULARIZATION: f64 = 0.05;
+// Graine du générateur : deux entraînements sur les mêmes notes produisent le même modèle
+pub const SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Factorisation matricielle biaisée, entraînée par descente de gradient stochastique :
+/// note prédite = moyenne globale + biais utilisateur + biais film + facteurs utilisateur · facteurs film
+pub struct FactorModel {
+    pub global_mean: f64,
+    pub user_ids: Vec<i32>,
+    pub user_factors: Vec<Factors>,
+    pub movie_ids: Vec<i32>,
+    pub movie_factors: Vec<Factors>,
+    /// RMSE d'entraînement à la fin de chaque époque
+    pub training_rmse: Vec<f64>,
+}
+
+/// Biais et facteurs latents d'un utilisateur ou d'un film
+pub struct Factors {
+    pub bias: f64,
+    pub values: Vec<f64>,
+}
+
+impl Factors {
+    /// Lit la colonne `factors` (valeurs séparées par des virgules) de User_Factors ou Movie_Factors
+    pub fn parse(bias: f64, factors: &str) -> Self {
+        Self {
+            bias,
+            values: factors.split(',').filter_map(|value| value.trim().parse().ok()).collect(),
+        }
+    }
+
+    /// Format de la colonne `factors`
+    pub fn format(&self) -> String {
+        self.values.iter().map(|value| format!("{:.6}", value)).collect::<Vec<_>>().join(",")
+    }
+}
+
+impl FactorModel {
+    /// Entraîne le modèle sur les notes `(user_id, movie_id, rating)`
+    pub fn train(ratings: &[(i32, i32, f64)]) -> Self {
+        let user_ids = distinct_ids(ratings.iter().map(|&(user_id, _, _)| user_id));
+        let movie_ids = distinct_ids(ratings.iter().map(|&(_, movie_id, _)| movie_id));
+
+        let global_mean = match ratings.len() {
+            0 => 0.0,
+            count => ratings.iter().map(|&(_, _, rating)| rating).sum::<f64>() / count as f64,
+        };
+
+        // Facteurs initialisés au hasard autour de 0, biais à 0
+        let mut rng = Rng(SEED);
+        let mut user_factors: Vec<Factors> = user_ids.iter().map(|_| rng.factors()).collect();
+        let mut movie_factors: Vec<Factors> = movie_ids.iter().map(|_| rng.factors()).collect();
+
+        let mut samples: Vec<(usize, usize, f64)> = ratings
+            .iter()
+            .map(|&(user_id, movie_id, rating)| {
+                (
+                    user_ids.binary_search(&user_id).unwrap(),
+                    movie_ids.binary_search(&movie_id).unwrap(),
+                    rating,
+                )
+            })
+            .collect();
+
+        let mut training_rmse = Vec::new();
+        for _ in 0..EPOCHS {
+            rng.shuffle(&mut samples);
+
+            let mut squared_error = 0.0;
+            for &(user, movie, rating) in &samples {
+                let (user, movie) = (&mut user_factors[user], &mut movie_factors[movie]);
+                let error = rating - predict(global_mean, Some(user), movie);
+                squared_error += error * error;
+
+                user.bias += LEARNING_RATE * (error - REGULARIZATION * user.bias);
+                movie.bias += LEARNING_RATE * (error - REGULARIZATION * movie.bias);
+
+                for (p, q) in user.values.iter_mut().zip(movie.values.iter_mut()) {
+                    let (old_p, old_q) = (*p, *q);
+                    *p += LEARNING_RATE * (error * old_q - REGULARIZATION * old_p);
+                    *q += LEARNING_RATE * (error * old_p - REGULARIZATION * old_q);
+                }
+            }
+
+            if !samples.is_empty() {
+                training_rmse.push((squared_error / samples.len() as f64).sqrt());
+            }
+        }
+
+        Self {
+            global_mean,
+            user_ids,
+            user_factors,
+            movie_ids,
+            movie_factors,
+            training_rmse,
+        }
+    }
+
+    /// Note prédite, bornée à l'échelle MovieLens ; un utilisateur ou un film absent
+    /// de l'entraînement n'apporte aucun terme
+    pub fn predict(&self, user_id: i32, movie_id: i32) -> f64 {
+        let user = self.user_ids.binary_search(&user_id).ok().map(|user| &self.user_factors[user]);
+        let prediction = match self.movie_ids.binary_search(&movie_id) {
+            Ok(movie) => predict(self.global_mean, user, &self.movie_factors[movie]),
+            Err(_) => self.global_mean + user.map(|user| user.bias).unwrap_or(0.0),
+        };
+        prediction.clamp(0.5, 5.0)
+    }
+
+    /// Erreur quadratique moyenne des prédictions sur les notes données
+    pub fn rmse(&self, ratings: &[(i32, i32, f64)]) -> f64 {
+        if ratings.is_empty() {
+            return 0.0;
+        }
+
+        let squared_error: f64 = ratings
+            .iter()
+            .map(|&(user_id, movie_id, rating)| (rating - self.predict(user_id, movie_id)).powi(2))
+            .sum();
+        (squared_error / ratings.len() as f64).sqrt()
+    }
+}
+
+/// Note prédite avant bornage ; sans facteurs utilisateur, seul le biais du film est pris en compte
+pub fn predict(global_mean: f64, user: Option<&Factors>, movie: &Factors) -> f64 {
+    match user {
+        Some(user) => global_mean + user.bias + movie.bias + dot(&user.values, &movie.values),
+        None => global_mean + movie.bias,
+    }
+}
+
+fn distinct_ids(ids: impl Iterator<Item = i32>) -> Vec<i32> {
+    let mut ids: Vec<i32> = ids.collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Générateur xorshift64* : reproductible d'une exécution à l'autre
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Nombre uniforme dans [0, 1)
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn factors(&mut self) -> Factors {
+        Factors {
+            bias: 0.0,
+            values: (0..FACTOR_COUNT).map(|_| (self.unit() - 0.5) * 0.1).collect(),
+        }
+    }
+
+    /// Mélange de Fisher-Yates
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = (self.next() % (i as u64 + 1)) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Notes de 20 utilisateurs sur 20 films : biais de l'utilisateur et du film,
+    /// plus un écart selon que leurs groupes de goût coïncident
+    fn ratings() -> Vec<(i32, i32, f64)> {
+        let mut ratings = Vec::new();
+        for user_id in 1..=20 {
+            for movie_id in 1..=20 {
+                let taste = if (user_id % 2) == (movie_id % 2) { 0.5 } else { -0.5 };
+                let rating = 1.5 + 0.1 * (user_id % 5) as f64 + 0.2 * (movie_id % 5) as f64 + taste;
+                ratings.push((user_id, movie_id, rating));
+            }
+        }
+        ratings
+    }
+
+    #[test]
+    fn training_lowers_the_error() {
+        let ratings = ratings();
+        let model = FactorModel::train(&ratings);
+
+        assert_eq!(model.training_rmse.len(), EPOCHS);
+        assert!(model.training_rmse.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert!(model.training_rmse.last() < model.training_rmse.first());
+
+        // Meilleur qu'une prédiction constante égale à la moyenne globale
+        let baseline = (ratings.iter().map(|&(_, _, rating)| (rating - model.global_mean).powi(2)).sum::<f64>()
+            / ratings.len() as f64)
+            .sqrt();
+        assert!(model.rmse(&ratings) < 0.9 * baseline);
+    }
+
+    #[test]
+    fn training_is_reproducible() {
+        let first = FactorModel::train(&ratings());
+        let second = FactorModel::train(&ratings());
+
+        assert_eq!(first.training_rmse, second.training_rmse);
+        let factors = |model: &FactorModel| {
+            let all = model.user_factors.iter().chain(&model.movie_factors);
+            all.map(|factors| (factors.bias, factors.values.clone())).collect::<Vec<_>>()
+        };
+        assert_eq!(factors(&first), factors(&second));
+    }
+
+    #[test]
+    fn unknown_ids_fall_back_to_biases() {
+        let model = FactorModel::train(&ratings());
+
+        assert_eq!(model.predict(99, 99), model.global_mean);
+        let movie = &model.movie_factors[0];
+        assert_eq!(model.predict(99, 1), (model.global_mean + movie.bias).clamp(0.5, 5.0));
+        assert!(FactorModel::train(&[]).training_rmse.is_empty());
+    }
+
+    #[test]
+    fn factors_column_round_trips() {
+        let factors = Factors {
+            bias: 0.25,
+            values: vec![0.5, -0.125, 1.0],
+        };
+
+        assert_eq!(factors.format(), "0.500000,-0.125000,1.000000");
+        assert_eq!(Factors::parse(0.25, &factors.format()).values, factors.values);
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+
+// Nombre de voisins conservés par film
+pub const NEIGHBOUR_COUNT: usize = 20;
+// Nombre minimal d'utilisateurs ayant noté les deux films
+pub const MIN_CO_RATINGS: u32 = 2;
+// Atténue la similarité des paires notées par peu d'utilisateurs : s * n / (n + SHRINKAGE)
+pub const SHRINKAGE: f64 = 10.0;
+// Les utilisateurs ayant noté davantage de films sont ignorés : leur coût est quadratique
+pub const MAX_USER_RATINGS: usize = 2000;
+
+/// Ligne de la table `Movie_Similarities`
+pub struct MovieNeighbour {
+    pub movie_id: i32,
+    pub similar_movie_id: i32,
+    pub rank: u32,
+    pub similarity: f64,
+    pub co_ratings: u32,
+}
+
+/// Voisins de chaque film à partir des notes `(user_id, movie_id, rating)` : similarité cosinus ajustée
+/// (notes centrées sur la moyenne de chaque utilisateur) sur les utilisateurs ayant noté les deux films.
+/// Le calcul ne dépend que des notes : deux exécutions sur les mêmes données produisent les mêmes voisins
+pub fn movie_neighbours(ratings: &[(i32, i32, f64)]) -> Vec<MovieNeighbour> {
+    // Notes regroupées par utilisateur, dans l'ordre des identifiants
+    let mut by_user: BTreeMap<i32, Vec<(i32, f64)>> = BTreeMap::new();
+    for &(user_id, movie_id, rating) in ratings {
+        by_user.entry(user_id).or_default().push((movie_id, rating));
+    }
+
+    let mut movie_ids: Vec<i32> = ratings.iter().map(|&(_, movie_id, _)| movie_id).collect();
+    movie_ids.sort_unstable();
+    movie_ids.dedup();
+    let index_of = |movie_id: i32| movie_ids.binary_search(&movie_id).unwrap();
+
+    let mut users: Vec<Vec<(usize, f64)>> = Vec::new();
+    let mut by_movie: Vec<Vec<(usize, f64)>> = vec![Vec::new(); movie_ids.len()];
+    for mut movies in by_user.into_values() {
+        if movies.len() > MAX_USER_RATINGS {
+            continue;
+        }
+
+        movies.sort_by_key(|&(movie_id, _)| movie_id);
+        let mean = movies.iter().map(|&(_, rating)| rating).sum::<f64>() / movies.len() as f64;
+        let centered: Vec<(usize, f64)> = movies
+            .iter()
+            .map(|&(movie_id, rating)| (index_of(movie_id), rating - mean))
+            .collect();
+
+        for &(movie, value) in &centered {
+            by_movie[movie].push((users.len(), value));
+        }
+        users.push(centered);
+    }
+
+    // Sommes accumulées pour chaque film voisin du film courant
+    let mut dot = vec![0.0; movie_ids.len()];
+    let mut own_norm = vec![0.0; movie_ids.len()];
+    let mut other_norm = vec![0.0; movie_ids.len()];
+    let mut co_ratings = vec![0u32; movie_ids.len()];
+    let mut touched = Vec::new();
+
+    let mut neighbours = Vec::new();
+    for (movie, raters) in by_movie.iter().enumerate() {
+        for &(user, a) in raters {
+            for &(other, b) in &users[user] {
+                if other == movie {
+                    continue;
+                }
+                if co_ratings[other] == 0 {
+                    touched.push(other);
+                }
+                dot[other] += a * b;
+                own_norm[other] += a * a;
+                other_norm[other] += b * b;
+                co_ratings[other] += 1;
+            }
+        }
+
+        let mut candidates = Vec::new();
+        for &other in &touched {
+            let count = co_ratings[other];
+            let norm = (own_norm[other] * other_norm[other]).sqrt();
+            if count >= MIN_CO_RATINGS && norm > 0.0 {
+                let similarity = dot[other] / norm * count as f64 / (count as f64 + SHRINKAGE);
+                if similarity > 0.0 {
+                    candidates.push((other, (similarity * 1e6).round() / 1e6, count));
+                }
+            }
+
+            dot[other] = 0.0;
+            own_norm[other] = 0.0;
+            other_norm[other] = 0.0;
+            co_ratings[other] = 0;
+        }
+        touched.clear();
+
+        // Les égalités sont départagées par l'identifiant : le résultat ne dépend pas de l'ordre de lecture
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        candidates.truncate(NEIGHBOUR_COUNT);
+
+        for (rank, (other, similarity, count)) in candidates.into_iter().enumerate() {
+            neighbours.push(MovieNeighbour {
+                movie_id: movie_ids[movie],
+                similar_movie_id: movie_ids[other],
+                rank: rank as u32 + 1,
+                similarity,
+                co_ratings: count,
+            });
+        }
+    }
+
+    neighbours
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Les films 10 et 20 ont les mêmes notes centrées chez les trois utilisateurs,
+    /// le film 30 varie en sens inverse
+    const RATINGS: &[(i32, i32, f64)] = &[
+        (1, 10, 5.0),
+        (1, 20, 5.0),
+        (1, 30, 1.0),
+        (2, 10, 4.0),
+        (2, 20, 4.0),
+        (2, 30, 1.0),
+        (3, 10, 2.0),
+        (3, 20, 2.0),
+        (3, 30, 5.0),
+    ];
+
+    fn similarity(neighbours: &[MovieNeighbour], movie_id: i32, similar_movie_id: i32) -> Option<f64> {
+        neighbours
+            .iter()
+            .find(|n| n.movie_id == movie_id && n.similar_movie_id == similar_movie_id)
+            .map(|n| n.similarity)
+    }
+
+    #[test]
+    fn similarity_is_symmetric_and_shrunk() {
+        let neighbours = movie_neighbours(RATINGS);
+
+        // Cosinus de 1 sur 3 utilisateurs communs, atténué en 3 / (3 + SHRINKAGE)
+        let expected = (3.0 / (3.0 + SHRINKAGE) * 1e6).round() / 1e6;
+        assert_eq!(similarity(&neighbours, 10, 20), Some(expected));
+        assert_eq!(similarity(&neighbours, 20, 10), Some(expected));
+        assert!(neighbours.iter().all(|n| n.rank == 1 && n.co_ratings == 3));
+
+        // Les similarités négatives ne sont pas conservées
+        assert!(neighbours.iter().all(|n| n.movie_id != 30 && n.similar_movie_id != 30));
+        assert_eq!(neighbours.len(), 2);
+    }
+
+    #[test]
+    fn pairs_rated_by_too_few_users_are_ignored() {
+        // MIN_CO_RATINGS utilisateurs communs suffisent, un seul ne suffit pas
+        let ratings: Vec<(i32, i32, f64)> = RATINGS.iter().copied().filter(|&(user_id, _, _)| user_id != 3).collect();
+        assert!(!movie_neighbours(&ratings).is_empty());
+
+        let ratings: Vec<(i32, i32, f64)> = RATINGS.iter().copied().filter(|&(user_id, _, _)| user_id == 1).collect();
+        assert!(movie_neighbours(&ratings).is_empty());
+    }
+
+    #[test]
+    fn neighbours_do_not_depend_on_the_reading_order() {
+        let mut reversed = RATINGS.to_vec();
+        reversed.reverse();
+
+        let key = |n: &MovieNeighbour| (n.movie_id, n.similar_movie_id, n.rank, n.similarity, n.co_ratings);
+        let expected: Vec<_> = movie_neighbours(RATINGS).iter().map(key).collect();
+        assert_eq!(movie_neighbours(&reversed).iter().map(key).collect::<Vec<_>>(), expected);
+    }
+}
@@ -5,8 +5,8 @@ use std::{collections::HashSet, env, fs::File};
 use csv::ReaderBuilder;
 
 use oracle::Connection;
-use tables::{link::Link, movie::Movie, rating::Rating, tag::Tag, user::User};
-use user::{role::create_roles, tablespace::create_tablespace, users_service::{create_user, create_users_records_table, create_users_service}};
+use tables::{content::ContentFeatures, link::Link, lookup::{Lookup, LookupValues, COMPANIES, COUNTRIES, GENRES, KEYWORDS, LANGUAGES}, movie::Movie, rating::Rating, recommendation::RatingModel, similarity::MovieSimilarity, tag::Tag, title_trigram::TitleTrigram, trash::Trash, user::User, weighted_rating::WeightedRating};
+use user::{audit::{create_audit_log_table, create_audit_procedure}, role::create_roles, tablespace::create_tablespace, users_service::{create_user, create_users_records_table, create_users_service}};
 
 const DATASET_FOLDER: &str = "./dataset";
 
@@ -25,12 +25,38 @@ fn main() -> Result<(), oracle::Error> {
     let conn = Connection::connect(username, password, host)
         .expect("Impossible de se connecter à la base de données");
 
+    // Traitements hors ligne sur une base déjà migrée :
+    // `cargo run -- similarities` recalcule les voisins des films à partir des notes,
+    // `cargo run -- recommendations` réentraîne le modèle de recommandation,
+    // `cargo run -- evaluate` mesure le RMSE du modèle sur des notes mises de côté,
+    // `cargo run -- content` recalcule les caractéristiques de contenu des films,
+    // `cargo run -- weighted-ratings` recalcule le score pondéré du classement « Top Rated »
+    let job = match env::args().nth(1).as_deref() {
+        Some("similarities") => Some(MovieSimilarity::compute(&conn)),
+        Some("content") => Some(ContentFeatures::compute(&conn)),
+        Some("recommendations") => Some(RatingModel::train(&conn)),
+        Some("evaluate") => Some(RatingModel::evaluate(&conn)),
+        Some("weighted-ratings") => Some(WeightedRating::from_env().refresh(&conn)),
+        _ => None,
+    };
+    if let Some(result) = job {
+        result?;
+        conn.close()?;
+
+        println!("Le traitement s'est terminé avec succès !");
+        return Ok(());
+    }
+
     // Création du tablespace
     create_tablespace(&conn)?;
 
     // Creation des roles
     create_roles(&conn)?;
 
+    // Journal des actions d'administration, utilisé dès la création des utilisateurs
+    create_audit_log_table(&conn)?;
+    create_audit_procedure(&conn)?;
+
     // Creation records user
     create_users_records_table(&conn)?;
     create_users_service(&conn)?;
@@ -44,8 +70,24 @@ fn main() -> Result<(), oracle::Error> {
     let mut seen_users: HashSet<i32> = HashSet::new();
 
     // Movie table
+    let mut lookups: Vec<(_, LookupValues)> = [GENRES, KEYWORDS, COMPANIES, COUNTRIES, LANGUAGES]
+        .into_iter()
+        .map(|lookup| (lookup, LookupValues::default()))
+        .collect();
     Movie::create_table(&conn)?;
-    insert_movies(&conn)?;
+    insert_movies(&conn, &mut lookups)?;
+    Movie::create_text_index(&conn)?;
+
+    // Tables de référence : genres, mots-clés, sociétés, pays et langues
+    for (lookup, values) in &lookups {
+        lookup.create_table(&conn)?;
+        lookup.insert(&conn, values)?;
+    }
+
+    // Index des trigrammes des titres (recherche approchée)
+    TitleTrigram::create_table(&conn)?;
+    TitleTrigram::create_procedure(&conn)?;
+    TitleTrigram::index_movies(&conn)?;
 
     // Rating table
     Rating::create_table(&conn)?;
@@ -54,6 +96,7 @@ fn main() -> Result<(), oracle::Error> {
     // Tag table
     Tag::create_table(&conn)?;
     insert_tags(&conn, &mut seen_users)?;
+    Tag::create_moderation_table(&conn)?;
 
     // Users table
     User::create_table(&conn)?;
@@ -62,6 +105,27 @@ fn main() -> Result<(), oracle::Error> {
     // Link table
     Link::create_table(&conn)?;
     insert_links(&conn)?;
+
+    // Films similaires d'après les notes (calculés après le chargement des notes et des liens)
+    MovieSimilarity::create_table(&conn)?;
+    MovieSimilarity::compute(&conn)?;
+
+    // Caractéristiques de contenu (genres, mots-clés, termes du résumé) des films MovieLens
+    ContentFeatures::create_table(&conn)?;
+    ContentFeatures::compute(&conn)?;
+
+    // Modèle de recommandation par facteurs latents, entraîné sur toutes les notes
+    RatingModel::create_table(&conn)?;
+    RatingModel::train(&conn)?;
+
+    // Score pondéré des films (moyenne bayésienne), paramétrable dans le fichier .env
+    WeightedRating::create_table(&conn)?;
+    WeightedRating::create_procedure(&conn)?;
+    WeightedRating::from_env().refresh(&conn)?;
+
+    // Corbeille des suppressions MovieLens, alimentée par les triggers de suppression en cascade
+    Trash::create_table(&conn)?;
+    Trash::create_procedure(&conn)?;
     
     create_triggers(&conn)?;
 
@@ -75,7 +139,7 @@ fn main() -> Result<(), oracle::Error> {
     Ok(())
 }
 
-fn insert_movies(conn: &Connection) -> Result<(), oracle::Error> {
+fn insert_movies(conn: &Connection, lookups: &mut [(Lookup, LookupValues)]) -> Result<(), oracle::Error> {
     println!("Préparation de l'insertion des films...");
     // Insertion des données : TMDB_movie_dataset_v11.csv
     let file_path = format!("{}/TMDB_movie_dataset_v11.csv", DATASET_FOLDER);
@@ -131,6 +195,18 @@ fn insert_movies(conn: &Connection) -> Result<(), oracle::Error> {
                     eprintln!("Erreur lors de l'insertion du film {} : {}", movie.id, res.err().unwrap());
                     continue;
                 }
+
+                // Listes insérées ensuite dans les tables de référence, dans l'ordre de `lookups`
+                let lists = [
+                    &movie.genres,
+                    &movie.keywords,
+                    &movie.production_companies,
+                    &movie.production_countries,
+                    &movie.spoken_languages,
+                ];
+                for ((_, values), list) in lookups.iter_mut().zip(lists) {
+                    values.add(movie.id, list);
+                }
             }
             Err(e) => {
                 eprintln!("Erreur lors de la lecture du CSV : {:?}", e);
@@ -328,10 +404,10 @@ fn create_views(conn: &Connection) -> Result<(), oracle::Error> {
             tmd.backdrop_path,
             tmd.overview,
             tmd.poster_path,
-            tmd.genres,
             tmd.keywords,
             tmd.budget,
-            tmd.revenue
+            tmd.revenue,
+            tmd.row_version
         FROM 
             TMDB_movie_dataset tmd
         JOIN 
@@ -346,6 +422,7 @@ fn create_views(conn: &Connection) -> Result<(), oracle::Error> {
     println!("Création de la vue MovieShortView...");
     // Création de la vue MovieShortView
     // Contient les informations essentielles des films
+    // Les colonnes de filtre servent à la recherche avancée (SearchQuery) et au tri (SortOrder)
     conn.execute("CREATE OR REPLACE VIEW MovieShortView AS
         SELECT 
             l.movie_id AS movie_id,
@@ -353,79 +430,34 @@ fn create_views(conn: &Connection) -> Result<(), oracle::Error> {
             tmd.title,
             tmd.vote_average,
             tmd.poster_path,
-            tmd.genres,
             tmd.popularity,
-            tmd.release_date
+            tmd.release_date,
+            EXTRACT(YEAR FROM tmd.release_date) AS release_year,
+            tmd.vote_count,
+            tmd.runtime,
+            tmd.revenue,
+            tmd.original_language,
+            tmd.status,
+            tmd.adult,
+            w.weighted_rating
         FROM 
             TMDB_movie_dataset tmd
         JOIN 
-            MovieLens_Links l ON l.tmdb_id = tmd.id", &[])?;
+            MovieLens_Links l ON l.tmdb_id = tmd.id
+        LEFT JOIN
+            Movie_Weighted_Ratings w ON w.movie_id = l.movie_id", &[])?;
 
     conn.execute("GRANT SELECT ON MovieShortView TO movie_db_user", &[])?;
     // Synonym
     conn.execute("DROP PUBLIC SYNONYM MovieShortView", &[]).ok();
     conn.execute("CREATE PUBLIC SYNONYM MovieShortView FOR SYSTEM.MovieShortView", &[])?;
 
-    println!("Création de la vue MovieShortView_Alphabetical...");
-    // Création de la vue MovieShortView_Alphabetical
-    conn.execute(
-        "CREATE OR REPLACE VIEW MovieShortView_Alphabetical AS
-        SELECT *
-        FROM MovieShortView
-        ORDER BY title ASC", 
-        &[]
-    )?;
-
-    conn.execute("GRANT SELECT ON MovieShortView_Alphabetical TO movie_db_user", &[])?;
-
-    conn.execute("DROP PUBLIC SYNONYM MovieShortView_Alphabetical", &[]).ok();
-    conn.execute("CREATE PUBLIC SYNONYM MovieShortView_Alphabetical FOR SYSTEM.MovieShortView_Alphabetical", &[])?;
-    
-
-    println!("Création de la vue MovieShortView_ByPopularity...");
-    // Création de la vue MovieShortView_ByPopularity
-    conn.execute(
-        "CREATE OR REPLACE VIEW MovieShortView_ByPopularity AS
-        SELECT *
-        FROM MovieShortView
-        ORDER BY popularity DESC", 
-        &[]
-    )?;
-
-    conn.execute("GRANT SELECT ON MovieShortView_ByPopularity TO movie_db_user", &[])?;
-
-    conn.execute("DROP PUBLIC SYNONYM MovieShortView_ByPopularity", &[]).ok();
-    conn.execute("CREATE PUBLIC SYNONYM MovieShortView_ByPopularity FOR SYSTEM.MovieShortView_ByPopularity", &[])?;
-
-    println!("Création de la vue MovieShortView_ByReleaseDate...");
-    // Création de la vue MovieShortView_ByReleaseDate
-    conn.execute(
-        "CREATE OR REPLACE VIEW MovieShortView_ByReleaseDate AS
-        SELECT *
-        FROM MovieShortView
-        ORDER BY release_date DESC", 
-        &[]
-    )?;
-
-    conn.execute("GRANT SELECT ON MovieShortView_ByReleaseDate TO movie_db_user", &[])?;
-
-    conn.execute("DROP PUBLIC SYNONYM MovieShortView_ByReleaseDate", &[]).ok();
-    conn.execute("CREATE PUBLIC SYNONYM MovieShortView_ByReleaseDate FOR SYSTEM.MovieShortView_ByReleaseDate", &[])?;
-
-    println!("Création de la vue MovieShortView_ByRating...");
-    // Création de la vue MovieShortView_ByRating
-    conn.execute(
-        "CREATE OR REPLACE VIEW MovieShortView_ByRating AS
-        SELECT *
-        FROM MovieShortView
-        ORDER BY vote_average DESC", 
-        &[]
-    )?;
-
-    conn.execute("GRANT SELECT ON MovieShortView_ByRating TO movie_db_user", &[])?;
-
-    conn.execute("DROP PUBLIC SYNONYM MovieShortView_ByRating", &[]).ok();
-    conn.execute("CREATE PUBLIC SYNONYM MovieShortView_ByRating FOR SYSTEM.MovieShortView_ByRating", &[])?;
+    // Le tri est appliqué par la requête de recherche de l'application :
+    // suppression des anciennes vues triées d'une base déjà migrée
+    for view in ["MovieShortView_Alphabetical", "MovieShortView_ByPopularity", "MovieShortView_ByReleaseDate", "MovieShortView_ByRating"] {
+        conn.execute(&format!("DROP PUBLIC SYNONYM {}", view), &[]).ok(); // Ignore l'erreur si le synonyme n'existe pas
+        conn.execute(&format!("DROP VIEW {}", view), &[]).ok();
+    }
 
     println!("Création de la vue TopUsers...");
     // Creation de la vue TopUsersByRatings
@@ -476,11 +508,28 @@ fn create_triggers(conn: &Connection) -> Result<(), oracle::Error> {
     println!("Création des triggers...");
 
     println!("Création du trigger trg_delete_user_cleanup...");
-    // Trigger pour la suppression en cascade des ratings et tags lors de la suppression d'un utilisateur
+    // Trigger pour la suppression en cascade des ratings et tags lors de la suppression d'un utilisateur ;
+    // l'utilisateur, ses ratings et ses tags sont d'abord archivés dans la corbeille
     conn.execute("CREATE OR REPLACE TRIGGER trg_delete_user_cleanup
         AFTER DELETE ON MovieLens_Users
         FOR EACH ROW
+        DECLARE
+            v_deletion_id NUMBER;
         BEGIN
+            INSERT INTO MovieLens_Deletions (kind, user_id, deleted_by, deleted_at)
+            VALUES ('User', :OLD.user_id, SYS_CONTEXT('USERENV', 'SESSION_USER'), TRUNC((CAST(SYS_EXTRACT_UTC(SYSTIMESTAMP) AS DATE) - DATE '1970-01-01') * 86400))
+            RETURNING deletion_id INTO v_deletion_id;
+
+            INSERT INTO Deleted_MovieLens_Ratings (deletion_id, user_id, movie_id, rating, timestamp)
+            SELECT v_deletion_id, user_id, movie_id, rating, timestamp
+            FROM MovieLens_Ratings
+            WHERE user_id = :OLD.user_id;
+
+            INSERT INTO Deleted_MovieLens_Tags (deletion_id, user_id, movie_id, tag, timestamp)
+            SELECT v_deletion_id, user_id, movie_id, tag, timestamp
+            FROM MovieLens_Tags
+            WHERE user_id = :OLD.user_id;
+
             -- Supprimer les ratings associés à l'utilisateur
             DELETE FROM MovieLens_Ratings WHERE user_id = :OLD.user_id;
             
@@ -492,12 +541,29 @@ fn create_triggers(conn: &Connection) -> Result<(), oracle::Error> {
     )?;
 
     println!("Création du trigger trg_delete_movie_cleanup...");
-    // Trigger pour la suppression en cascade des ratings et tags lors de la suppression de la relation film movieLens <-> TMDB
+    // Trigger pour la suppression en cascade des ratings et tags lors de la suppression de la relation film movieLens <-> TMDB ;
+    // le lien, les ratings et les tags du film sont d'abord archivés dans la corbeille
     conn.execute("
         CREATE OR REPLACE TRIGGER trg_delete_movie_cleanup
         AFTER DELETE ON MovieLens_Links
         FOR EACH ROW
+        DECLARE
+            v_deletion_id NUMBER;
         BEGIN
+            INSERT INTO MovieLens_Deletions (kind, movie_id, imdb_id, tmdb_id, deleted_by, deleted_at)
+            VALUES ('Movie', :OLD.movie_id, :OLD.imdb_id, :OLD.tmdb_id, SYS_CONTEXT('USERENV', 'SESSION_USER'), TRUNC((CAST(SYS_EXTRACT_UTC(SYSTIMESTAMP) AS DATE) - DATE '1970-01-01') * 86400))
+            RETURNING deletion_id INTO v_deletion_id;
+
+            INSERT INTO Deleted_MovieLens_Ratings (deletion_id, user_id, movie_id, rating, timestamp)
+            SELECT v_deletion_id, user_id, movie_id, rating, timestamp
+            FROM MovieLens_Ratings
+            WHERE movie_id = :OLD.movie_id;
+
+            INSERT INTO Deleted_MovieLens_Tags (deletion_id, user_id, movie_id, tag, timestamp)
+            SELECT v_deletion_id, user_id, movie_id, tag, timestamp
+            FROM MovieLens_Tags
+            WHERE movie_id = :OLD.movie_id;
+
             -- Supprimer les ratings associés au film
             DELETE FROM MovieLens_Ratings WHERE movie_id = :OLD.movie_id;
             
@@ -559,27 +625,21 @@ fn create_procedure(conn: &Connection) -> Result<(), oracle::Error> {
         
         -- Genres count
         OPEN genre_count FOR
-            WITH GenreSplit AS (
-            SELECT 
-                TRIM(REGEXP_SUBSTR(TO_CHAR(genres), '[^,]+', 1, LEVEL)) AS genre,
-                movie_id
-            FROM 
-                MovieDetailsView
-            CONNECT BY REGEXP_SUBSTR(TO_CHAR(genres), '[^,]+', 1, LEVEL) IS NOT NULL
-            AND PRIOR movie_id = movie_id
-            AND PRIOR SYS_GUID() IS NOT NULL
-        )
-        SELECT 
-            genre,
+        SELECT
+            g.name AS genre,
             COUNT(*) AS num_movies
-        FROM 
-            GenreSplit
-        GROUP BY 
-            genre
-        ORDER BY 
+        FROM
+            Movie_Genres mg
+        JOIN
+            Genres g ON g.genre_id = mg.genre_id
+        JOIN
+            MovieLens_Links l ON l.tmdb_id = mg.tmdb_id
+        GROUP BY
+            g.name
+        ORDER BY
             num_movies DESC;
-            
-            
+
+
         -- get top users
         OPEN top_users FOR
         SELECT user_id, num_ratings, num_tags
@@ -619,6 +679,10 @@ fn create_procedure(conn: &Connection) -> Result<(), oracle::Error> {
             DELETE FROM MovieLens_Users
             WHERE user_id = u_id;
 
+            IF SQL%ROWCOUNT > 0 THEN
+                LogAdminAction('DeleteMovieLensUser', 'movielens_user:' || u_id, NULL);
+            END IF;
+
             -- Effectuer un commit pour enregistrer les modifications
             COMMIT;
 
@@ -641,7 +705,7 @@ fn create_procedure(conn: &Connection) -> Result<(), oracle::Error> {
     conn.execute("CREATE PUBLIC SYNONYM DeleteMovieLensUser FOR SYSTEM.DeleteMovieLensUser", &[])?;
 
     // Création de la procédure DeleteMovieLensTag
-    // Supprime un tag movieLens
+    // Supprime un tag movieLens, archivé dans la corbeille
 
     println!("Création de la procédure DeleteMovieLensTag...");
     conn.execute("CREATE OR REPLACE PROCEDURE DeleteMovieLensTag(
@@ -649,13 +713,37 @@ fn create_procedure(conn: &Connection) -> Result<(), oracle::Error> {
         u_id IN NUMBER,
         tag_timestamp IN NUMBER
     ) AS
+        v_deletion_id NUMBER;
+        v_count NUMBER;
     BEGIN
-        -- Supprimer le tag correspondant dans la table MovieLens_Tags
-        DELETE FROM MovieLens_Tags
+        SELECT COUNT(*) INTO v_count
+        FROM MovieLens_Tags
         WHERE user_id = u_id
         AND movie_id = m_id
         AND timestamp = tag_timestamp;
 
+        IF v_count > 0 THEN
+            -- Archiver le tag dans la corbeille
+            INSERT INTO MovieLens_Deletions (kind, user_id, movie_id, deleted_by, deleted_at)
+            VALUES ('Tag', u_id, m_id, SYS_CONTEXT('USERENV', 'SESSION_USER'), TRUNC((CAST(SYS_EXTRACT_UTC(SYSTIMESTAMP) AS DATE) - DATE '1970-01-01') * 86400))
+            RETURNING deletion_id INTO v_deletion_id;
+
+            INSERT INTO Deleted_MovieLens_Tags (deletion_id, user_id, movie_id, tag, timestamp)
+            SELECT v_deletion_id, user_id, movie_id, tag, timestamp
+            FROM MovieLens_Tags
+            WHERE user_id = u_id
+            AND movie_id = m_id
+            AND timestamp = tag_timestamp;
+
+            -- Supprimer le tag correspondant dans la table MovieLens_Tags
+            DELETE FROM MovieLens_Tags
+            WHERE user_id = u_id
+            AND movie_id = m_id
+            AND timestamp = tag_timestamp;
+
+            LogAdminAction('DeleteMovieLensTag', 'movie:' || m_id, 'user_id=' || u_id || ', timestamp=' || tag_timestamp);
+        END IF;
+
         -- Effectuer un commit pour enregistrer les modifications
         COMMIT;
 
@@ -677,6 +765,546 @@ fn create_procedure(conn: &Connection) -> Result<(), oracle::Error> {
     conn.execute("DROP PUBLIC SYNONYM DeleteMovieLensTag", &[]).ok();
     conn.execute("CREATE PUBLIC SYNONYM DeleteMovieLensTag FOR SYSTEM.DeleteMovieLensTag", &[])?;
 
+    // Création de la procédure UpdateMovie
+    // Modifie les champs renseignés d'un film (les paramètres NULL sont conservés),
+    // seulement si sa version n'a pas changé depuis la lecture (concurrence optimiste)
+    println!("Création de la procédure UpdateMovie...");
+    conn.execute("CREATE OR REPLACE PROCEDURE UpdateMovie(
+        m_id IN NUMBER,
+        expected_version IN NUMBER,
+        new_title IN VARCHAR2,
+        new_overview IN CLOB,
+        new_release_date IN VARCHAR2,
+        new_runtime IN NUMBER,
+        new_poster_path IN VARCHAR2
+    ) AS
+        v_tmdb_id NUMBER;
+        v_version NUMBER;
+        v_release_date DATE;
+    BEGIN
+        -- Validation des champs renseignés, avec les mêmes bornes que l'application
+        IF new_title IS NOT NULL AND (TRIM(new_title) IS NULL OR LENGTH(new_title) > 768) THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Titre invalide : il doit contenir entre 1 et 768 caractères');
+        END IF;
+
+        IF new_overview IS NOT NULL AND (TRIM(new_overview) IS NULL OR DBMS_LOB.GETLENGTH(new_overview) > 4000) THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Résumé invalide : il doit contenir entre 1 et 4000 caractères');
+        END IF;
+
+        IF new_release_date IS NOT NULL THEN
+            BEGIN
+                v_release_date := TO_DATE(new_release_date, 'FXYYYY-MM-DD');
+            EXCEPTION
+                WHEN OTHERS THEN
+                    RAISE_APPLICATION_ERROR(-20005, 'Date de sortie invalide : ' || new_release_date || ' (format attendu AAAA-MM-JJ)');
+            END;
+        END IF;
+
+        IF new_runtime IS NOT NULL AND (new_runtime < 0 OR new_runtime > 1440 OR new_runtime <> TRUNC(new_runtime)) THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Durée invalide : ' || new_runtime || ' (entre 0 et 1440 minutes)');
+        END IF;
+
+        IF new_poster_path IS NOT NULL
+            AND (SUBSTR(new_poster_path, 1, 1) <> '/' OR LENGTH(new_poster_path) > 255 OR REGEXP_LIKE(new_poster_path, '[[:space:]]')) THEN
+            RAISE_APPLICATION_ERROR(
+                -20005,
+                'Chemin d''affiche invalide : ' || new_poster_path || ' (chemin TMDB commençant par ''/'', sans espace, 255 caractères au plus)'
+            );
+        END IF;
+
+        SELECT tmdb_id INTO v_tmdb_id
+        FROM MovieLens_Links
+        WHERE movie_id = m_id;
+
+        -- La version est comparée par la mise à jour elle-même : deux modifications
+        -- concurrentes partant de la même version ne peuvent pas réussir toutes les deux
+        UPDATE TMDB_movie_dataset
+        SET
+            title = NVL(new_title, title),
+            overview = NVL(new_overview, overview),
+            release_date = NVL(v_release_date, release_date),
+            runtime = NVL(new_runtime, runtime),
+            poster_path = NVL(new_poster_path, poster_path),
+            row_version = row_version + 1
+        WHERE id = v_tmdb_id
+        AND row_version = expected_version;
+
+        IF SQL%ROWCOUNT = 0 THEN
+            SELECT row_version INTO v_version
+            FROM TMDB_movie_dataset
+            WHERE id = v_tmdb_id;
+
+            RAISE_APPLICATION_ERROR(
+                -20007,
+                'Le film ' || m_id || ' a été modifié par un autre administrateur (version ' || v_version
+                    || ', attendue ' || expected_version || '). Rechargez-le avant de le modifier.'
+            );
+        END IF;
+
+        -- Journaliser la version modifiée et les champs renseignés
+        LogAdminAction(
+            'UpdateMovie',
+            'movie:' || m_id,
+            'version=' || expected_version || ', fields=' || RTRIM(
+                CASE WHEN new_title IS NOT NULL THEN 'title,' END
+                    || CASE WHEN new_overview IS NOT NULL THEN 'overview,' END
+                    || CASE WHEN new_release_date IS NOT NULL THEN 'release_date,' END
+                    || CASE WHEN new_runtime IS NOT NULL THEN 'runtime,' END
+                    || CASE WHEN new_poster_path IS NOT NULL THEN 'poster_path,' END,
+                ','
+            )
+        );
+
+        -- Effectuer un commit pour enregistrer les modifications
+        COMMIT;
+    EXCEPTION
+        WHEN NO_DATA_FOUND THEN
+            RAISE_APPLICATION_ERROR(-20006, 'Film introuvable : ' || m_id);
+    END;", &[])?;
+
+    // Grant
+    conn.execute("GRANT EXECUTE ON UpdateMovie TO movie_db_admin", &[])?;
+
+    // Synonym
+    conn.execute("DROP PUBLIC SYNONYM UpdateMovie", &[]).ok();
+    conn.execute("CREATE PUBLIC SYNONYM UpdateMovie FOR SYSTEM.UpdateMovie", &[])?;
+
+    // Création de la procédure AddMovie
+    // Ajoute un film TMDB, son lien MovieLens et ses genres en une transaction ;
+    // le movie_id attribué (le plus grand existant + 1) est retourné dans new_movie_id
+    println!("Création de la procédure AddMovie...");
+    conn.execute("CREATE OR REPLACE PROCEDURE AddMovie(
+        new_tmdb_id IN NUMBER,
+        new_imdb_id IN VARCHAR2,
+        new_title IN VARCHAR2,
+        new_overview IN CLOB,
+        new_release_date IN VARCHAR2,
+        new_runtime IN NUMBER,
+        new_poster_path IN VARCHAR2,
+        new_original_language IN VARCHAR2,
+        new_adult IN NUMBER,
+        new_genres IN VARCHAR2,
+        new_movie_id OUT NUMBER
+    ) AS
+        v_release_date DATE;
+        v_count NUMBER;
+    BEGIN
+        -- Validation des champs obligatoires, avec les mêmes bornes que l'application
+        IF new_tmdb_id IS NULL OR new_tmdb_id <= 0 THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Identifiant TMDB invalide : ' || new_tmdb_id);
+        END IF;
+
+        IF new_imdb_id IS NULL OR NOT REGEXP_LIKE(new_imdb_id, '^[0-9]{7,10}$') THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Identifiant IMDb invalide : ' || new_imdb_id || ' (format attendu tt0114709)');
+        END IF;
+
+        IF TRIM(new_title) IS NULL OR LENGTH(new_title) > 768 THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Titre invalide : il doit contenir entre 1 et 768 caractères');
+        END IF;
+
+        IF new_overview IS NULL OR TRIM(new_overview) IS NULL OR DBMS_LOB.GETLENGTH(new_overview) > 4000 THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Résumé invalide : il doit contenir entre 1 et 4000 caractères');
+        END IF;
+
+        BEGIN
+            v_release_date := TO_DATE(new_release_date, 'FXYYYY-MM-DD');
+        EXCEPTION
+            WHEN OTHERS THEN
+                RAISE_APPLICATION_ERROR(-20005, 'Date de sortie invalide : ' || new_release_date || ' (format attendu AAAA-MM-JJ)');
+        END;
+        IF v_release_date IS NULL THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Date de sortie manquante (format attendu AAAA-MM-JJ)');
+        END IF;
+
+        IF new_runtime IS NULL OR new_runtime < 0 OR new_runtime > 1440 OR new_runtime <> TRUNC(new_runtime) THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Durée invalide : ' || new_runtime || ' (entre 0 et 1440 minutes)');
+        END IF;
+
+        IF new_poster_path IS NULL
+            OR SUBSTR(new_poster_path, 1, 1) <> '/' OR LENGTH(new_poster_path) > 255 OR REGEXP_LIKE(new_poster_path, '[[:space:]]') THEN
+            RAISE_APPLICATION_ERROR(
+                -20005,
+                'Chemin d''affiche invalide : ' || new_poster_path || ' (chemin TMDB commençant par ''/'', sans espace, 255 caractères au plus)'
+            );
+        END IF;
+
+        IF new_original_language IS NOT NULL AND NOT REGEXP_LIKE(new_original_language, '^[a-z]{2}$') THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Langue originale invalide : ' || new_original_language || ' (code ISO 639-1 attendu)');
+        END IF;
+
+        -- Verrou jusqu'au commit : deux ajouts simultanés ne peuvent pas recevoir le même movie_id
+        LOCK TABLE MovieLens_Links IN EXCLUSIVE MODE;
+
+        SELECT COUNT(*) INTO v_count
+        FROM (
+            SELECT id FROM TMDB_movie_dataset WHERE id = new_tmdb_id
+            UNION ALL
+            SELECT tmdb_id FROM MovieLens_Links WHERE tmdb_id = new_tmdb_id
+        );
+        IF v_count > 0 THEN
+            RAISE_APPLICATION_ERROR(-20008, 'Le film TMDB ' || new_tmdb_id || ' existe déjà');
+        END IF;
+
+        SELECT COUNT(*) INTO v_count
+        FROM (
+            SELECT id FROM TMDB_movie_dataset WHERE imdb_id = 'tt' || new_imdb_id
+            UNION ALL
+            SELECT movie_id FROM MovieLens_Links WHERE imdb_id = new_imdb_id
+        );
+        IF v_count > 0 THEN
+            RAISE_APPLICATION_ERROR(-20008, 'Le film IMDb tt' || new_imdb_id || ' existe déjà');
+        END IF;
+
+        -- Un film de la corbeille garde son movie_id, qui n'est pas réattribué
+        SELECT GREATEST(
+            NVL(MAX(movie_id), 0),
+            (SELECT NVL(MAX(movie_id), 0) FROM MovieLens_Deletions WHERE kind = 'Movie')
+        ) + 1 INTO new_movie_id
+        FROM MovieLens_Links;
+
+        -- Les statistiques TMDB (notes, popularité, recettes) partent de 0
+        INSERT INTO TMDB_movie_dataset (
+            id, title, vote_average, vote_count, status, release_date, revenue, runtime, adult, budget,
+            imdb_id, original_language, original_title, overview, popularity, poster_path, genres
+        ) VALUES (
+            new_tmdb_id, new_title, 0, 0, 'Released', v_release_date, 0, new_runtime, NVL(new_adult, 0), 0,
+            'tt' || new_imdb_id, new_original_language, new_title, new_overview, 0, new_poster_path, new_genres
+        );
+
+        INSERT INTO MovieLens_Links (movie_id, imdb_id, tmdb_id)
+        VALUES (new_movie_id, new_imdb_id, new_tmdb_id);
+
+        -- Genres de la liste (séparés par des virgules), créés s'ils n'existent pas encore
+        FOR g IN (
+            SELECT TRIM(REGEXP_SUBSTR(new_genres, '[^,]+', 1, LEVEL)) AS name, LEVEL AS position
+            FROM DUAL
+            CONNECT BY LEVEL <= REGEXP_COUNT(new_genres, '[^,]+')
+        ) LOOP
+            IF g.name IS NOT NULL THEN
+                MERGE INTO Genres t
+                USING (SELECT g.name AS name FROM DUAL) s ON (t.name = s.name)
+                WHEN NOT MATCHED THEN INSERT (name) VALUES (s.name);
+
+                INSERT INTO Movie_Genres (tmdb_id, genre_id, position)
+                SELECT new_tmdb_id, genre_id, g.position
+                FROM Genres
+                WHERE name = g.name
+                AND NOT EXISTS (
+                    SELECT 1 FROM Movie_Genres mg
+                    WHERE mg.tmdb_id = new_tmdb_id AND mg.genre_id = Genres.genre_id
+                );
+            END IF;
+        END LOOP;
+
+        LogAdminAction(
+            'AddMovie',
+            'movie:' || new_movie_id,
+            'tmdb_id=' || new_tmdb_id || ', imdb_id=tt' || new_imdb_id || ', title=' || new_title
+        );
+
+        -- Effectuer un commit pour enregistrer les modifications
+        COMMIT;
+    EXCEPTION
+        WHEN OTHERS THEN
+            -- Annuler l'ajout et libérer le verrou avant de transmettre l'erreur
+            ROLLBACK;
+            RAISE;
+    END;", &[])?;
+
+    // Grant
+    conn.execute("GRANT EXECUTE ON AddMovie TO movie_db_admin", &[])?;
+
+    // Synonym
+    conn.execute("DROP PUBLIC SYNONYM AddMovie", &[]).ok();
+    conn.execute("CREATE PUBLIC SYNONYM AddMovie FOR SYSTEM.AddMovie", &[])?;
+
+    // Création de la procédure AddMovieLensRating
+    // Note un film au nom d'un utilisateur MovieLens, créé s'il n'existe pas encore ;
+    // la note est horodatée par la base et le timestamp est retourné dans new_timestamp
+    println!("Création de la procédure AddMovieLensRating...");
+    conn.execute("CREATE OR REPLACE PROCEDURE AddMovieLensRating(
+        m_id IN NUMBER,
+        u_id IN NUMBER,
+        new_rating IN NUMBER,
+        new_timestamp OUT NUMBER
+    ) AS
+        v_count NUMBER;
+        v_user_id NUMBER;
+    BEGIN
+        IF u_id IS NULL OR u_id <= 0 OR u_id <> TRUNC(u_id) THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Identifiant utilisateur invalide : ' || u_id);
+        END IF;
+
+        IF new_rating IS NULL OR new_rating < 0.5 OR new_rating > 5 OR new_rating * 2 <> TRUNC(new_rating * 2) THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Note invalide : ' || new_rating || ' (de 0.5 à 5 par demi-étoile)');
+        END IF;
+
+        SELECT COUNT(*) INTO v_count
+        FROM MovieLens_Links
+        WHERE movie_id = m_id;
+        IF v_count = 0 THEN
+            RAISE_APPLICATION_ERROR(-20006, 'Film introuvable : ' || m_id);
+        END IF;
+
+        MERGE INTO MovieLens_Users t
+        USING (SELECT u_id AS user_id FROM DUAL) s ON (t.user_id = s.user_id)
+        WHEN NOT MATCHED THEN INSERT (user_id) VALUES (s.user_id);
+
+        -- Verrou sur l'utilisateur jusqu'au commit : deux notes simultanées ne peuvent pas passer toutes les deux
+        SELECT user_id INTO v_user_id
+        FROM MovieLens_Users
+        WHERE user_id = u_id
+        FOR UPDATE;
+
+        SELECT COUNT(*) INTO v_count
+        FROM MovieLens_Ratings
+        WHERE movie_id = m_id AND user_id = u_id;
+        IF v_count > 0 THEN
+            RAISE_APPLICATION_ERROR(-20009, 'L''utilisateur ' || u_id || ' a déjà noté le film ' || m_id);
+        END IF;
+
+        new_timestamp := TRUNC((CAST(SYS_EXTRACT_UTC(SYSTIMESTAMP) AS DATE) - DATE '1970-01-01') * 86400);
+
+        INSERT INTO MovieLens_Ratings (user_id, movie_id, rating, timestamp)
+        VALUES (u_id, m_id, new_rating, new_timestamp);
+
+        LogAdminAction('AddMovieLensRating', 'movie:' || m_id, 'user_id=' || u_id || ', rating=' || TO_CHAR(new_rating, 'FM0.0'));
+
+        -- Effectuer un commit pour enregistrer les modifications
+        COMMIT;
+    EXCEPTION
+        WHEN OTHERS THEN
+            ROLLBACK;
+            RAISE;
+    END;", &[])?;
+
+    // Grant
+    conn.execute("GRANT EXECUTE ON AddMovieLensRating TO movie_db_admin", &[])?;
+
+    // Synonym
+    conn.execute("DROP PUBLIC SYNONYM AddMovieLensRating", &[]).ok();
+    conn.execute("CREATE PUBLIC SYNONYM AddMovieLensRating FOR SYSTEM.AddMovieLensRating", &[])?;
+
+    // Création de la procédure UpdateMovieLensRating
+    // Change une note existante ; son timestamp est remplacé par l'heure de la modification
+    println!("Création de la procédure UpdateMovieLensRating...");
+    conn.execute("CREATE OR REPLACE PROCEDURE UpdateMovieLensRating(
+        m_id IN NUMBER,
+        u_id IN NUMBER,
+        new_rating IN NUMBER,
+        new_timestamp OUT NUMBER
+    ) AS
+    BEGIN
+        IF new_rating IS NULL OR new_rating < 0.5 OR new_rating > 5 OR new_rating * 2 <> TRUNC(new_rating * 2) THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Note invalide : ' || new_rating || ' (de 0.5 à 5 par demi-étoile)');
+        END IF;
+
+        new_timestamp := TRUNC((CAST(SYS_EXTRACT_UTC(SYSTIMESTAMP) AS DATE) - DATE '1970-01-01') * 86400);
+
+        UPDATE MovieLens_Ratings
+        SET rating = new_rating, timestamp = new_timestamp
+        WHERE movie_id = m_id AND user_id = u_id;
+
+        IF SQL%ROWCOUNT = 0 THEN
+            RAISE_APPLICATION_ERROR(-20010, 'Aucune note de l''utilisateur ' || u_id || ' pour le film ' || m_id);
+        END IF;
+
+        LogAdminAction('UpdateMovieLensRating', 'movie:' || m_id, 'user_id=' || u_id || ', rating=' || TO_CHAR(new_rating, 'FM0.0'));
+
+        -- Effectuer un commit pour enregistrer les modifications
+        COMMIT;
+    END;", &[])?;
+
+    // Grant
+    conn.execute("GRANT EXECUTE ON UpdateMovieLensRating TO movie_db_admin", &[])?;
+
+    // Synonym
+    conn.execute("DROP PUBLIC SYNONYM UpdateMovieLensRating", &[]).ok();
+    conn.execute("CREATE PUBLIC SYNONYM UpdateMovieLensRating FOR SYSTEM.UpdateMovieLensRating", &[])?;
+
+    // Création de la procédure AddMovieLensTag
+    // Ajoute un tag au nom d'un utilisateur MovieLens, créé s'il n'existe pas encore ;
+    // le timestamp, qui identifie le tag, est rendu unique pour ce couple film/utilisateur
+    println!("Création de la procédure AddMovieLensTag...");
+    conn.execute("CREATE OR REPLACE PROCEDURE AddMovieLensTag(
+        m_id IN NUMBER,
+        u_id IN NUMBER,
+        new_tag IN VARCHAR2,
+        new_timestamp OUT NUMBER
+    ) AS
+        v_count NUMBER;
+        v_user_id NUMBER;
+    BEGIN
+        IF u_id IS NULL OR u_id <= 0 OR u_id <> TRUNC(u_id) THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Identifiant utilisateur invalide : ' || u_id);
+        END IF;
+
+        IF TRIM(new_tag) IS NULL OR LENGTHB(new_tag) > 255 THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Tag invalide : il doit contenir entre 1 et 255 octets');
+        END IF;
+
+        SELECT COUNT(*) INTO v_count
+        FROM MovieLens_Links
+        WHERE movie_id = m_id;
+        IF v_count = 0 THEN
+            RAISE_APPLICATION_ERROR(-20006, 'Film introuvable : ' || m_id);
+        END IF;
+
+        MERGE INTO MovieLens_Users t
+        USING (SELECT u_id AS user_id FROM DUAL) s ON (t.user_id = s.user_id)
+        WHEN NOT MATCHED THEN INSERT (user_id) VALUES (s.user_id);
+
+        -- Verrou sur l'utilisateur jusqu'au commit : deux tags simultanés ne peuvent pas recevoir le même timestamp
+        SELECT user_id INTO v_user_id
+        FROM MovieLens_Users
+        WHERE user_id = u_id
+        FOR UPDATE;
+
+        SELECT GREATEST(
+            TRUNC((CAST(SYS_EXTRACT_UTC(SYSTIMESTAMP) AS DATE) - DATE '1970-01-01') * 86400),
+            NVL(MAX(timestamp) + 1, 0)
+        ) INTO new_timestamp
+        FROM MovieLens_Tags
+        WHERE movie_id = m_id AND user_id = u_id;
+
+        INSERT INTO MovieLens_Tags (user_id, movie_id, tag, timestamp)
+        VALUES (u_id, m_id, TRIM(new_tag), new_timestamp);
+
+        LogAdminAction(
+            'AddMovieLensTag',
+            'movie:' || m_id,
+            'user_id=' || u_id || ', timestamp=' || new_timestamp || ', tag=' || TRIM(new_tag)
+        );
+
+        -- Effectuer un commit pour enregistrer les modifications
+        COMMIT;
+    EXCEPTION
+        WHEN OTHERS THEN
+            ROLLBACK;
+            RAISE;
+    END;", &[])?;
+
+    // Grant
+    conn.execute("GRANT EXECUTE ON AddMovieLensTag TO movie_db_admin", &[])?;
+
+    // Synonym
+    conn.execute("DROP PUBLIC SYNONYM AddMovieLensTag", &[]).ok();
+    conn.execute("CREATE PUBLIC SYNONYM AddMovieLensTag FOR SYSTEM.AddMovieLensTag", &[])?;
+
+    // Création de la procédure RenameMovieLensTag
+    // Corrige le texte d'un tag identifié par (movie_id, user_id, timestamp), sans changer son timestamp
+    println!("Création de la procédure RenameMovieLensTag...");
+    conn.execute("CREATE OR REPLACE PROCEDURE RenameMovieLensTag(
+        m_id IN NUMBER,
+        u_id IN NUMBER,
+        t_timestamp IN NUMBER,
+        new_tag IN VARCHAR2
+    ) AS
+    BEGIN
+        IF TRIM(new_tag) IS NULL OR LENGTHB(new_tag) > 255 THEN
+            RAISE_APPLICATION_ERROR(-20005, 'Tag invalide : il doit contenir entre 1 et 255 octets');
+        END IF;
+
+        UPDATE MovieLens_Tags
+        SET tag = TRIM(new_tag)
+        WHERE movie_id = m_id AND user_id = u_id AND timestamp = t_timestamp;
+
+        IF SQL%ROWCOUNT = 0 THEN
+            RAISE_APPLICATION_ERROR(
+                -20010,
+                'Tag introuvable pour user_id=' || u_id || ', movie_id=' || m_id || ', timestamp=' || t_timestamp
+            );
+        END IF;
+
+        LogAdminAction(
+            'RenameMovieLensTag',
+            'movie:' || m_id,
+            'user_id=' || u_id || ', timestamp=' || t_timestamp || ', tag=' || TRIM(new_tag)
+        );
+
+        -- Effectuer un commit pour enregistrer les modifications
+        COMMIT;
+    END;", &[])?;
+
+    // Grant
+    conn.execute("GRANT EXECUTE ON RenameMovieLensTag TO movie_db_admin", &[])?;
+
+    // Synonym
+    conn.execute("DROP PUBLIC SYNONYM RenameMovieLensTag", &[]).ok();
+    conn.execute("CREATE PUBLIC SYNONYM RenameMovieLensTag FOR SYSTEM.RenameMovieLensTag", &[])?;
+
+    // Création de la procédure ApplyTagModeration
+    // Supprime ou réécrit les tags déposés par l'application dans Tag_Moderation_Changes, puis journalise
+    // la règle (p_rule) avec les comptes réellement appliqués. Un tag modifié ou supprimé depuis sa lecture
+    // annule toute la modération
+    println!("Création de la procédure ApplyTagModeration...");
+    conn.execute("CREATE OR REPLACE PROCEDURE ApplyTagModeration(
+        p_rule IN VARCHAR2
+    ) AS
+        v_matched NUMBER := 0;
+        v_deleted NUMBER := 0;
+        v_deletion_id NUMBER;
+    BEGIN
+        FOR c IN (SELECT movie_id, user_id, timestamp, tag, new_tag FROM Tag_Moderation_Changes) LOOP
+            IF c.new_tag IS NULL THEN
+                -- Archiver le tag dans la corbeille, comme DeleteMovieLensTag
+                INSERT INTO MovieLens_Deletions (kind, user_id, movie_id, deleted_by, deleted_at)
+                VALUES ('Tag', c.user_id, c.movie_id, SYS_CONTEXT('USERENV', 'SESSION_USER'), TRUNC((CAST(SYS_EXTRACT_UTC(SYSTIMESTAMP) AS DATE) - DATE '1970-01-01') * 86400))
+                RETURNING deletion_id INTO v_deletion_id;
+
+                INSERT INTO Deleted_MovieLens_Tags (deletion_id, user_id, movie_id, tag, timestamp)
+                SELECT v_deletion_id, user_id, movie_id, tag, timestamp
+                FROM MovieLens_Tags
+                WHERE movie_id = c.movie_id
+                AND user_id = c.user_id
+                AND timestamp = c.timestamp
+                AND tag = c.tag;
+
+                DELETE FROM MovieLens_Tags
+                WHERE movie_id = c.movie_id
+                AND user_id = c.user_id
+                AND timestamp = c.timestamp
+                AND tag = c.tag;
+            ELSE
+                UPDATE MovieLens_Tags
+                SET tag = c.new_tag
+                WHERE movie_id = c.movie_id
+                AND user_id = c.user_id
+                AND timestamp = c.timestamp
+                AND tag = c.tag;
+            END IF;
+
+            IF SQL%ROWCOUNT = 0 THEN
+                RAISE_APPLICATION_ERROR(-20013, 'Des tags ont été modifiés pendant la modération. Relancez l''aperçu.');
+            END IF;
+
+            v_matched := v_matched + 1;
+            IF c.new_tag IS NULL THEN
+                v_deleted := v_deleted + 1;
+            END IF;
+        END LOOP;
+
+        LogAdminAction(
+            'ApplyTagModeration',
+            NULL,
+            p_rule || ', matched=' || v_matched || ', deleted=' || v_deleted || ', rewritten=' || (v_matched - v_deleted)
+        );
+
+        -- Le commit vide aussi Tag_Moderation_Changes
+        COMMIT;
+    EXCEPTION
+        WHEN OTHERS THEN
+            ROLLBACK;
+            RAISE;
+    END;", &[])?;
+
+    // Grant
+    conn.execute("GRANT EXECUTE ON ApplyTagModeration TO movie_db_admin", &[])?;
+
+    // Synonym
+    conn.execute("DROP PUBLIC SYNONYM ApplyTagModeration", &[]).ok();
+    conn.execute("CREATE PUBLIC SYNONYM ApplyTagModeration FOR SYSTEM.ApplyTagModeration", &[])?;
+
     // Commit
     conn.commit()?;
 
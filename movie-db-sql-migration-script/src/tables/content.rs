@@ -0,0 +1,126 @@
+use movie_db_models::content::ContentIndex;
+use oracle::Connection;
+
+/// Tables des caractéristiques de contenu, utilisées par la recherche de films similaires
+pub struct ContentFeatures;
+
+impl ContentFeatures {
+    pub fn create_table(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Suppression des tables Content_Features, Movie_Content_Features et Movie_Content_Norms...");
+        for table in ["Movie_Content_Norms", "Movie_Content_Features", "Content_Features"] {
+            conn.execute(&format!("DROP PUBLIC SYNONYM {}", table), &[]).ok(); // Ignore l'erreur si le synonyme n'existe pas
+            conn.execute(&format!("DROP TABLE {} CASCADE CONSTRAINTS", table), &[]).ok();
+        }
+
+        println!("Création des tables Content_Features, Movie_Content_Features et Movie_Content_Norms...");
+
+        // kind : 'G' genre, 'K' mot-clé, 'T' terme du résumé ; weight : IDF pondéré par le type
+        conn.execute(
+            "CREATE TABLE Content_Features (
+                feature_id NUMBER PRIMARY KEY,
+                kind CHAR(1) NOT NULL CHECK (kind IN ('G', 'K', 'T')),
+                name VARCHAR2(512) NOT NULL,
+                movie_count NUMBER NOT NULL,
+                weight NUMBER NOT NULL
+            )
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        // Table organisée en index : les deux sens de parcours ne lisent que des index
+        conn.execute(
+            "CREATE TABLE Movie_Content_Features (
+                tmdb_id NUMBER NOT NULL,
+                feature_id NUMBER NOT NULL,
+                CONSTRAINT pk_movie_content_features PRIMARY KEY (tmdb_id, feature_id)
+            )
+            ORGANIZATION INDEX
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        conn.execute("CREATE INDEX Movie_Content_Features_ref_idx ON Movie_Content_Features(feature_id, tmdb_id)", &[])?;
+
+        // total_weight : somme des poids des caractéristiques du film (dénominateur du Jaccard pondéré)
+        conn.execute(
+            "CREATE TABLE Movie_Content_Norms (
+                tmdb_id NUMBER PRIMARY KEY,
+                total_weight NUMBER NOT NULL
+            )
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        // Role GRANT : les tables ne sont alimentées que par le script
+        for table in ["Content_Features", "Movie_Content_Features", "Movie_Content_Norms"] {
+            conn.execute(&format!("GRANT SELECT ON {} TO movie_db_user", table), &[])?;
+            conn.execute(&format!("GRANT SELECT ON {} TO movie_db_admin", table), &[])?;
+
+            // Synonym
+            conn.execute(&format!("CREATE PUBLIC SYNONYM {} FOR SYSTEM.{}", table, table), &[])?;
+        }
+
+        Ok(())
+    }
+
+    /// Recalcule les vecteurs des films MovieLens à partir de `TMDB_movie_dataset`, en une transaction
+    pub fn compute(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Lecture des genres, mots-clés et résumés des films...");
+        let rows = conn.query_as::<(i32, Option<String>, Option<String>, Option<String>)>(
+            "SELECT id, genres, keywords, overview
+            FROM TMDB_movie_dataset
+            WHERE id IN (SELECT tmdb_id FROM MovieLens_Links)
+            ORDER BY id",
+            &[],
+        )?;
+
+        let mut movies = Vec::new();
+        for row in rows {
+            movies.push(row?);
+        }
+
+        println!("Calcul des caractéristiques de {} films...", movies.len());
+        let index = ContentIndex::build(&movies);
+
+        println!(
+            "Insertion de {} caractéristiques et {} associations...",
+            index.features.len(),
+            index.links.len()
+        );
+        conn.execute("DELETE FROM Movie_Content_Norms", &[])?;
+        conn.execute("DELETE FROM Movie_Content_Features", &[])?;
+        conn.execute("DELETE FROM Content_Features", &[])?;
+
+        let mut batch = conn
+            .batch(
+                "INSERT INTO Content_Features (feature_id, kind, name, movie_count, weight) VALUES (:1, :2, :3, :4, :5)",
+                32768,
+            )
+            .build()?;
+        for (index, feature) in index.features.iter().enumerate() {
+            let feature_id = index as u32 + 1;
+            batch.append_row(&[&feature_id, &feature.kind, &feature.name, &feature.movie_count, &feature.weight])?;
+        }
+        batch.execute()?;
+
+        let mut batch = conn
+            .batch("INSERT INTO Movie_Content_Features (tmdb_id, feature_id) VALUES (:1, :2)", 32768)
+            .build()?;
+        for (tmdb_id, feature_id) in &index.links {
+            batch.append_row(&[tmdb_id, feature_id])?;
+        }
+        batch.execute()?;
+
+        let mut batch = conn
+            .batch("INSERT INTO Movie_Content_Norms (tmdb_id, total_weight) VALUES (:1, :2)", 32768)
+            .build()?;
+        for (tmdb_id, total_weight) in &index.norms {
+            batch.append_row(&[tmdb_id, total_weight])?;
+        }
+        batch.execute()?;
+
+        conn.commit()?;
+
+        Ok(())
+    }
+}
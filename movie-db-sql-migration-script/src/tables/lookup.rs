@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use oracle::Connection;
+
+/// Attribut multivalué des films (genres, mots-clés...), stocké dans le CSV TMDB sous forme
+/// de liste séparée par des virgules et normalisé en une table de référence et une table
+/// d'association avec les films
+pub struct Lookup {
+    pub table: &'static str,
+    pub junction: &'static str,
+    pub id_column: &'static str,
+    pub name_length: u32,
+}
+
+pub const GENRES: Lookup = Lookup {
+    table: "Genres",
+    junction: "Movie_Genres",
+    id_column: "genre_id",
+    name_length: 64,
+};
+
+pub const KEYWORDS: Lookup = Lookup {
+    table: "Keywords",
+    junction: "Movie_Keywords",
+    id_column: "keyword_id",
+    name_length: 512,
+};
+
+pub const COMPANIES: Lookup = Lookup {
+    table: "Companies",
+    junction: "Movie_Companies",
+    id_column: "company_id",
+    name_length: 512,
+};
+
+pub const COUNTRIES: Lookup = Lookup {
+    table: "Countries",
+    junction: "Movie_Countries",
+    id_column: "country_id",
+    name_length: 128,
+};
+
+pub const LANGUAGES: Lookup = Lookup {
+    table: "Languages",
+    junction: "Movie_Languages",
+    id_column: "language_id",
+    name_length: 128,
+};
+
+/// Valeurs lues dans le CSV : chaque nom reçoit un indice provisoire, les identifiants
+/// définitifs sont attribués à l'insertion
+#[derive(Default)]
+pub struct LookupValues {
+    names: HashMap<String, u32>,
+    links: Vec<(i32, u32, u32)>,
+}
+
+impl LookupValues {
+    /// Ajoute la liste d'un film ; les doublons et les noms vides sont ignorés
+    pub fn add(&mut self, tmdb_id: i32, list: &str) {
+        let mut seen = Vec::new();
+        for name in list.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            let next = self.names.len() as u32;
+            let index = *self.names.entry(name.to_string()).or_insert(next);
+            if !seen.contains(&index) {
+                seen.push(index);
+                self.links.push((tmdb_id, index, seen.len() as u32));
+            }
+        }
+    }
+}
+
+impl Lookup {
+    pub fn create_table(&self, conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Suppression des tables {} et {}...", self.table, self.junction);
+        conn.execute(&format!("DROP PUBLIC SYNONYM {}", self.junction), &[]).ok(); // Ignore l'erreur si le synonyme n'existe pas
+        conn.execute(&format!("DROP PUBLIC SYNONYM {}", self.table), &[]).ok();
+        conn.execute(&format!("DROP TABLE {} CASCADE CONSTRAINTS", self.junction), &[]).ok();
+        conn.execute(&format!("DROP TABLE {} CASCADE CONSTRAINTS", self.table), &[]).ok();
+
+        println!("Création des tables {} et {}...", self.table, self.junction);
+        conn.execute(
+            &format!(
+                "CREATE TABLE {table} (
+                    {id} NUMBER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+                    name VARCHAR2({length}) NOT NULL,
+                    CONSTRAINT uq_{table}_name UNIQUE (name)
+                )
+                TABLESPACE movie_db_tbs",
+                table = self.table,
+                id = self.id_column,
+                length = self.name_length,
+            ),
+            &[],
+        )?;
+
+        // position : rang de la valeur dans la liste d'origine
+        conn.execute(
+            &format!(
+                "CREATE TABLE {junction} (
+                    tmdb_id NUMBER NOT NULL,
+                    {id} NUMBER NOT NULL,
+                    position NUMBER NOT NULL,
+                    CONSTRAINT pk_{junction} PRIMARY KEY (tmdb_id, {id}),
+                    CONSTRAINT fk_{junction}_movie FOREIGN KEY (tmdb_id) REFERENCES TMDB_movie_dataset (id) ON DELETE CASCADE,
+                    CONSTRAINT fk_{junction}_ref FOREIGN KEY ({id}) REFERENCES {table} ({id}) ON DELETE CASCADE
+                )
+                TABLESPACE movie_db_tbs",
+                table = self.table,
+                junction = self.junction,
+                id = self.id_column,
+            ),
+            &[],
+        )?;
+
+        // Index : films associés à une valeur (filtres de recherche, statistiques)
+        conn.execute(
+            &format!("CREATE INDEX {junction}_ref_idx ON {junction}({id}, tmdb_id)", junction = self.junction, id = self.id_column),
+            &[],
+        )?;
+
+        // Role GRANT
+        for table in [self.table, self.junction] {
+            conn.execute(&format!("GRANT SELECT ON {} TO movie_db_user", table), &[])?;
+            conn.execute(&format!("GRANT SELECT, INSERT, UPDATE, DELETE ON {} TO movie_db_admin", table), &[])?;
+        }
+
+        // Synonym
+        for table in [self.table, self.junction] {
+            conn.execute(&format!("CREATE PUBLIC SYNONYM {table} FOR SYSTEM.{table}", table = table), &[])?;
+        }
+
+        Ok(())
+    }
+
+    /// Insère les valeurs puis les associations film / valeur.
+    /// Les identifiants suivent l'ordre alphabétique des noms.
+    pub fn insert(&self, conn: &Connection, values: &LookupValues) -> Result<(), oracle::Error> {
+        println!("Insertion de {} valeurs dans {}...", values.names.len(), self.table);
+
+        let mut names: Vec<(&String, u32)> = values.names.iter().map(|(name, index)| (name, *index)).collect();
+        names.sort();
+
+        let mut ids = vec![0; names.len()];
+        let mut batch = conn
+            .batch(&format!("INSERT INTO {} ({}, name) VALUES (:1, :2)", self.table, self.id_column), 32768)
+            .build()?;
+        for (position, (name, index)) in names.iter().enumerate() {
+            let id = position as i32 + 1;
+            ids[*index as usize] = id;
+            batch.append_row(&[&id, *name])?;
+        }
+        batch.execute()?;
+
+        // Les valeurs ajoutées ensuite reçoivent un identifiant au-delà des valeurs insérées
+        conn.execute(
+            &format!("ALTER TABLE {} MODIFY {} GENERATED BY DEFAULT AS IDENTITY (START WITH LIMIT VALUE)", self.table, self.id_column),
+            &[],
+        )?;
+
+        println!("Insertion de {} associations dans {}...", values.links.len(), self.junction);
+        let mut batch = conn
+            .batch(
+                &format!("INSERT INTO {} (tmdb_id, {}, position) VALUES (:1, :2, :3)", self.junction, self.id_column),
+                32768,
+            )
+            .build()?;
+        for (tmdb_id, index, position) in &values.links {
+            batch.append_row(&[tmdb_id, &ids[*index as usize], position])?;
+        }
+        batch.execute()?;
+
+        conn.commit()?;
+
+        Ok(())
+    }
+}
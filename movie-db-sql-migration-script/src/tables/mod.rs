@@ -1,5 +1,12 @@
 pub mod movie;
+pub mod lookup;
+pub mod content;
 pub mod rating;
 pub mod link;
 pub mod tag;
 pub mod user;
+pub mod title_trigram;
+pub mod similarity;
+pub mod recommendation;
+pub mod weighted_rating;
+pub mod trash;
@@ -78,7 +78,8 @@ impl Movie {
             production_companies CLOB,
             production_countries CLOB,
             spoken_languages CLOB,
-            keywords CLOB
+            keywords CLOB,
+            row_version NUMBER DEFAULT 1 NOT NULL
         ) 
         TABLESPACE movie_db_tbs",
             &[],
@@ -86,6 +87,8 @@ impl Movie {
         
         // Création de l'index sur le titre
         conn.execute("CREATE INDEX TMDB_movie_dataset_title_idx ON TMDB_movie_dataset(title)", &[])?;
+        // Index fonctionnel pour l'autocomplétion (LOWER(title) LIKE 'prefixe%')
+        conn.execute("CREATE INDEX TMDB_movie_dataset_lower_title_idx ON TMDB_movie_dataset(LOWER(title))", &[])?;
 
         // Role GRANT
         conn.execute("GRANT SELECT ON TMDB_movie_dataset TO movie_db_user", &[])?;
@@ -97,6 +100,47 @@ impl Movie {
         Ok(())
     }
 
+    /// Index Oracle Text sur le titre, le résumé, l'accroche et les mots-clés.
+    /// À créer après le chargement des films : l'index est construit en une fois.
+    pub fn create_text_index(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Création de l'index plein texte TMDB_movie_text_idx...");
+
+        conn.execute("GRANT EXECUTE ON CTXSYS.CTX_DDL TO SYSTEM", &[]).ok();
+
+        conn.execute("DROP INDEX TMDB_movie_text_idx FORCE", &[]).ok();
+        conn.execute("BEGIN CTX_DDL.DROP_PREFERENCE('movie_text_ds'); END;", &[]).ok();
+        conn.execute("BEGIN CTX_DDL.DROP_SECTION_GROUP('movie_text_sg'); END;", &[]).ok();
+
+        // Les quatre colonnes sont indexées comme un seul document
+        conn.execute(
+            "BEGIN
+                CTX_DDL.CREATE_PREFERENCE('movie_text_ds', 'MULTI_COLUMN_DATASTORE');
+                CTX_DDL.SET_ATTRIBUTE('movie_text_ds', 'COLUMNS', 'title, overview, tagline, keywords');
+
+                CTX_DDL.CREATE_SECTION_GROUP('movie_text_sg', 'BASIC_SECTION_GROUP');
+                CTX_DDL.ADD_FIELD_SECTION('movie_text_sg', 'title', 'title', TRUE);
+                CTX_DDL.ADD_FIELD_SECTION('movie_text_sg', 'overview', 'overview', TRUE);
+                CTX_DDL.ADD_FIELD_SECTION('movie_text_sg', 'tagline', 'tagline', TRUE);
+                CTX_DDL.ADD_FIELD_SECTION('movie_text_sg', 'keywords', 'keywords', TRUE);
+            END;",
+            &[],
+        )?;
+
+        // SYNC (ON COMMIT) : l'index suit les modifications de la table
+        conn.execute(
+            "CREATE INDEX TMDB_movie_text_idx ON TMDB_movie_dataset(overview)
+            INDEXTYPE IS CTXSYS.CONTEXT
+            PARAMETERS ('DATASTORE movie_text_ds SECTION GROUP movie_text_sg SYNC (ON COMMIT)')",
+            &[],
+        )?;
+
+        // Les extraits (CTX_DOC.SNIPPET) sont calculés avec les droits de l'utilisateur
+        conn.execute("GRANT EXECUTE ON CTXSYS.CTX_DOC TO movie_db_user", &[])?;
+        conn.execute("GRANT EXECUTE ON CTXSYS.CTX_DOC TO movie_db_admin", &[])?;
+
+        Ok(())
+    }
+
     /// Génère la oommande SQL pour l'insertion en batch
     pub fn batch_insert_statement() -> String {
         "
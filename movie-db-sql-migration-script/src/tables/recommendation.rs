@@ -0,0 +1,203 @@
+use movie_db_models::recommendation::{FactorModel, EPOCHS, FACTOR_COUNT, LEARNING_RATE, MODEL_VERSION, REGULARIZATION};
+use oracle::Connection;
+
+// Part des notes (en %) mises de côté par l'évaluation
+pub const HOLDOUT_PERCENT: u64 = 10;
+
+/// Tables du modèle de recommandation : historique des entraînements et facteurs du modèle actif
+pub struct RatingModel;
+
+impl RatingModel {
+    pub fn create_table(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Suppression des tables Rating_Models, User_Factors et Movie_Factors...");
+        for table in ["Rating_Models", "User_Factors", "Movie_Factors"] {
+            conn.execute(&format!("DROP PUBLIC SYNONYM {}", table), &[]).ok(); // Ignore l'erreur si le synonyme n'existe pas
+            conn.execute(&format!("DROP TABLE {} CASCADE CONSTRAINTS", table), &[]).ok();
+        }
+
+        println!("Création des tables Rating_Models, User_Factors et Movie_Factors...");
+
+        // Une ligne par entraînement ('TRAIN') ou évaluation ('EVALUATE') ; active : modèle
+        // dont les facteurs sont dans User_Factors et Movie_Factors
+        conn.execute(
+            "CREATE TABLE Rating_Models (
+                model_id NUMBER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+                model_version NUMBER NOT NULL,
+                run_mode VARCHAR2(10) NOT NULL CHECK (run_mode IN ('TRAIN', 'EVALUATE')),
+                factor_count NUMBER NOT NULL,
+                epochs NUMBER NOT NULL,
+                learning_rate NUMBER NOT NULL,
+                regularization NUMBER NOT NULL,
+                global_mean NUMBER NOT NULL,
+                train_ratings NUMBER NOT NULL,
+                holdout_ratings NUMBER NOT NULL,
+                rmse NUMBER,
+                active NUMBER(1) DEFAULT 0 NOT NULL,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            )
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        // factors : FACTOR_COUNT valeurs séparées par des virgules
+        conn.execute(
+            "CREATE TABLE User_Factors (
+                user_id NUMBER PRIMARY KEY,
+                bias NUMBER NOT NULL,
+                factors VARCHAR2(4000) NOT NULL
+            )
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        conn.execute(
+            "CREATE TABLE Movie_Factors (
+                movie_id NUMBER PRIMARY KEY,
+                bias NUMBER NOT NULL,
+                factors VARCHAR2(4000) NOT NULL
+            )
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        // Role GRANT : les tables ne sont alimentées que par le script
+        for table in ["Rating_Models", "User_Factors", "Movie_Factors"] {
+            conn.execute(&format!("GRANT SELECT ON {} TO movie_db_user", table), &[])?;
+            conn.execute(&format!("GRANT SELECT ON {} TO movie_db_admin", table), &[])?;
+
+            // Synonym
+            conn.execute(&format!("CREATE PUBLIC SYNONYM {} FOR SYSTEM.{}", table, table), &[])?;
+        }
+
+        Ok(())
+    }
+
+    /// Entraîne le modèle sur toutes les notes et remplace les facteurs du modèle actif,
+    /// en une transaction
+    pub fn train(conn: &Connection) -> Result<(), oracle::Error> {
+        let ratings = Self::read_ratings(conn)?;
+
+        println!("Entraînement du modèle sur {} notes...", ratings.len());
+        let model = FactorModel::train(&ratings);
+        print_training_rmse(&model);
+
+        println!(
+            "Insertion des facteurs de {} utilisateurs et {} films...",
+            model.user_ids.len(),
+            model.movie_ids.len()
+        );
+        conn.execute("DELETE FROM User_Factors", &[])?;
+        conn.execute("DELETE FROM Movie_Factors", &[])?;
+
+        let mut batch = conn
+            .batch("INSERT INTO User_Factors (user_id, bias, factors) VALUES (:1, :2, :3)", 32768)
+            .build()?;
+        for (user_id, factors) in model.user_ids.iter().zip(&model.user_factors) {
+            batch.append_row(&[user_id, &factors.bias, &factors.format()])?;
+        }
+        batch.execute()?;
+
+        let mut batch = conn
+            .batch("INSERT INTO Movie_Factors (movie_id, bias, factors) VALUES (:1, :2, :3)", 32768)
+            .build()?;
+        for (movie_id, factors) in model.movie_ids.iter().zip(&model.movie_factors) {
+            batch.append_row(&[movie_id, &factors.bias, &factors.format()])?;
+        }
+        batch.execute()?;
+
+        conn.execute("UPDATE Rating_Models SET active = 0", &[])?;
+        Self::record_run(conn, "TRAIN", &model, ratings.len(), 0, None, true)?;
+
+        conn.commit()?;
+
+        Ok(())
+    }
+
+    /// Évaluation : entraîne le modèle sans les notes mises de côté et mesure le RMSE sur
+    /// ces dernières. Le résultat est enregistré dans Rating_Models, les facteurs actifs ne changent pas.
+    pub fn evaluate(conn: &Connection) -> Result<(), oracle::Error> {
+        let ratings = Self::read_ratings(conn)?;
+        let (holdout, training): (Vec<_>, Vec<_>) = ratings
+            .into_iter()
+            .partition(|&(user_id, movie_id, _)| is_holdout(user_id, movie_id));
+
+        println!(
+            "Entraînement du modèle sur {} notes, évaluation sur {} notes...",
+            training.len(),
+            holdout.len()
+        );
+        let model = FactorModel::train(&training);
+        print_training_rmse(&model);
+        let rmse = model.rmse(&holdout);
+
+        println!("RMSE du modèle (version {}) sur les notes mises de côté : {:.4}", MODEL_VERSION, rmse);
+
+        Self::record_run(conn, "EVALUATE", &model, training.len(), holdout.len(), Some(rmse), false)?;
+        conn.commit()?;
+
+        Ok(())
+    }
+
+    fn read_ratings(conn: &Connection) -> Result<Vec<(i32, i32, f64)>, oracle::Error> {
+        println!("Lecture des notes MovieLens...");
+        let rows = conn.query_as::<(i32, i32, f64)>(
+            "SELECT user_id, movie_id, rating
+            FROM MovieLens_Ratings
+            ORDER BY user_id, movie_id",
+            &[],
+        )?;
+
+        let mut ratings = Vec::new();
+        for row in rows {
+            ratings.push(row?);
+        }
+        Ok(ratings)
+    }
+
+    fn record_run(
+        conn: &Connection,
+        run_mode: &str,
+        model: &FactorModel,
+        train_ratings: usize,
+        holdout_ratings: usize,
+        rmse: Option<f64>,
+        active: bool,
+    ) -> Result<(), oracle::Error> {
+        conn.execute(
+            "INSERT INTO Rating_Models (
+                model_version, run_mode, factor_count, epochs, learning_rate, regularization,
+                global_mean, train_ratings, holdout_ratings, rmse, active
+            ) VALUES (:1, :2, :3, :4, :5, :6, :7, :8, :9, :10, :11)",
+            &[
+                &MODEL_VERSION,
+                &run_mode,
+                &(FACTOR_COUNT as u32),
+                &(EPOCHS as u32),
+                &LEARNING_RATE,
+                &REGULARIZATION,
+                &model.global_mean,
+                &(train_ratings as u64),
+                &(holdout_ratings as u64),
+                &rmse,
+                &if active { 1 } else { 0 },
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+fn print_training_rmse(model: &FactorModel) {
+    for (epoch, rmse) in model.training_rmse.iter().enumerate() {
+        println!("Époque {}/{} : RMSE d'entraînement {:.4}", epoch + 1, EPOCHS, rmse);
+    }
+}
+
+/// Une note appartient à l'ensemble d'évaluation selon un hachage de (user_id, movie_id) :
+/// le découpage ne dépend pas de l'ordre de lecture
+fn is_holdout(user_id: i32, movie_id: i32) -> bool {
+    let mut x = ((user_id as u32 as u64) << 32) | movie_id as u32 as u64;
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^= x >> 31;
+    x % 100 < HOLDOUT_PERCENT
+}
@@ -0,0 +1,79 @@
+use movie_db_models::similarity::movie_neighbours;
+use oracle::Connection;
+
+/// Table des voisins des films, calculés à partir des notes des utilisateurs ayant noté les deux films
+pub struct MovieSimilarity;
+
+impl MovieSimilarity {
+    pub fn create_table(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Suppression de la table Movie_Similarities...");
+        conn.execute("DROP PUBLIC SYNONYM Movie_Similarities", &[]).ok(); // Ignore l'erreur si le synonyme n'existe pas
+        conn.execute("DROP TABLE Movie_Similarities CASCADE CONSTRAINTS", &[]).ok();
+
+        println!("Création de la table Movie_Similarities...");
+
+        // rank : 1 pour le voisin le plus proche
+        conn.execute(
+            "CREATE TABLE Movie_Similarities (
+                movie_id NUMBER NOT NULL,
+                similar_movie_id NUMBER NOT NULL,
+                rank NUMBER NOT NULL,
+                similarity NUMBER NOT NULL,
+                co_ratings NUMBER NOT NULL,
+                CONSTRAINT pk_movie_similarities PRIMARY KEY (movie_id, similar_movie_id)
+            )
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        // Role GRANT
+        conn.execute("GRANT SELECT ON Movie_Similarities TO movie_db_user", &[])?;
+        conn.execute("GRANT SELECT, INSERT, UPDATE, DELETE ON Movie_Similarities TO movie_db_admin", &[])?;
+
+        // Synonym
+        conn.execute("CREATE PUBLIC SYNONYM Movie_Similarities FOR SYSTEM.Movie_Similarities", &[])?;
+
+        Ok(())
+    }
+
+    /// Recalcule toute la table à partir de `MovieLens_Ratings`, en une transaction.
+    /// Le calcul ne dépend que des notes : deux exécutions sur les mêmes données
+    /// produisent la même table.
+    pub fn compute(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Lecture des notes MovieLens...");
+        let rows = conn.query_as::<(i32, i32, f64)>(
+            "SELECT user_id, movie_id, rating
+            FROM MovieLens_Ratings
+            WHERE movie_id IN (SELECT movie_id FROM MovieLens_Links)
+            ORDER BY user_id, movie_id",
+            &[],
+        )?;
+
+        let mut ratings = Vec::new();
+        for row in rows {
+            ratings.push(row?);
+        }
+
+        println!("Calcul des similarités entre {} notes...", ratings.len());
+        let similarities = movie_neighbours(&ratings);
+
+        println!("Insertion de {} voisins dans Movie_Similarities...", similarities.len());
+        conn.execute("DELETE FROM Movie_Similarities", &[])?;
+
+        let mut batch = conn
+            .batch(
+                "INSERT INTO Movie_Similarities (movie_id, similar_movie_id, rank, similarity, co_ratings)
+                VALUES (:1, :2, :3, :4, :5)",
+                32768,
+            )
+            .build()?;
+        for s in &similarities {
+            batch.append_row(&[&s.movie_id, &s.similar_movie_id, &s.rank, &s.similarity, &s.co_ratings])?;
+        }
+        batch.execute()?;
+
+        conn.commit()?;
+
+        Ok(())
+    }
+}
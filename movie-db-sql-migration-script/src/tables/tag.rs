@@ -41,6 +41,35 @@ impl Tag {
         Ok(())
     }
 
+    /// Crée la table temporaire `Tag_Moderation_Changes`, où l'application dépose les tags
+    /// d'une modération avant d'appeler `ApplyTagModeration`
+    pub fn create_moderation_table(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Suppression de la table Tag_Moderation_Changes...");
+        conn.execute("DROP PUBLIC SYNONYM Tag_Moderation_Changes", &[]).ok(); // Ignore l'erreur si le synonyme n'existe pas
+        conn.execute("DROP TABLE Tag_Moderation_Changes", &[]).ok();
+
+        // Propre à chaque session et vidée au commit ; new_tag NULL pour une suppression
+        println!("Création de la table Tag_Moderation_Changes...");
+        conn.execute(
+            "CREATE GLOBAL TEMPORARY TABLE Tag_Moderation_Changes (
+                movie_id NUMBER,
+                user_id NUMBER,
+                timestamp NUMBER,
+                tag VARCHAR2(255),
+                new_tag VARCHAR2(255)
+            )
+            ON COMMIT DELETE ROWS",
+            &[],
+        )?;
+
+        // Role GRANT
+        conn.execute("GRANT INSERT ON Tag_Moderation_Changes TO movie_db_admin", &[])?;
+
+        // Synonym
+        conn.execute("CREATE PUBLIC SYNONYM Tag_Moderation_Changes FOR SYSTEM.Tag_Moderation_Changes", &[])?;
+        Ok(())
+    }
+
     /// Génère la commande SQL pour l'insertion en batch
     pub fn batch_insert_statement() -> String {
         "
@@ -0,0 +1,158 @@
+use oracle::Connection;
+
+/// Index des trigrammes des titres, utilisé par la recherche approchée de l'application
+pub struct TitleTrigram;
+
+impl TitleTrigram {
+    pub fn create_table(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Suppression des tables Movie_Title_Index et Movie_Title_Trigrams...");
+        conn.execute("DROP PUBLIC SYNONYM Movie_Title_Index", &[]).ok(); // Ignore l'erreur si le synonyme n'existe pas
+        conn.execute("DROP PUBLIC SYNONYM Movie_Title_Trigrams", &[]).ok();
+        conn.execute("DROP TABLE Movie_Title_Trigrams CASCADE CONSTRAINTS", &[]).ok();
+        conn.execute("DROP TABLE Movie_Title_Index CASCADE CONSTRAINTS", &[]).ok();
+
+        println!("Création des tables Movie_Title_Index et Movie_Title_Trigrams...");
+
+        // Une ligne par titre indexé : 'T' pour le titre, 'O' pour le titre original s'il diffère
+        conn.execute(
+            "CREATE TABLE Movie_Title_Index (
+                tmdb_id NUMBER NOT NULL,
+                variant CHAR(1) NOT NULL,
+                title VARCHAR2(768) NOT NULL,
+                trigram_count NUMBER NOT NULL,
+                CONSTRAINT pk_movie_title_index PRIMARY KEY (tmdb_id, variant)
+            )
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        // Table organisée en index : la recherche ne lit que la clé primaire
+        conn.execute(
+            "CREATE TABLE Movie_Title_Trigrams (
+                trigram VARCHAR2(3 CHAR) NOT NULL,
+                tmdb_id NUMBER NOT NULL,
+                variant CHAR(1) NOT NULL,
+                CONSTRAINT pk_movie_title_trigrams PRIMARY KEY (trigram, tmdb_id, variant)
+            )
+            ORGANIZATION INDEX
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        conn.execute("CREATE INDEX Movie_Title_Trigrams_tmdb_id_idx ON Movie_Title_Trigrams(tmdb_id)", &[])?;
+
+        // Role GRANT : les tables sont alimentées uniquement par le trigger TMDB_movie_title_index
+        conn.execute("GRANT SELECT ON Movie_Title_Index TO movie_db_user", &[])?;
+        conn.execute("GRANT SELECT ON Movie_Title_Index TO movie_db_admin", &[])?;
+        conn.execute("GRANT SELECT ON Movie_Title_Trigrams TO movie_db_user", &[])?;
+        conn.execute("GRANT SELECT ON Movie_Title_Trigrams TO movie_db_admin", &[])?;
+
+        // Synonym
+        conn.execute("CREATE PUBLIC SYNONYM Movie_Title_Index FOR SYSTEM.Movie_Title_Index", &[])?;
+        conn.execute("CREATE PUBLIC SYNONYM Movie_Title_Trigrams FOR SYSTEM.Movie_Title_Trigrams", &[])?;
+
+        Ok(())
+    }
+
+    /// Procédure `IndexMovieTitle` et trigger qui maintient l'index à jour.
+    /// Le découpage doit rester identique à `sql::trigram::trigrams` côté application :
+    /// minuscules, ponctuation remplacée par des espaces, chaque mot complété en '  mot '.
+    pub fn create_procedure(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Création de la procédure IndexMovieTitle...");
+
+        conn.execute(
+            "CREATE OR REPLACE PROCEDURE IndexMovieTitle(
+                p_tmdb_id IN NUMBER,
+                p_title IN VARCHAR2,
+                p_original_title IN VARCHAR2
+            ) AS
+                PROCEDURE index_variant(p_variant IN CHAR, p_text IN VARCHAR2) IS
+                    v_normalized VARCHAR2(4000);
+                    v_padded VARCHAR2(4000);
+                    v_word VARCHAR2(4000);
+                    v_count NUMBER;
+                    i PLS_INTEGER := 1;
+                BEGIN
+                    v_normalized := TRIM(REGEXP_REPLACE(LOWER(p_text), '[^[:alnum:]]+', ' '));
+
+                    LOOP
+                        v_word := REGEXP_SUBSTR(v_normalized, '[^ ]+', 1, i);
+                        EXIT WHEN v_word IS NULL;
+
+                        v_padded := '  ' || v_word || ' ';
+                        FOR j IN 1 .. LENGTH(v_padded) - 2 LOOP
+                            BEGIN
+                                INSERT INTO Movie_Title_Trigrams (trigram, tmdb_id, variant)
+                                VALUES (SUBSTR(v_padded, j, 3), p_tmdb_id, p_variant);
+                            EXCEPTION
+                                -- Trigramme déjà présent dans ce titre
+                                WHEN DUP_VAL_ON_INDEX THEN NULL;
+                            END;
+                        END LOOP;
+
+                        i := i + 1;
+                    END LOOP;
+
+                    SELECT COUNT(*) INTO v_count
+                    FROM Movie_Title_Trigrams
+                    WHERE tmdb_id = p_tmdb_id AND variant = p_variant;
+
+                    IF v_count > 0 THEN
+                        INSERT INTO Movie_Title_Index (tmdb_id, variant, title, trigram_count)
+                        VALUES (p_tmdb_id, p_variant, p_text, v_count);
+                    END IF;
+                END;
+            BEGIN
+                DELETE FROM Movie_Title_Trigrams WHERE tmdb_id = p_tmdb_id;
+                DELETE FROM Movie_Title_Index WHERE tmdb_id = p_tmdb_id;
+
+                IF p_title IS NOT NULL THEN
+                    index_variant('T', p_title);
+                END IF;
+
+                -- Le titre original n'est indexé que s'il diffère du titre
+                IF p_original_title IS NOT NULL AND (p_title IS NULL OR LOWER(p_original_title) <> LOWER(p_title)) THEN
+                    index_variant('O', p_original_title);
+                END IF;
+            END;",
+            &[],
+        )?;
+
+        println!("Création du trigger TMDB_movie_title_index...");
+
+        // Les titres sont passés en paramètres : la procédure ne relit pas la table en mutation
+        conn.execute(
+            "CREATE OR REPLACE TRIGGER TMDB_movie_title_index
+            AFTER INSERT OR UPDATE OF title, original_title OR DELETE ON TMDB_movie_dataset
+            FOR EACH ROW
+            BEGIN
+                IF DELETING THEN
+                    DELETE FROM Movie_Title_Trigrams WHERE tmdb_id = :OLD.id;
+                    DELETE FROM Movie_Title_Index WHERE tmdb_id = :OLD.id;
+                ELSE
+                    IndexMovieTitle(:NEW.id, :NEW.title, :NEW.original_title);
+                END IF;
+            END;",
+            &[],
+        )?;
+
+        Ok(())
+    }
+
+    /// Indexe les films déjà chargés ; les suivants le sont par le trigger
+    pub fn index_movies(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Indexation des trigrammes des titres...");
+
+        conn.execute(
+            "BEGIN
+                FOR m IN (SELECT id, title, original_title FROM TMDB_movie_dataset) LOOP
+                    IndexMovieTitle(m.id, m.title, m.original_title);
+                END LOOP;
+            END;",
+            &[],
+        )?;
+        conn.commit()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+use oracle::Connection;
+
+/// Corbeille MovieLens : les utilisateurs, liens et tags supprimés sont archivés avec
+/// les notes et tags supprimés en cascade, et peuvent être restaurés
+pub struct Trash;
+
+impl Trash {
+    pub fn create_table(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Suppression des tables MovieLens_Deletions, Deleted_MovieLens_Ratings et Deleted_MovieLens_Tags...");
+        for table in ["Deleted_MovieLens_Ratings", "Deleted_MovieLens_Tags", "MovieLens_Deletions"] {
+            conn.execute(&format!("DROP PUBLIC SYNONYM {}", table), &[]).ok(); // Ignore l'erreur si le synonyme n'existe pas
+            conn.execute(&format!("DROP TABLE {} CASCADE CONSTRAINTS", table), &[]).ok();
+        }
+
+        println!("Création des tables MovieLens_Deletions, Deleted_MovieLens_Ratings et Deleted_MovieLens_Tags...");
+
+        // Une ligne par suppression : utilisateur ('User'), lien MovieLens <-> TMDB ('Movie') ou tag ('Tag').
+        // imdb_id et tmdb_id gardent le lien supprimé ; deleted_at est un timestamp Unix, comme ceux de MovieLens
+        conn.execute(
+            "CREATE TABLE MovieLens_Deletions (
+                deletion_id NUMBER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+                kind VARCHAR2(5) NOT NULL CHECK (kind IN ('User', 'Movie', 'Tag')),
+                user_id NUMBER,
+                movie_id NUMBER,
+                imdb_id VARCHAR2(20),
+                tmdb_id NUMBER,
+                deleted_by VARCHAR2(128),
+                deleted_at NUMBER NOT NULL
+            )
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        // Lignes archivées par chaque suppression, effacées à la restauration
+        conn.execute(
+            "CREATE TABLE Deleted_MovieLens_Ratings (
+                deletion_id NUMBER NOT NULL REFERENCES MovieLens_Deletions (deletion_id) ON DELETE CASCADE,
+                user_id NUMBER,
+                movie_id NUMBER,
+                rating NUMBER(2, 1),
+                timestamp NUMBER
+            )
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        conn.execute(
+            "CREATE TABLE Deleted_MovieLens_Tags (
+                deletion_id NUMBER NOT NULL REFERENCES MovieLens_Deletions (deletion_id) ON DELETE CASCADE,
+                user_id NUMBER,
+                movie_id NUMBER,
+                tag VARCHAR2(255),
+                timestamp NUMBER
+            )
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        // Index
+        conn.execute("CREATE INDEX MovieLens_Deletions_user_id_idx ON MovieLens_Deletions(user_id)", &[])?;
+        conn.execute("CREATE INDEX MovieLens_Deletions_movie_id_idx ON MovieLens_Deletions(movie_id)", &[])?;
+        conn.execute("CREATE INDEX Deleted_Ratings_deletion_idx ON Deleted_MovieLens_Ratings(deletion_id)", &[])?;
+        conn.execute("CREATE INDEX Deleted_Tags_deletion_idx ON Deleted_MovieLens_Tags(deletion_id)", &[])?;
+
+        // Role GRANT : la corbeille n'est alimentée que par les triggers et les procédures
+        for table in ["MovieLens_Deletions", "Deleted_MovieLens_Ratings", "Deleted_MovieLens_Tags"] {
+            conn.execute(&format!("GRANT SELECT ON {} TO movie_db_admin", table), &[])?;
+
+            // Synonym
+            conn.execute(&format!("CREATE PUBLIC SYNONYM {} FOR SYSTEM.{}", table, table), &[])?;
+        }
+
+        Ok(())
+    }
+
+    /// Procédure `RestoreMovieLensDeletion` : remet en place un élément de la corbeille et tout ce qui
+    /// a été supprimé en cascade avec lui, puis le retire de la corbeille
+    pub fn create_procedure(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Création de la procédure RestoreMovieLensDeletion...");
+
+        conn.execute(
+            "CREATE OR REPLACE PROCEDURE RestoreMovieLensDeletion(
+                d_id IN NUMBER,
+                restored_ratings OUT NUMBER,
+                restored_tags OUT NUMBER
+            ) AS
+                v_kind MovieLens_Deletions.kind%TYPE;
+                v_user_id NUMBER;
+                v_movie_id NUMBER;
+                v_imdb_id MovieLens_Deletions.imdb_id%TYPE;
+                v_tmdb_id NUMBER;
+                v_blocking NUMBER;
+                v_count NUMBER;
+            BEGIN
+                BEGIN
+                    SELECT kind, user_id, movie_id, imdb_id, tmdb_id
+                    INTO v_kind, v_user_id, v_movie_id, v_imdb_id, v_tmdb_id
+                    FROM MovieLens_Deletions
+                    WHERE deletion_id = d_id
+                    FOR UPDATE;
+                EXCEPTION
+                    WHEN NO_DATA_FOUND THEN
+                        RAISE_APPLICATION_ERROR(-20011, 'Élément introuvable dans la corbeille : ' || d_id);
+                END;
+
+                -- Les lignes archivées dont l'utilisateur ou le film est encore dans la corbeille
+                -- attendent que celui-ci soit restauré
+                SELECT MIN(d.deletion_id) INTO v_blocking
+                FROM MovieLens_Deletions d
+                WHERE d.deletion_id <> d_id
+                AND (
+                    (
+                        d.kind = 'User'
+                        AND d.user_id IN (
+                            SELECT user_id FROM Deleted_MovieLens_Ratings WHERE deletion_id = d_id
+                            UNION
+                            SELECT user_id FROM Deleted_MovieLens_Tags WHERE deletion_id = d_id
+                        )
+                        AND NOT EXISTS (SELECT 1 FROM MovieLens_Users u WHERE u.user_id = d.user_id)
+                    )
+                    OR (
+                        d.kind = 'Movie'
+                        AND d.movie_id IN (
+                            SELECT movie_id FROM Deleted_MovieLens_Ratings WHERE deletion_id = d_id
+                            UNION
+                            SELECT movie_id FROM Deleted_MovieLens_Tags WHERE deletion_id = d_id
+                        )
+                        AND NOT EXISTS (SELECT 1 FROM MovieLens_Links l WHERE l.movie_id = d.movie_id)
+                    )
+                );
+                IF v_blocking IS NOT NULL THEN
+                    RAISE_APPLICATION_ERROR(-20012, 'Restaurez d''abord l''élément ' || v_blocking || ' de la corbeille');
+                END IF;
+
+                IF v_kind = 'User' THEN
+                    -- L'identifiant a pu être réutilisé depuis la suppression
+                    MERGE INTO MovieLens_Users t
+                    USING (SELECT v_user_id AS user_id FROM DUAL) s ON (t.user_id = s.user_id)
+                    WHEN NOT MATCHED THEN INSERT (user_id) VALUES (s.user_id);
+                ELSIF v_kind = 'Movie' THEN
+                    SELECT COUNT(*) INTO v_count
+                    FROM MovieLens_Links
+                    WHERE movie_id = v_movie_id OR tmdb_id = v_tmdb_id;
+                    IF v_count > 0 THEN
+                        RAISE_APPLICATION_ERROR(-20012, 'Le film ' || v_movie_id || ' (TMDB ' || v_tmdb_id || ') est de nouveau relié');
+                    END IF;
+
+                    INSERT INTO MovieLens_Links (movie_id, imdb_id, tmdb_id)
+                    VALUES (v_movie_id, v_imdb_id, v_tmdb_id);
+                END IF;
+
+                -- Une note ou un tag ajouté depuis la suppression est conservé
+                INSERT INTO MovieLens_Ratings (user_id, movie_id, rating, timestamp)
+                SELECT r.user_id, r.movie_id, r.rating, r.timestamp
+                FROM Deleted_MovieLens_Ratings r
+                WHERE r.deletion_id = d_id
+                AND NOT EXISTS (
+                    SELECT 1 FROM MovieLens_Ratings x
+                    WHERE x.user_id = r.user_id AND x.movie_id = r.movie_id
+                );
+                restored_ratings := SQL%ROWCOUNT;
+
+                INSERT INTO MovieLens_Tags (user_id, movie_id, tag, timestamp)
+                SELECT t.user_id, t.movie_id, t.tag, t.timestamp
+                FROM Deleted_MovieLens_Tags t
+                WHERE t.deletion_id = d_id
+                AND NOT EXISTS (
+                    SELECT 1 FROM MovieLens_Tags x
+                    WHERE x.user_id = t.user_id AND x.movie_id = t.movie_id AND x.timestamp = t.timestamp AND x.tag = t.tag
+                );
+                restored_tags := SQL%ROWCOUNT;
+
+                LogAdminAction(
+                    'RestoreTrashItem',
+                    'trash:' || d_id,
+                    'kind=' || v_kind || ', restored_ratings=' || restored_ratings || ', restored_tags=' || restored_tags
+                );
+
+                -- Les lignes archivées sont supprimées en cascade
+                DELETE FROM MovieLens_Deletions
+                WHERE deletion_id = d_id;
+
+                -- Effectuer un commit pour enregistrer les modifications
+                COMMIT;
+            EXCEPTION
+                WHEN OTHERS THEN
+                    ROLLBACK;
+                    RAISE;
+            END;",
+            &[],
+        )?;
+
+        // Grant
+        conn.execute("GRANT EXECUTE ON RestoreMovieLensDeletion TO movie_db_admin", &[])?;
+
+        // Synonym
+        conn.execute("DROP PUBLIC SYNONYM RestoreMovieLensDeletion", &[]).ok();
+        conn.execute("CREATE PUBLIC SYNONYM RestoreMovieLensDeletion FOR SYSTEM.RestoreMovieLensDeletion", &[])?;
+
+        conn.commit()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use std::env;
+
+use oracle::Connection;
+
+// Paramètres par défaut du score bayésien, identiques à la migration SQLite de l'application
+pub const DEFAULT_MIN_VOTES: f64 = 200.0;
+pub const DEFAULT_MOVIELENS_WEIGHT: f64 = 0.0;
+pub const DEFAULT_MOVIELENS_MIN_RATINGS: f64 = 10.0;
+
+/// Score pondéré des films (classement « Top Rated »), moyenne bayésienne à la IMDb :
+/// `(v * R + m * C) / (v + m)` avec `v` votes de moyenne `R`, `m` votes minimum et `C` l'a priori
+pub struct WeightedRating {
+    pub min_votes: f64,
+    /// `None` : moyenne des notes TMDB des films MovieLens
+    pub prior: Option<f64>,
+    /// Part de la moyenne MovieLens (ramenée sur 10) dans le score, entre 0 et 1
+    pub movielens_weight: f64,
+    pub movielens_min_ratings: f64,
+}
+
+impl WeightedRating {
+    /// Paramètres lus dans le fichier .env (`WEIGHTED_RATING_MIN_VOTES`, `WEIGHTED_RATING_PRIOR`,
+    /// `WEIGHTED_RATING_MOVIELENS_WEIGHT`, `WEIGHTED_RATING_MOVIELENS_MIN_RATINGS`), sinon valeurs par défaut
+    pub fn from_env() -> Self {
+        let number = |name: &str| {
+            env::var(name).ok().map(|value| {
+                value
+                    .trim()
+                    .parse::<f64>()
+                    .unwrap_or_else(|_| panic!("{} is not a number in .env file", name))
+            })
+        };
+
+        Self {
+            min_votes: number("WEIGHTED_RATING_MIN_VOTES").unwrap_or(DEFAULT_MIN_VOTES).max(1.0),
+            prior: number("WEIGHTED_RATING_PRIOR"),
+            movielens_weight: number("WEIGHTED_RATING_MOVIELENS_WEIGHT")
+                .unwrap_or(DEFAULT_MOVIELENS_WEIGHT)
+                .clamp(0.0, 1.0),
+            movielens_min_ratings: number("WEIGHTED_RATING_MOVIELENS_MIN_RATINGS")
+                .unwrap_or(DEFAULT_MOVIELENS_MIN_RATINGS)
+                .max(1.0),
+        }
+    }
+
+    pub fn create_table(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Suppression de la table Movie_Weighted_Ratings...");
+        conn.execute("DROP PUBLIC SYNONYM Movie_Weighted_Ratings", &[]).ok(); // Ignore l'erreur si le synonyme n'existe pas
+        conn.execute("DROP TABLE Movie_Weighted_Ratings CASCADE CONSTRAINTS", &[]).ok();
+
+        println!("Création de la table Movie_Weighted_Ratings...");
+
+        // Une ligne par film MovieLens relié à TMDB ; exposée par MovieShortView
+        conn.execute(
+            "CREATE TABLE Movie_Weighted_Ratings (
+                movie_id NUMBER PRIMARY KEY,
+                weighted_rating NUMBER NOT NULL
+            )
+            TABLESPACE movie_db_tbs",
+            &[],
+        )?;
+
+        // Role GRANT : la table n'est alimentée que par RefreshWeightedRatings
+        conn.execute("GRANT SELECT ON Movie_Weighted_Ratings TO movie_db_user", &[])?;
+        conn.execute("GRANT SELECT ON Movie_Weighted_Ratings TO movie_db_admin", &[])?;
+
+        // Synonym
+        conn.execute("CREATE PUBLIC SYNONYM Movie_Weighted_Ratings FOR SYSTEM.Movie_Weighted_Ratings", &[])?;
+
+        Ok(())
+    }
+
+    /// Procédure `RefreshWeightedRatings` : recalcule le score de tous les films en une transaction.
+    /// La moyenne MovieLens (sur 5) est doublée pour être comparable aux notes TMDB (sur 10).
+    pub fn create_procedure(conn: &Connection) -> Result<(), oracle::Error> {
+        println!("Création de la procédure RefreshWeightedRatings...");
+
+        conn.execute(
+            "CREATE OR REPLACE PROCEDURE RefreshWeightedRatings(
+                p_min_votes IN NUMBER,
+                p_prior IN NUMBER,
+                p_movielens_weight IN NUMBER,
+                p_movielens_min_ratings IN NUMBER
+            ) AS
+                v_prior NUMBER := p_prior;
+            BEGIN
+                IF p_min_votes <= 0 OR p_movielens_min_ratings <= 0 OR p_movielens_weight NOT BETWEEN 0 AND 1 THEN
+                    RAISE_APPLICATION_ERROR(
+                        -20004,
+                        'Paramètres du score pondéré invalides : min_votes=' || p_min_votes
+                            || ', movielens_weight=' || p_movielens_weight
+                            || ', movielens_min_ratings=' || p_movielens_min_ratings
+                    );
+                END IF;
+
+                -- A priori par défaut : moyenne des notes TMDB des films MovieLens
+                IF v_prior IS NULL THEN
+                    SELECT NVL(AVG(t.vote_average), 0) INTO v_prior
+                    FROM TMDB_movie_dataset t
+                    JOIN MovieLens_Links l ON l.tmdb_id = t.id
+                    WHERE t.vote_count > 0;
+                END IF;
+
+                DELETE FROM Movie_Weighted_Ratings;
+
+                INSERT INTO Movie_Weighted_Ratings (movie_id, weighted_rating)
+                SELECT
+                    l.movie_id,
+                    ROUND(
+                        (1 - p_movielens_weight)
+                            * (NVL(t.vote_count, 0) * NVL(t.vote_average, 0) + p_min_votes * v_prior)
+                            / (NVL(t.vote_count, 0) + p_min_votes)
+                        + p_movielens_weight
+                            * (NVL(r.rating_count, 0) * NVL(r.rating_mean, 0) * 2 + p_movielens_min_ratings * v_prior)
+                            / (NVL(r.rating_count, 0) + p_movielens_min_ratings),
+                        6
+                    )
+                FROM
+                    MovieLens_Links l
+                JOIN
+                    TMDB_movie_dataset t ON t.id = l.tmdb_id
+                LEFT JOIN (
+                    SELECT movie_id, COUNT(*) AS rating_count, AVG(rating) AS rating_mean
+                    FROM MovieLens_Ratings
+                    GROUP BY movie_id
+                ) r ON r.movie_id = l.movie_id;
+
+                COMMIT;
+            END;",
+            &[],
+        )?;
+
+        // Grant
+        conn.execute("GRANT EXECUTE ON RefreshWeightedRatings TO movie_db_admin", &[])?;
+
+        // Synonym
+        conn.execute("DROP PUBLIC SYNONYM RefreshWeightedRatings", &[]).ok();
+        conn.execute("CREATE PUBLIC SYNONYM RefreshWeightedRatings FOR SYSTEM.RefreshWeightedRatings", &[])?;
+
+        Ok(())
+    }
+
+    /// Recalcule les scores avec ces paramètres
+    pub fn refresh(&self, conn: &Connection) -> Result<(), oracle::Error> {
+        println!(
+            "Calcul du score pondéré (votes minimum : {}, part MovieLens : {})...",
+            self.min_votes, self.movielens_weight
+        );
+
+        conn.execute(
+            "BEGIN RefreshWeightedRatings(:1, :2, :3, :4); END;",
+            &[&self.min_votes, &self.prior, &self.movielens_weight, &self.movielens_min_ratings],
+        )?;
+
+        Ok(())
+    }
+}
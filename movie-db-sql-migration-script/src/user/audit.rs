@@ -0,0 +1,68 @@
+use oracle::Connection;
+
+pub fn create_audit_log_table(conn: &Connection) -> Result<(), oracle::Error> {
+    conn.execute("DROP PUBLIC SYNONYM Admin_Audit_Log", &[]).ok(); // Ignore l'erreur si le synonyme n'existe pas
+
+    conn.execute("DROP TABLE Admin_Audit_Log CASCADE CONSTRAINTS", &[]).ok(); // Ignore l'erreur si la table n'existe pas
+
+    println!("Création de la table Admin_Audit_Log...");
+
+    // Une ligne par action d'administration, écrite dans la transaction de l'action.
+    // target désigne l'élément modifié (movie:10, movielens_user:42, sql_user:BOB...),
+    // parameters les autres arguments (jamais de mot de passe) ; acted_at est un timestamp Unix
+    conn.execute(
+        "CREATE TABLE Admin_Audit_Log (
+                audit_id NUMBER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+                acted_by VARCHAR2(128) NOT NULL,
+                acted_at NUMBER NOT NULL,
+                action VARCHAR2(30) NOT NULL,
+                target VARCHAR2(255),
+                parameters VARCHAR2(4000)
+            )
+            TABLESPACE movie_db_tbs",
+        &[],
+    )?;
+
+    // Index
+    conn.execute("CREATE INDEX Admin_Audit_Log_acted_at_idx ON Admin_Audit_Log(acted_at)", &[])?;
+    conn.execute("CREATE INDEX Admin_Audit_Log_target_idx ON Admin_Audit_Log(target)", &[])?;
+
+    // Role GRANT : le journal n'est alimenté que par LogAdminAction
+    conn.execute("GRANT SELECT ON Admin_Audit_Log TO movie_db_admin", &[])?;
+
+    // Synonym
+    conn.execute("CREATE PUBLIC SYNONYM Admin_Audit_Log FOR SYSTEM.Admin_Audit_Log", &[])?;
+
+    conn.commit()?;
+    Ok(())
+}
+
+pub fn create_audit_procedure(conn: &Connection) -> Result<(), oracle::Error> {
+    println!("Création de la procédure LogAdminAction...");
+
+    // Journalise une action avec l'utilisateur Oracle de la session, sans commit :
+    // la ligne est validée ou annulée avec l'action qui l'appelle.
+    // Seules les procédures d'administration l'appellent : aucun rôle n'a le droit de l'exécuter,
+    // un administrateur ne peut donc pas écrire lui-même dans le journal
+    conn.execute("CREATE OR REPLACE PROCEDURE LogAdminAction(
+        p_action IN VARCHAR2,
+        p_target IN VARCHAR2,
+        p_parameters IN VARCHAR2
+    ) AS
+    BEGIN
+        INSERT INTO Admin_Audit_Log (acted_by, acted_at, action, target, parameters)
+        VALUES (
+            SYS_CONTEXT('USERENV', 'SESSION_USER'),
+            TRUNC((CAST(SYS_EXTRACT_UTC(SYSTIMESTAMP) AS DATE) - DATE '1970-01-01') * 86400),
+            p_action,
+            SUBSTR(p_target, 1, 255),
+            SUBSTR(p_parameters, 1, 4000)
+        );
+    END;", &[])?;
+
+    // Pas de synonyme public : les procédures appelantes appartiennent au même schéma
+    conn.execute("DROP PUBLIC SYNONYM LogAdminAction", &[]).ok(); // Ignore l'erreur si le synonyme n'existe pas
+
+    conn.commit()?;
+    Ok(())
+}
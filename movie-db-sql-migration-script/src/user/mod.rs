@@ -1,4 +1,5 @@
 pub mod tablespace;
 pub mod role;
 
-pub mod users_service;
\ No newline at end of file
+pub mod users_service;
+pub mod audit;
\ No newline at end of file
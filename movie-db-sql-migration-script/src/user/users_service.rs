@@ -44,6 +44,9 @@ pub fn create_users_service(conn: &Connection) -> Result<(), oracle::Error> {
         INSERT INTO USERS_RECORDS (username, is_admin)
             VALUES (username, is_admin);
 
+        -- Journaliser la création, sans le mot de passe
+        LogAdminAction('CreateSqlUser', 'sql_user:' || UPPER(username), 'is_admin=' || is_admin);
+
         -- Commit pour sauvegarder l'enregistrement
         COMMIT;
                 
@@ -83,6 +86,9 @@ pub fn create_users_service(conn: &Connection) -> Result<(), oracle::Error> {
         -- Supprimer l'utilisateur de la table Users_Records
         DELETE FROM USERS_RECORDS WHERE UPPER(username) = UPPER(u_name);
 
+        -- Journaliser la suppression
+        LogAdminAction('DeleteSqlUser', 'sql_user:' || UPPER(u_name), NULL);
+
         -- Commit des changements
         COMMIT;
     END;", &[])?;
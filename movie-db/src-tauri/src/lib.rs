@@ -1,13 +1,28 @@
 mod console;
 mod sql;
 
-use sql::types::genre::SearchGenre;
-use sql::types::movie::{Movie, MovieShort};
-use sql::types::search_filter::{SearchFilter};
+use sql::types::audit::{AuditEntry, AuditFilter};
+use sql::types::content::ContentMatch;
+use sql::types::facet::{FacetValue, MovieFacet};
+use sql::types::genre::Genre;
+use sql::types::movie::{check_rating, check_user_id, normalize_tag, Movie, MovieDetails, MovieMatch, MovieRating, MovieShort, MovieTag, MovieUpdate, NewMovie, Recommendation, SimilarMovie, TitleCompletion, DEFAULT_COMPLETION_LIMIT, DEFAULT_RECOMMENDATION_COUNT, DEFAULT_SIMILAR_LIMIT, MAX_COMPLETION_LIMIT, MAX_RECOMMENDATION_COUNT, MAX_SIMILAR_LIMIT};
+use sql::types::movie_lens_user::MovieLensUserProfile;
+use sql::types::page::{Page, PageRequest};
+use sql::types::search_filter::{ActivitySort, SearchFilter, SortOrder};
+use sql::types::search_query::{ActivityFilter, SearchQuery};
 use sql::types::sql_user::{SqlUser, SqlUserCredentials};
 use sql::types::stats::{CountStats, Stats};
+use sql::types::tag_moderation::{TagModeration, TagModerationPreview, TagModerationReport, DEFAULT_MODERATION_SAMPLE_SIZE, MAX_MODERATION_SAMPLE_SIZE};
+use sql::types::trash::{TrashItem, TrashKind, TrashRestore};
 
-use crate::sql::database;
+use std::path::PathBuf;
+
+use sql::config::ConnectionProfile;
+use sql::moderation::TagRule;
+use sql::search::{AuditLogSearch, FacetSearch, MovieActivitySearch, MovieSearch, TrashSearch, UserActivity, UserActivitySearch};
+use tauri::{AppHandle, Manager};
+
+use crate::sql::{profiles, store};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -17,15 +32,44 @@ pub fn run() {
   .invoke_handler(tauri::generate_handler![
     login_user,
     logout_user,
+
+    list_connection_profiles,
+    add_connection_profile,
+    edit_connection_profile,
+    remove_connection_profile,
+    test_connection_profile,
     
     get_logged_username,
     get_current_user,
     
     get_all_movies,
+    search_movies_full_text,
+    search_movies_fuzzy,
+    autocomplete_titles,
     get_movie,
+    get_movie_ratings_page,
+    get_movie_tags_page,
+    get_similar_movies_by_ratings,
+    get_similar_movies_by_content,
+    get_recommendations_for_user,
+    get_genres,
+    get_facet_values,
+    get_movies_by_facet,
 
+    get_movie_lens_user,
+    add_movie,
+    update_movie,
+    add_movie_lens_rating,
+    update_movie_lens_rating,
+    add_movie_lens_tag,
+    rename_movie_lens_tag,
+    preview_tag_moderation,
+    apply_tag_moderation,
     delete_movie_lens_user,
     delete_movie_lens_tag,
+    get_trash,
+    restore_trash_item,
+    get_audit_log,
 
     get_count_stats,
 
@@ -50,9 +94,11 @@ pub fn run() {
 }
 
 #[tauri::command(async)]
-async fn login_user(user: SqlUserCredentials) -> Result<bool, String> {
-  // Initialise la connexion Oracle au démarrage de l'application
-  match database::init_connection(user) {
+async fn login_user(app: AppHandle, user: SqlUserCredentials, profile: String) -> Result<bool, String> {
+  let profile = profiles::get_profile(&config_dir(&app)?, &profile)?.resolve_paths(&data_dir(&app)?);
+
+  // Initialise la connexion au backend du profil choisi
+  match store::init_store(profile, user).await {
     Ok(_) => Ok(true),
     Err(e) => Err(e.to_string()),
   }
@@ -60,87 +106,316 @@ async fn login_user(user: SqlUserCredentials) -> Result<bool, String> {
 
 #[tauri::command(async)]
 async fn logout_user() -> Result<bool, String> {
-  match database::close_connection() {
+  match store::close_store().await {
     Ok(_) => Ok(true),
-    Err(e) => Err(e),
+    Err(e) => Err(e.to_string()),
   }
 }
 
+// Connection profiles
+fn config_dir(app: &AppHandle) -> Result<PathBuf, String> {
+  app.path().app_config_dir().map_err(|e| e.to_string())
+}
+
+fn data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+  app.path().app_data_dir().map_err(|e| e.to_string())
+}
+
 #[tauri::command(async)]
-async fn get_logged_username() -> Result<String, String> {
-  let conn = database::get_connection()?;
+async fn list_connection_profiles(app: AppHandle) -> Result<Vec<ConnectionProfile>, String> {
+  profiles::list_profiles(&config_dir(&app)?)
+}
+
+#[tauri::command(async)]
+async fn add_connection_profile(app: AppHandle, profile: ConnectionProfile) -> Result<(), String> {
+  profiles::add_profile(&config_dir(&app)?, profile)
+}
+
+#[tauri::command(async)]
+async fn edit_connection_profile(app: AppHandle, name: String, profile: ConnectionProfile) -> Result<(), String> {
+  profiles::edit_profile(&config_dir(&app)?, &name, profile)
+}
+
+#[tauri::command(async)]
+async fn remove_connection_profile(app: AppHandle, name: String) -> Result<(), String> {
+  profiles::remove_profile(&config_dir(&app)?, &name)
+}
 
-  Ok(
-    database::get_current_sql_username(&conn.as_ref().unwrap())
-  )
+/// Teste un profil (enregistré ou non) et renvoie l'utilisateur connecté
+#[tauri::command(async)]
+async fn test_connection_profile(app: AppHandle, profile: ConnectionProfile, user: SqlUserCredentials) -> Result<String, String> {
+  profile.validate()?;
+
+  store::test_profile(profile.resolve_paths(&data_dir(&app)?), user)
+    .await
+    .map_err(|e| e.to_string())
+}
+
+#[tauri::command(async)]
+async fn get_logged_username() -> Result<String, String> {
+  store::with_store(|store| store.current_sql_username()).await
 }
 
 #[tauri::command(async)]
 async fn get_current_user() -> Result<SqlUser, String> {
-  let conn = database::get_connection()?;
-  database::get_current_user_statut(&conn.as_ref().unwrap()).map_err(|e| e.to_string())
+  store::with_store(|store| store.current_user()).await
 }
 
-/// Commande Tauri : Récupérer tous les films
+/// Commande Tauri : Récupérer une page de films
+/// `sort`, s'il est renseigné, remplace le tri prédéfini `filter`
 #[tauri::command(async)]
-async fn get_all_movies(genre: SearchGenre, query: String, filter: SearchFilter) -> Result<Vec<MovieShort>, String> {
+async fn get_all_movies(query: SearchQuery, filter: SearchFilter, sort: Option<Vec<SortOrder>>, page: Option<PageRequest>) -> Result<Page<MovieShort>, String> {
+    let sort = sort.unwrap_or_else(|| filter.sort_orders());
+    let search = MovieSearch::new(query, sort, page.unwrap_or_default())?;
+    let suggestion_search = search.suggestion_search();
 
-    println!("Genre: {:?}", genre);
-    println!("Query: {:?}", query);
-    println!("Filter: {:?}", filter);
-    
-    let conn = database::get_connection()?;
-    database::fetch_all_movies(&conn.as_ref().unwrap(), genre, query, filter).map_err(|e| e.to_string())
+    store::with_store(move |store| {
+        let mut page = store.fetch_all_movies(search)?;
+
+        // Aucun titre ne contient la recherche : on propose les titres les plus proches.
+        // Les suggestions sont facultatives : une erreur est journalisée et les laisse vides
+        if let (0, Some(fuzzy)) = (page.total, suggestion_search) {
+            let suggestions = match store.search_movies_ranked(fuzzy) {
+                Ok(ranked) => ranked.items,
+                Err(e) => {
+                    console::error("Search", &format!("Suggestions unavailable: {}", e));
+                    Vec::new()
+                }
+            };
+            for suggestion in suggestions {
+                if !page.suggestions.contains(&suggestion.movie.title) {
+                    page.suggestions.push(suggestion.movie.title);
+                }
+            }
+        }
+
+        Ok(page)
+    })
+    .await
+}
+
+/// Commande Tauri : Recherche plein texte (résumé, accroche, mots-clés), triée par pertinence
+#[tauri::command(async)]
+async fn search_movies_full_text(query: SearchQuery, page: Option<PageRequest>) -> Result<Page<MovieMatch>, String> {
+    let search = MovieSearch::full_text(query, page.unwrap_or_default())?;
+    store::with_store(move |store| store.search_movies_ranked(search)).await
+}
+
+/// Commande Tauri : Recherche tolérante aux fautes de frappe sur le titre, triée par similarité
+#[tauri::command(async)]
+async fn search_movies_fuzzy(query: SearchQuery, page: Option<PageRequest>) -> Result<Page<MovieMatch>, String> {
+    let search = MovieSearch::fuzzy(query, page.unwrap_or_default())?;
+    store::with_store(move |store| store.search_movies_ranked(search)).await
+}
+
+/// Commande Tauri : Titres commençant par `prefix`, pour l'autocomplétion de la recherche
+#[tauri::command(async)]
+async fn autocomplete_titles(prefix: String, limit: Option<u32>) -> Result<Vec<TitleCompletion>, String> {
+    let prefix = prefix.trim().to_string();
+    if prefix.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let limit = limit.unwrap_or(DEFAULT_COMPLETION_LIMIT).clamp(1, MAX_COMPLETION_LIMIT);
+    store::with_store(move |store| store.autocomplete_titles(&prefix, limit)).await
+}
+
+#[tauri::command(async)]
+async fn get_movie(id: i32, include_ratings: Option<bool>) -> Result<Option<Movie>, String> {
+    let include_ratings = include_ratings.unwrap_or(false);
+    store::with_store(move |store| store.get_movie(id, include_ratings)).await
+}
+
+/// Commande Tauri : Notes MovieLens d'un film, filtrées par note et par date, triées et paginées
+#[tauri::command(async)]
+async fn get_movie_ratings_page(movie_id: i32, filter: Option<ActivityFilter>, sort: Option<ActivitySort>, page: Option<PageRequest>) -> Result<Page<MovieRating>, String> {
+    let search = MovieActivitySearch::new(movie_id, UserActivity::Ratings, filter.unwrap_or_default(), sort.unwrap_or_default(), page.unwrap_or_default())?;
+    store::with_store(move |store| store.get_movie_ratings_page(search)).await
+}
+
+/// Commande Tauri : Tags MovieLens d'un film, filtrés par texte et par date, triés et paginés
+#[tauri::command(async)]
+async fn get_movie_tags_page(movie_id: i32, filter: Option<ActivityFilter>, sort: Option<ActivitySort>, page: Option<PageRequest>) -> Result<Page<MovieTag>, String> {
+    let search = MovieActivitySearch::new(movie_id, UserActivity::Tags, filter.unwrap_or_default(), sort.unwrap_or_default(), page.unwrap_or_default())?;
+    store::with_store(move |store| store.get_movie_tags_page(search)).await
+}
+
+/// Commande Tauri : Films que les utilisateurs ayant aimé ce film ont aussi aimés (table Movie_Similarities)
+#[tauri::command(async)]
+async fn get_similar_movies_by_ratings(movie_id: i32, limit: Option<u32>) -> Result<Vec<SimilarMovie>, String> {
+    let limit = limit.unwrap_or(DEFAULT_SIMILAR_LIMIT).clamp(1, MAX_SIMILAR_LIMIT);
+    store::with_store(move |store| store.get_similar_movies_by_ratings(movie_id, limit)).await
 }
 
+/// Commande Tauri : Films les plus proches par leurs genres, mots-clés et résumé, avec les caractéristiques communes
 #[tauri::command(async)]
-async fn get_movie(id: i32) -> Result<Option<Movie>, String> {
-    let conn = database::get_connection()?;
-    database::get_movie(&conn.as_ref().unwrap(), id).map_err(|e| e.to_string())
+async fn get_similar_movies_by_content(movie_id: i32, limit: Option<u32>) -> Result<Vec<ContentMatch>, String> {
+    let limit = limit.unwrap_or(DEFAULT_SIMILAR_LIMIT).clamp(1, MAX_SIMILAR_LIMIT);
+    store::with_store(move |store| store.get_similar_movies_by_content(movie_id, limit)).await
+}
+
+/// Commande Tauri : Films non vus les mieux prédits pour un utilisateur MovieLens (facteurs latents)
+#[tauri::command(async)]
+async fn get_recommendations_for_user(user_id: i32, n: Option<u32>) -> Result<Vec<Recommendation>, String> {
+    let n = n.unwrap_or(DEFAULT_RECOMMENDATION_COUNT).clamp(1, MAX_RECOMMENDATION_COUNT);
+    store::with_store(move |store| store.get_recommendations_for_user(user_id, n)).await
+}
+
+/// Commande Tauri : Catalogue des genres, avec le nombre de films de chacun
+#[tauri::command(async)]
+async fn get_genres() -> Result<Vec<Genre>, String> {
+    store::with_store(|store| store.get_genres()).await
+}
+
+/// Commande Tauri : Valeurs d'un attribut (mots-clés, sociétés, pays, langues) et nombre de films
+#[tauri::command(async)]
+async fn get_facet_values(facet: MovieFacet, name: Option<String>, page: Option<PageRequest>) -> Result<Page<FacetValue>, String> {
+    let search = FacetSearch::new(facet, name, page.unwrap_or_default())?;
+    store::with_store(move |store| store.get_facet_values(search)).await
+}
+
+/// Commande Tauri : Films associés à une valeur d'attribut (un mot-clé, une société...)
+#[tauri::command(async)]
+async fn get_movies_by_facet(facet: MovieFacet, id: i32, filter: SearchFilter, sort: Option<Vec<SortOrder>>, page: Option<PageRequest>) -> Result<Page<MovieShort>, String> {
+    let mut query = SearchQuery::default();
+    query.facet_ids_mut(facet).push(id);
+
+    let sort = sort.unwrap_or_else(|| filter.sort_orders());
+    let search = MovieSearch::new(query, sort, page.unwrap_or_default())?;
+    store::with_store(move |store| store.fetch_all_movies(search)).await
 }
 
 #[tauri::command(async)]
 async fn get_count_stats() -> Result<Stats, String> {
-    let conn = database::get_connection()?;
-    database::get_stats(&conn.as_ref().unwrap()).map_err(|e| e.to_string())
+    store::with_store(|store| store.get_stats()).await
+}
+
+/// Commande Tauri : Profil d'un utilisateur MovieLens (statistiques, genres préférés, tags et historiques paginés)
+#[tauri::command(async)]
+async fn get_movie_lens_user(user_id: i32, ratings_page: Option<PageRequest>, tags_page: Option<PageRequest>) -> Result<Option<MovieLensUserProfile>, String> {
+    let ratings = UserActivitySearch::new(user_id, UserActivity::Ratings, ratings_page.unwrap_or_default())?;
+    let tags = UserActivitySearch::new(user_id, UserActivity::Tags, tags_page.unwrap_or_default())?;
+    store::with_store(move |store| store.get_movie_lens_user(user_id, ratings, tags)).await
+}
+
+// Admin Insert / Update Command
+/// Commande Tauri : Ajoute un film et son lien MovieLens (administrateurs), refusé si l'identifiant TMDB ou IMDb existe déjà
+#[tauri::command(async)]
+async fn add_movie(movie: NewMovie) -> Result<MovieDetails, String> {
+  let movie = movie.validate()?;
+  store::with_store(move |store| store.add_movie(movie)).await
+}
+
+/// Commande Tauri : Modifie les champs renseignés d'un film (administrateurs), refusée si `update.version` est dépassée
+#[tauri::command(async)]
+async fn update_movie(movie_id: i32, update: MovieUpdate) -> Result<MovieDetails, String> {
+  let update = update.validate()?;
+  store::with_store(move |store| store.update_movie(movie_id, update)).await
+}
+
+// Admin MovieLens Write Command
+/// Commande Tauri : Note un film au nom d'un utilisateur MovieLens, créé s'il n'existe pas
+#[tauri::command(async)]
+async fn add_movie_lens_rating(movie_id: i32, user_id: i32, rating: f32) -> Result<MovieRating, String> {
+  check_user_id(user_id)?;
+  check_rating(rating)?;
+  store::with_store(move |store| store.add_movie_lens_rating(movie_id, user_id, rating)).await
+}
+
+/// Commande Tauri : Change la note existante d'un utilisateur MovieLens
+#[tauri::command(async)]
+async fn update_movie_lens_rating(movie_id: i32, user_id: i32, rating: f32) -> Result<MovieRating, String> {
+  check_rating(rating)?;
+  store::with_store(move |store| store.update_movie_lens_rating(movie_id, user_id, rating)).await
+}
+
+/// Commande Tauri : Ajoute un tag au nom d'un utilisateur MovieLens, créé s'il n'existe pas
+#[tauri::command(async)]
+async fn add_movie_lens_tag(movie_id: i32, user_id: i32, tag: String) -> Result<MovieTag, String> {
+  check_user_id(user_id)?;
+  let tag = normalize_tag(&tag)?;
+  store::with_store(move |store| store.add_movie_lens_tag(movie_id, user_id, &tag)).await
+}
+
+/// Commande Tauri : Corrige le texte d'un tag sans le supprimer
+#[tauri::command(async)]
+async fn rename_movie_lens_tag(movie_id: i32, user_id: i32, timestamp: i64, tag: String) -> Result<MovieTag, String> {
+  let tag = normalize_tag(&tag)?;
+  store::with_store(move |store| store.rename_movie_lens_tag(movie_id, user_id, timestamp, &tag)).await
+}
+
+// Admin Tag Moderation Command
+/// Commande Tauri : Aperçu des tags trouvés par une règle de modération, sans modifier la base
+#[tauri::command(async)]
+async fn preview_tag_moderation(moderation: TagModeration, sample_size: Option<u32>) -> Result<TagModerationPreview, String> {
+  let rule = TagRule::compile(&moderation)?;
+  let sample_size = sample_size.unwrap_or(DEFAULT_MODERATION_SAMPLE_SIZE).min(MAX_MODERATION_SAMPLE_SIZE);
+  store::with_store(move |store| store.preview_tag_moderation(&rule, sample_size)).await
+}
+
+/// Commande Tauri : Supprime ou réécrit en une transaction les tags trouvés par une règle ;
+/// `expected_matches`, le nombre de tags de l'aperçu, empêche d'appliquer la règle à des tags qui ont changé
+#[tauri::command(async)]
+async fn apply_tag_moderation(moderation: TagModeration, expected_matches: Option<u32>) -> Result<TagModerationReport, String> {
+  let rule = TagRule::compile(&moderation)?;
+  let report = store::with_store(move |store| store.apply_tag_moderation(&rule, expected_matches)).await?;
+  console::success("Moderation", &report.to_string());
+  Ok(report)
 }
 
 // Admin Delete Command
 #[tauri::command(async)]
 async fn delete_movie_lens_user(id: i32) -> Result<(), String> {
-  let conn = database::get_connection()?;
-  database::delete_movie_lens_user(&conn.as_ref().unwrap(), id).map_err(|e| e.to_string())?;
-
-  Ok(())
+  store::with_store(move |store| store.delete_movie_lens_user(id)).await
 }
 
 #[tauri::command(async)]
 async fn delete_movie_lens_tag(movie_id: i32, user_id: i32, timestamp: i64) -> Result<(), String> {
-  let conn = database::get_connection()?;
-  database::delete_movie_lens_tag(&conn.as_ref().unwrap(), movie_id, user_id, timestamp).map_err(|e| e.to_string())?;
+  store::with_store(move |store| store.delete_movie_lens_tag(movie_id, user_id, timestamp)).await
+}
 
-  Ok(())
+// Admin Trash Command
+/// Commande Tauri : Corbeille paginée, des suppressions les plus récentes aux plus anciennes
+#[tauri::command(async)]
+async fn get_trash(kind: Option<TrashKind>, page: Option<PageRequest>) -> Result<Page<TrashItem>, String> {
+  let search = TrashSearch::new(kind, page.unwrap_or_default())?;
+  store::with_store(move |store| store.get_trash(search)).await
+}
+
+/// Commande Tauri : Restaure un élément de la corbeille avec ses notes et tags supprimés en cascade
+#[tauri::command(async)]
+async fn restore_trash_item(deletion_id: i32) -> Result<TrashRestore, String> {
+  let restore = store::with_store(move |store| store.restore_trash_item(deletion_id)).await?;
+  console::success(
+    "Trash",
+    &format!(
+      "Élément {} restauré : {} notes, {} tags",
+      restore.deletion_id, restore.restored_ratings, restore.restored_tags
+    ),
+  );
+  Ok(restore)
+}
+
+// Admin Audit Command
+/// Commande Tauri : Journal des actions d'administration, des plus récentes aux plus anciennes
+#[tauri::command(async)]
+async fn get_audit_log(filter: Option<AuditFilter>, page: Option<PageRequest>) -> Result<Page<AuditEntry>, String> {
+  let search = AuditLogSearch::new(filter.unwrap_or_default(), page.unwrap_or_default())?;
+  store::with_store(move |store| store.get_audit_log(search)).await
 }
 
 // User
 #[tauri::command(async)]
 async fn create_sql_user(username: String, password: String, is_admin: bool) -> Result<(), String> {
-  let conn = database::get_connection()?;
-  database::create_sql_user(&conn.as_ref().unwrap(), &username, &password, is_admin).map_err(|e| e.to_string())?;
-
-  Ok(())
+  store::with_store(move |store| store.create_sql_user(&username, &password, is_admin)).await
 }
 
 #[tauri::command(async)]
 async fn get_sql_users() -> Result<Vec<SqlUser>, String> {
-  let conn = database::get_connection()?;
-  database::get_sql_users(&conn.as_ref().unwrap()).map_err(|e| e.to_string())
+  store::with_store(|store| store.get_sql_users()).await
 }
 
 #[tauri::command(async)]
 async fn delete_sql_user(username: String) -> Result<(), String> {
-  let conn = database::get_connection()?;
-  database::delete_sql_user(&conn.as_ref().unwrap(), &username).map_err(|e| e.to_string())?;
-
-  Ok(())
-}
\ No newline at end of file
+  store::with_store(move |store| store.delete_sql_user(&username)).await
+}
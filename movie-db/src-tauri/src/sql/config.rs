@@ -0,0 +1,136 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// Profil de connexion nommé, enregistré dans le fichier de configuration de l'application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionProfile {
+    pub name: String,
+    pub backend: ProfileBackend,
+
+    #[serde(default)]
+    pub pool: PoolConfig,
+}
+
+/// Cible d'un profil : une base Oracle ou un fichier SQLite local
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum ProfileBackend {
+    Oracle(OracleProfile),
+    Sqlite { path: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OracleProfile {
+    pub host: String,
+    pub port: u16,
+    pub service_name: Option<String>,
+    pub tns_alias: Option<String>,
+    pub connect_descriptor: Option<String>,
+}
+
+impl OracleProfile {
+    /// Chaîne de connexion : descripteur complet, sinon alias TNS, sinon Easy Connect
+    pub fn connect_string(&self) -> String {
+        if let Some(descriptor) = non_empty(&self.connect_descriptor) {
+            descriptor.to_string()
+        } else if let Some(alias) = non_empty(&self.tns_alias) {
+            alias.to_string()
+        } else {
+            format!(
+                "//{}:{}/{}",
+                self.host,
+                self.port,
+                non_empty(&self.service_name).unwrap_or_default()
+            )
+        }
+    }
+}
+
+impl ConnectionProfile {
+    /// Vérifie que le profil contient de quoi construire une connexion
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Le nom du profil est obligatoire".to_string());
+        }
+
+        if self.pool.max_connections == 0 || self.pool.min_connections > self.pool.max_connections {
+            return Err("Taille du pool invalide".to_string());
+        }
+
+        match &self.backend {
+            ProfileBackend::Oracle(oracle) => {
+                let has_target = non_empty(&oracle.connect_descriptor).is_some()
+                    || non_empty(&oracle.tns_alias).is_some()
+                    || (!oracle.host.trim().is_empty() && non_empty(&oracle.service_name).is_some());
+
+                if has_target {
+                    Ok(())
+                } else {
+                    Err("Renseignez un hôte et un service, un alias TNS ou un descripteur".to_string())
+                }
+            }
+            ProfileBackend::Sqlite { path } if path.trim().is_empty() => {
+                Err("Le chemin du fichier SQLite est obligatoire".to_string())
+            }
+            ProfileBackend::Sqlite { .. } => Ok(()),
+        }
+    }
+
+    /// Les chemins SQLite relatifs sont résolus dans le dossier de données de l'application
+    pub fn resolve_paths(mut self, data_dir: &Path) -> Self {
+        if let ProfileBackend::Sqlite { path } = &mut self.backend {
+            if Path::new(path.as_str()).is_relative() {
+                *path = data_dir.join(path.as_str()).to_string_lossy().into_owned();
+            }
+        }
+        self
+    }
+
+    /// Profils créés au premier lancement
+    pub fn defaults() -> Vec<ConnectionProfile> {
+        vec![
+            ConnectionProfile {
+                name: "Local XE".to_string(),
+                backend: ProfileBackend::Oracle(OracleProfile {
+                    host: "localhost".to_string(),
+                    port: 1521,
+                    service_name: Some("XEPDB1".to_string()),
+                    tns_alias: None,
+                    connect_descriptor: None,
+                }),
+                pool: PoolConfig::default(),
+            },
+            ConnectionProfile {
+                name: "Local SQLite".to_string(),
+                backend: ProfileBackend::Sqlite {
+                    path: "movie-db.sqlite3".to_string(),
+                },
+                pool: PoolConfig::default(),
+            },
+        ]
+    }
+}
+
+/// Dimensionnement du pool de connexions partagé par les commandes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolConfig {
+    pub min_connections: u32,
+    pub max_connections: u32,
+    pub connection_increment: u32,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            min_connections: 1,
+            max_connections: 4,
+            connection_increment: 1,
+        }
+    }
+}
+
+/// Valeur facultative non vide, espaces retirés
+pub(super) fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
@@ -0,0 +1,35 @@
+use super::{
+    search::Dialect,
+    types::content::{ContentMatch, SharedFeature},
+};
+
+// Nombre de caractéristiques communes renvoyées avec chaque film similaire
+pub const MAX_SHARED_FEATURES: usize = 8;
+
+/// Caractéristiques communes au film de référence (premier paramètre, identifiant MovieLens)
+/// et aux films similaires trouvés (paramètres suivants, identifiants TMDB), les plus discriminantes d'abord
+pub fn shared_features_query(dialect: Dialect, count: usize) -> String {
+    let placeholders: Vec<String> = (2..count + 2).map(|index| dialect.placeholder(index)).collect();
+    format!(
+        "SELECT c.tmdb_id, f.kind, f.name, f.weight
+        FROM MovieLens_Links l
+        JOIN Movie_Content_Features target ON target.tmdb_id = l.tmdb_id
+        JOIN Movie_Content_Features c ON c.feature_id = target.feature_id
+        JOIN Content_Features f ON f.feature_id = target.feature_id
+        WHERE l.movie_id = {} AND c.tmdb_id IN ({})
+        ORDER BY f.weight DESC, f.name",
+        dialect.placeholder(1),
+        placeholders.join(", ")
+    )
+}
+
+/// Range les caractéristiques communes (triées par poids décroissant) avec leur film
+pub fn attach_shared_features(matches: &mut [ContentMatch], features: Vec<(i32, SharedFeature)>) {
+    for (tmdb_id, feature) in features {
+        if let Some(m) = matches.iter_mut().find(|m| m.movie.tmdb_id == tmdb_id) {
+            if m.shared_features.len() < MAX_SHARED_FEATURES {
+                m.shared_features.push(feature);
+            }
+        }
+    }
+}
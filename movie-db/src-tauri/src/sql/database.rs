@@ -1,113 +1,318 @@
-use oracle::{sql_type::OracleType, Connection};
-use std::sync::Mutex;
-
-use crate::console;
+use oracle::{
+    pool::{CloseMode, Pool, PoolBuilder},
+    sql_type::{OracleType, ToSql},
+    Connection,
+};
 
 use super::{
+    config::{OracleProfile, PoolConfig},
     queries::{
-        CREATE_SQL_USER, DELETE_MOVIE_LENS_TAG, DELETE_MOVIE_LENS_USER, DELETE_SQL_USER, GET_CURRENT_SQL_USERNAME, GET_CURRENT_USER, GET_SQL_USERS, GET_STATS, SELECT_MOVIE_BY_ID, SELECT_MOVIE_RATINGS, SELECT_MOVIE_TAGS, SELECT_SHORT_MOVIES
+        ADD_MOVIE, ADD_MOVIE_LENS_RATING, ADD_MOVIE_LENS_TAG, APPLY_TAG_MODERATION, AUTOCOMPLETE_TITLES, CREATE_SQL_USER, DELETE_MOVIE_LENS_TAG, DELETE_MOVIE_LENS_USER, DELETE_SQL_USER, GET_CURRENT_SQL_USERNAME, GET_CURRENT_USER, GET_SQL_USERS, GET_STATS, RENAME_MOVIE_LENS_TAG, RESTORE_MOVIE_LENS_DELETION, SELECT_GENRES, SELECT_MOVIE_BY_ID, SELECT_MOVIE_GENRES, SELECT_MOVIE_RATING_HISTOGRAM, SELECT_MOVIE_RATING_SUMMARY, SELECT_MOVIE_RATINGS, SELECT_MOVIE_TAGS, SELECT_RATING_MODEL, SELECT_MOVIE_LENS_USER_SUMMARY, SELECT_SIMILAR_MOVIES, SELECT_SIMILAR_MOVIES_BY_CONTENT, SELECT_UNSEEN_MOVIE_FACTORS, SELECT_USER_FACTORS, SELECT_USER_GENRE_RATINGS, SELECT_USER_TOP_TAGS, STAGE_MODERATED_TAG, UPDATE_MOVIE, UPDATE_MOVIE_LENS_RATING
     },
+    content::{attach_shared_features, shared_features_query},
+    moderation::{check_expected_matches, preview, select_tags_query, ModerationRun, TagRule},
+    recommendation::{into_recommendations, select_movies_query, top_predictions, Factors, NO_MODEL_MESSAGE},
+    search::{AuditLogSearch, Dialect, FacetSearch, MovieActivitySearch, MovieSearch, SearchMode, SqlQuery, TrashSearch, UserActivitySearch},
+    store::{MovieStore, StoreError},
     types::{
-        genre::SearchGenre,
-        movie::{Movie, MovieDetails, MovieRating, MovieShort, MovieTag},
-        search_filter::SearchFilter,
+        audit::AuditEntry,
+        content::{ContentMatch, SharedFeature},
+        facet::FacetValue,
+        genre::Genre,
+        movie::{Movie, MovieDetails, MovieMatch, MovieRating, MovieShort, MovieTag, MovieUpdate, NewMovie, RatingSummary, Recommendation, SimilarMovie, TitleCompletion},
+        movie_lens_user::{GenreAffinity, MovieLensUserProfile, TagUsage, UserRating, UserSummary, UserTag, TOP_TAG_COUNT},
+        page::{Page, SortValue},
         sql_user::{SqlUser, SqlUserCredentials}, stats::{CountStats, MovieGenreCountStats, Stats, TopMovieProfit, TopUserItem},
+        tag_moderation::{TagModerationPreview, TagModerationReport, TagModerationSample, TagRow},
+        trash::{TrashItem, TrashRestore},
     },
 };
 
-// Configuration de la base de données
-const HOST: &str = "//localhost:1521/XEPDB1";
-
-// Un wrapper Mutex pour gérer une connexion partagée
-lazy_static::lazy_static! {
-    static ref ORACLE_CONNECTION: Mutex<Option<Connection>> = Mutex::new(None);
+/// Backend Oracle : un pool de sessions ouvert avec les identifiants de l'utilisateur
+pub struct OracleStore {
+    pool: Pool,
 }
 
-/// Initialise la connexion Oracle
-pub fn init_connection(user: SqlUserCredentials) -> Result<(), oracle::Error> {
-    console::state("Db", "Connecting...");
-    let mut conn = ORACLE_CONNECTION.lock().unwrap();
-    if conn.is_none() {
-        *conn = Some(Connection::connect(user.username, user.password, HOST)?);
-        console::success("Db", "Connected");
+impl OracleStore {
+    /// Initialise le pool de connexions Oracle
+    pub fn connect(user: SqlUserCredentials, profile: &OracleProfile, config: &PoolConfig) -> Result<Self, oracle::Error> {
+        let pool = PoolBuilder::new(user.username, user.password, profile.connect_string())
+            .min_connections(config.min_connections)
+            .max_connections(config.max_connections)
+            .connection_increment(config.connection_increment)
+            .build()?;
+
+        Ok(Self { pool })
+    }
+
+    /// Emprunte une connexion au pool, rendue automatiquement à sa libération
+    fn conn(&self) -> Result<Connection, oracle::Error> {
+        self.pool.get()
     }
-    Ok(())
 }
 
-pub fn close_connection() -> Result<(), String> {
-    let mut conn = ORACLE_CONNECTION.lock().unwrap();
-    if let Some(c) = conn.as_mut() {
-        c.close().unwrap();
-        *conn = None;
-        console::success("Db", "Connection closed");
+impl MovieStore for OracleStore {
+    fn current_sql_username(&self) -> Result<String, StoreError> {
+        Ok(get_current_sql_username(&self.conn()?)?)
+    }
+
+    fn current_user(&self) -> Result<SqlUser, StoreError> {
+        Ok(get_current_user_statut(&self.conn()?)?)
+    }
+
+    fn fetch_all_movies(&self, search: MovieSearch) -> Result<Page<MovieShort>, StoreError> {
+        Ok(fetch_all_movies(&self.conn()?, search)?)
+    }
+
+    fn search_movies_ranked(&self, search: MovieSearch) -> Result<Page<MovieMatch>, StoreError> {
+        Ok(search_movies_ranked(&self.conn()?, search)?)
+    }
+
+    fn autocomplete_titles(&self, prefix: &str, limit: u32) -> Result<Vec<TitleCompletion>, StoreError> {
+        Ok(autocomplete_titles(&self.conn()?, prefix, limit)?)
+    }
+
+    fn get_movie(&self, movie_id: i32, include_ratings: bool) -> Result<Option<Movie>, StoreError> {
+        Ok(get_movie(&self.conn()?, movie_id, include_ratings)?)
+    }
+
+    fn get_similar_movies_by_ratings(&self, movie_id: i32, limit: u32) -> Result<Vec<SimilarMovie>, StoreError> {
+        Ok(get_similar_movies_by_ratings(&self.conn()?, movie_id, limit)?)
+    }
+
+    fn get_similar_movies_by_content(&self, movie_id: i32, limit: u32) -> Result<Vec<ContentMatch>, StoreError> {
+        Ok(get_similar_movies_by_content(&self.conn()?, movie_id, limit)?)
+    }
+
+    fn get_recommendations_for_user(&self, user_id: i32, n: u32) -> Result<Vec<Recommendation>, StoreError> {
+        get_recommendations_for_user(&self.conn()?, user_id, n)
+    }
+
+    fn get_genres(&self) -> Result<Vec<Genre>, StoreError> {
+        Ok(get_genres(&self.conn()?)?)
+    }
+
+    fn get_facet_values(&self, search: FacetSearch) -> Result<Page<FacetValue>, StoreError> {
+        Ok(get_facet_values(&self.conn()?, search)?)
+    }
+
+    fn get_stats(&self) -> Result<Stats, StoreError> {
+        Ok(get_stats(&self.conn()?)?)
+    }
+
+    fn get_movie_ratings_page(&self, search: MovieActivitySearch) -> Result<Page<MovieRating>, StoreError> {
+        Ok(get_movie_ratings_page(&self.conn()?, search)?)
+    }
+
+    fn get_movie_tags_page(&self, search: MovieActivitySearch) -> Result<Page<MovieTag>, StoreError> {
+        Ok(get_movie_tags_page(&self.conn()?, search)?)
+    }
+
+    fn get_movie_lens_user(
+        &self,
+        user_id: i32,
+        ratings: UserActivitySearch,
+        tags: UserActivitySearch,
+    ) -> Result<Option<MovieLensUserProfile>, StoreError> {
+        Ok(get_movie_lens_user(&self.conn()?, user_id, ratings, tags)?)
+    }
+
+    fn add_movie(&self, movie: NewMovie) -> Result<MovieDetails, StoreError> {
+        let conn = self.conn()?;
+        let movie_id = add_movie(&conn, &movie)?;
+        get_movie_details(&conn, movie_id)?.ok_or_else(|| StoreError::Message(format!("Film introuvable : {}", movie_id)))
+    }
+
+    fn update_movie(&self, movie_id: i32, update: MovieUpdate) -> Result<MovieDetails, StoreError> {
+        let conn = self.conn()?;
+        update_movie(&conn, movie_id, &update)?;
+        get_movie_details(&conn, movie_id)?.ok_or_else(|| StoreError::Message(format!("Film introuvable : {}", movie_id)))
+    }
+
+    fn add_movie_lens_rating(&self, movie_id: i32, user_id: i32, rating: f32) -> Result<MovieRating, StoreError> {
+        Ok(write_movie_lens_rating(&self.conn()?, ADD_MOVIE_LENS_RATING, movie_id, user_id, rating)?)
+    }
+
+    fn update_movie_lens_rating(&self, movie_id: i32, user_id: i32, rating: f32) -> Result<MovieRating, StoreError> {
+        Ok(write_movie_lens_rating(&self.conn()?, UPDATE_MOVIE_LENS_RATING, movie_id, user_id, rating)?)
+    }
+
+    fn add_movie_lens_tag(&self, movie_id: i32, user_id: i32, tag: &str) -> Result<MovieTag, StoreError> {
+        Ok(add_movie_lens_tag(&self.conn()?, movie_id, user_id, tag)?)
+    }
+
+    fn rename_movie_lens_tag(&self, movie_id: i32, user_id: i32, timestamp: i64, tag: &str) -> Result<MovieTag, StoreError> {
+        Ok(rename_movie_lens_tag(&self.conn()?, movie_id, user_id, timestamp, tag)?)
+    }
+
+    fn preview_tag_moderation(&self, rule: &TagRule, sample_size: u32) -> Result<TagModerationPreview, StoreError> {
+        let conn = self.conn()?;
+        require_admin(&conn)?;
+        let changes = rule.plan(select_moderation_tags(&conn, rule)?).map_err(StoreError::Message)?;
+        Ok(preview(changes, sample_size))
+    }
+
+    fn apply_tag_moderation(&self, rule: &TagRule, expected_matches: Option<u32>) -> Result<TagModerationReport, StoreError> {
+        let conn = self.conn()?;
+        let run = ModerationRun::start();
+        match apply_tag_moderation(&conn, rule, expected_matches) {
+            Ok(changes) => Ok(run.finish(&changes)),
+            Err(e) => {
+                // Vide Tag_Moderation_Changes avant de rendre la connexion au pool
+                conn.rollback().ok();
+                Err(e)
+            }
+        }
+    }
+
+    fn delete_movie_lens_user(&self, user_id: i32) -> Result<(), StoreError> {
+        Ok(delete_movie_lens_user(&self.conn()?, user_id)?)
+    }
+
+    fn delete_movie_lens_tag(&self, movie_id: i32, user_id: i32, timestamp: i64) -> Result<(), StoreError> {
+        Ok(delete_movie_lens_tag(&self.conn()?, movie_id, user_id, timestamp)?)
+    }
+
+    fn get_trash(&self, search: TrashSearch) -> Result<Page<TrashItem>, StoreError> {
+        Ok(get_trash(&self.conn()?, search)?)
+    }
+
+    fn restore_trash_item(&self, deletion_id: i32) -> Result<TrashRestore, StoreError> {
+        Ok(restore_trash_item(&self.conn()?, deletion_id)?)
+    }
+
+    fn get_audit_log(&self, search: AuditLogSearch) -> Result<Page<AuditEntry>, StoreError> {
+        Ok(get_audit_log(&self.conn()?, search)?)
+    }
+
+    fn create_sql_user(&self, username: &str, password: &str, is_admin: bool) -> Result<(), StoreError> {
+        Ok(create_sql_user(&self.conn()?, username, password, is_admin)?)
+    }
+
+    fn delete_sql_user(&self, username: &str) -> Result<(), StoreError> {
+        Ok(delete_sql_user(&self.conn()?, username)?)
+    }
+
+    fn get_sql_users(&self) -> Result<Vec<SqlUser>, StoreError> {
+        Ok(get_sql_users(&self.conn()?)?)
+    }
+
+    fn close(&self) -> Result<(), StoreError> {
+        // Des connexions encore empruntées après le délai d'attente sont fermées de force
+        if self.pool.close(&CloseMode::Default).is_err() {
+            self.pool.close(&CloseMode::Force)?;
+        }
+        Ok(())
     }
-    Ok(())
 }
 
-/// Obtenir une référence à la connexion Oracle existante
-pub fn get_connection() -> Result<std::sync::MutexGuard<'static, Option<Connection>>, String> {
-    let conn = ORACLE_CONNECTION
-        .lock()
-        .map_err(|_| "Erreur lors de l'accès à la connexion".to_string())?;
-    if conn.is_none() {
-        Err("Connexion non initialisée. Appelez `init_connection`.".to_string())
-    } else {
-        Ok(conn)
+pub fn get_current_sql_username(conn: &Connection) -> Result<String, oracle::Error> {
+    let mut rows = conn.query(GET_CURRENT_SQL_USERNAME, &[])?;
+    match rows.next() {
+        Some(row) => row?.get(0),
+        None => Ok("Unknown".to_string()),
     }
 }
 
-pub fn get_current_sql_username(conn: &Connection) -> String {
-    let mut stmt = conn.query(GET_CURRENT_SQL_USERNAME, &[]).unwrap();
-    match stmt.next() {
-        Some(row) => {
-            // print row
-            println!("{:?}", row);
-            let user: String = row.unwrap().get(0).unwrap();
-            user
+pub fn fetch_all_movies(conn: &Connection, search: MovieSearch) -> Result<Page<MovieShort>, oracle::Error> {
+    // Nombre total de résultats, indépendant de la page demandée
+    let count = search.count_query(Dialect::Oracle);
+    let total: u32 = conn.query_row_as(&count.sql, &count.oracle_params())?;
+
+    let query = search.page_query(Dialect::Oracle);
+    let rows = conn.query(&query.sql, &query.oracle_params())?;
+
+    let sort_keys = search.sort_keys();
+    let mut movies = Vec::new();
+    for row in rows {
+        let row = row?;
+        let mut sort_values = Vec::new();
+        for (index, key) in sort_keys.iter().enumerate() {
+            sort_values.push(match key.numeric {
+                true => SortValue::Number(row.get(6 + index)?),
+                false => SortValue::Text(row.get(6 + index)?),
+            });
         }
-        None => "Unknown".to_string(),
+        movies.push((MovieShort::from_row(row)?, sort_values));
     }
+
+    Ok(search.into_page(movies, total, |movie| movie.movie_id))
 }
 
-pub fn fetch_all_movies(
-    conn: &Connection,
-    genre: SearchGenre,
-    query: String,
-    filter: SearchFilter,
-) -> Result<Vec<MovieShort>, oracle::Error> {
-    let search_query = format!("%{}%", query.to_lowercase());
-
-    // Préparer la clause genre conditionnellement
-    let genre_filter = if genre != SearchGenre::All {
-        "AND DBMS_LOB.INSTR(genres, :2) > 0"
-    } else {
-        ""
-    };
+/// Recherche plein texte via l'index Oracle Text (`SCORE`) ou approchée via les trigrammes
+/// des titres, triée par pertinence
+pub fn search_movies_ranked(conn: &Connection, search: MovieSearch) -> Result<Page<MovieMatch>, oracle::Error> {
+    let count = search.count_query(Dialect::Oracle);
+    let total: u32 = conn.query_row_as(&count.sql, &count.oracle_params())?;
 
-    // Construire la requête complète
-    let sql_query = SELECT_SHORT_MOVIES
-        .replace("{GENRE_FILTER}", genre_filter)
-        .replace("{VIEW_NAME}", filter.to_view_name());
+    let query = search.page_query(Dialect::Oracle);
+    let rows = conn.query(&query.sql, &query.oracle_params())?;
 
-    // Préparer les paramètres
-    let mut params: Vec<&dyn oracle::sql_type::ToSql> = vec![&search_query];
+    let mut matches = Vec::new();
+    for row in rows {
+        let row = row?;
+        let score: f64 = row.get(6)?;
+        let snippet: Option<String> = match search.mode {
+            SearchMode::FullText => row.get(7)?,
+            _ => None,
+        };
+        let movie = MovieMatch {
+            movie: MovieShort::from_row(row)?,
+            score,
+            snippet: snippet.map(|s| decode_entities(&s)),
+        };
+        matches.push((movie, vec![SortValue::Number(score)]));
+    }
 
-    let genre_string;
-    if let Some(g) = genre.as_str() {
-        genre_string = g.to_string();
+    Ok(search.into_page(matches, total, |m| m.movie.movie_id))
+}
+
+// CTX_DOC.SNIPPET échappe le texte en entités HTML ; le frontend attend du texte brut
+fn decode_entities(snippet: &str) -> String {
+    snippet
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
+}
+
+pub fn autocomplete_titles(conn: &Connection, prefix: &str, limit: u32) -> Result<Vec<TitleCompletion>, oracle::Error> {
+    // Les jokers saisis par l'utilisateur sont échappés : seul le préfixe est recherché
+    let pattern = format!(
+        "{}%",
+        prefix.to_lowercase().replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+    );
+
+    let rows = conn.query(AUTOCOMPLETE_TITLES, &[&pattern, &limit])?;
+    let mut titles = Vec::new();
+    for row in rows {
+        titles.push(TitleCompletion::from_row(row?)?);
+    }
+    Ok(titles)
+}
 
-        params.push(&genre_string);
+pub fn get_genres(conn: &Connection) -> Result<Vec<Genre>, oracle::Error> {
+    let rows = conn.query(SELECT_GENRES, &[])?;
+    let mut genres = Vec::new();
+    for row in rows {
+        genres.push(Genre::from_row(row?)?);
     }
+    Ok(genres)
+}
 
-    // Exécuter la requête
-    let rows = conn.query(&sql_query, &params)?;
+pub fn get_facet_values(conn: &Connection, search: FacetSearch) -> Result<Page<FacetValue>, oracle::Error> {
+    let count = search.count_query(Dialect::Oracle);
+    let total: u32 = conn.query_row_as(&count.sql, &count.oracle_params())?;
 
-    let mut movies = Vec::new();
+    let query = search.page_query(Dialect::Oracle);
+    let rows = conn.query(&query.sql, &query.oracle_params())?;
+
+    let mut values = Vec::new();
     for row in rows {
-        movies.push(MovieShort::from_row(row?)?);
+        values.push(FacetValue::from_row(row?)?);
     }
 
-    Ok(movies)
+    Ok(search.into_page(values, total))
 }
 
 pub fn get_movie_details(
@@ -116,10 +321,29 @@ pub fn get_movie_details(
 ) -> Result<Option<MovieDetails>, oracle::Error> {
     let mut stmt = conn.query(SELECT_MOVIE_BY_ID, &[&movie_id])?;
 
-    match stmt.next() {
-        Some(row) => Ok(Some(MovieDetails::from_row(row?)?)),
-        None => Ok(None),
+    let mut details = match stmt.next() {
+        Some(row) => MovieDetails::from_row(row?)?,
+        None => return Ok(None),
+    };
+
+    let rows = conn.query_as::<String>(SELECT_MOVIE_GENRES, &[&movie_id])?;
+    for genre in rows {
+        details.genres.push(genre?);
+    }
+
+    Ok(Some(details))
+}
+
+pub fn get_movie_rating_summary(conn: &Connection, movie_id: i32) -> Result<RatingSummary, oracle::Error> {
+    let row = conn.query_row(SELECT_MOVIE_RATING_SUMMARY, &[&movie_id])?;
+    let summary = RatingSummary::from_row(row)?;
+
+    let mut counts = Vec::new();
+    for row in conn.query_as::<(f32, u32)>(SELECT_MOVIE_RATING_HISTOGRAM, &[&movie_id])? {
+        counts.push(row?);
     }
+
+    Ok(summary.with_histogram(counts))
 }
 
 pub fn get_movie_ratings(
@@ -143,7 +367,7 @@ pub fn get_movie_tags(conn: &Connection, movie_id: i32) -> Result<Vec<MovieTag>,
     Ok(tags)
 }
 
-pub fn get_movie(conn: &Connection, movie_id: i32) -> Result<Option<Movie>, oracle::Error> {
+pub fn get_movie(conn: &Connection, movie_id: i32, include_ratings: bool) -> Result<Option<Movie>, oracle::Error> {
     let details = match get_movie_details(conn, movie_id) {
         Ok(Some(details)) => details,
         Err(e) => {
@@ -153,16 +377,295 @@ pub fn get_movie(conn: &Connection, movie_id: i32) -> Result<Option<Movie>, orac
         _ => return Ok(None),
     };
 
-    let ratings = get_movie_ratings(conn, movie_id)?;
+    let rating_summary = get_movie_rating_summary(conn, movie_id)?;
+    let ratings = match include_ratings {
+        true => Some(get_movie_ratings(conn, movie_id)?),
+        false => None,
+    };
     let tags = get_movie_tags(conn, movie_id)?;
 
     Ok(Some(Movie {
         details,
+        rating_summary,
         ratings,
         tags,
     }))
 }
 
+pub fn get_similar_movies_by_ratings(conn: &Connection, movie_id: i32, limit: u32) -> Result<Vec<SimilarMovie>, oracle::Error> {
+    let rows = conn.query(SELECT_SIMILAR_MOVIES, &[&movie_id, &limit])?;
+    let mut movies = Vec::new();
+    for row in rows {
+        movies.push(SimilarMovie::from_row(row?)?);
+    }
+    Ok(movies)
+}
+
+pub fn get_similar_movies_by_content(conn: &Connection, movie_id: i32, limit: u32) -> Result<Vec<ContentMatch>, oracle::Error> {
+    let rows = conn.query(SELECT_SIMILAR_MOVIES_BY_CONTENT, &[&movie_id, &limit])?;
+    let mut matches = Vec::new();
+    for row in rows {
+        matches.push(ContentMatch::from_row(row?)?);
+    }
+    if matches.is_empty() {
+        return Ok(matches);
+    }
+
+    let tmdb_ids: Vec<i32> = matches.iter().map(|m| m.movie.tmdb_id).collect();
+    let mut params: Vec<&dyn ToSql> = vec![&movie_id];
+    params.extend(tmdb_ids.iter().map(|tmdb_id| tmdb_id as &dyn ToSql));
+
+    let mut features = Vec::new();
+    for row in conn.query(&shared_features_query(Dialect::Oracle, tmdb_ids.len()), &params)? {
+        features.push(SharedFeature::from_row(row?)?);
+    }
+    attach_shared_features(&mut matches, features);
+
+    Ok(matches)
+}
+
+/// Prédit les notes des films non vus à partir des facteurs du modèle actif
+pub fn get_recommendations_for_user(conn: &Connection, user_id: i32, n: u32) -> Result<Vec<Recommendation>, StoreError> {
+    let global_mean = match conn.query_as::<f64>(SELECT_RATING_MODEL, &[])?.next() {
+        Some(global_mean) => global_mean?,
+        None => return Err(StoreError::Message(NO_MODEL_MESSAGE.to_string())),
+    };
+
+    // Utilisateur absent de l'entraînement : seuls les biais des films sont utilisés
+    let user = match conn.query_as::<(f64, String)>(SELECT_USER_FACTORS, &[&user_id])?.next() {
+        Some(row) => {
+            let (bias, factors) = row?;
+            Some(Factors::parse(bias, &factors))
+        }
+        None => None,
+    };
+
+    let mut movies = Vec::new();
+    for row in conn.query_as::<(i32, f64, String)>(SELECT_UNSEEN_MOVIE_FACTORS, &[&user_id])? {
+        let (movie_id, bias, factors) = row?;
+        movies.push((movie_id, Factors::parse(bias, &factors)));
+    }
+
+    let predictions = top_predictions(global_mean, user.as_ref(), &movies, n as usize);
+    if predictions.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let ids: Vec<i32> = predictions.iter().map(|&(movie_id, _)| movie_id).collect();
+    let params: Vec<&dyn ToSql> = ids.iter().map(|movie_id| movie_id as &dyn ToSql).collect();
+
+    let mut shorts = Vec::new();
+    for row in conn.query(&select_movies_query(Dialect::Oracle, ids.len()), &params)? {
+        shorts.push(MovieShort::from_row(row?)?);
+    }
+
+    Ok(into_recommendations(predictions, shorts))
+}
+
+/* MOVIE-LENS USER PROFILE */
+pub fn get_movie_lens_user(
+    conn: &Connection,
+    user_id: i32,
+    ratings: UserActivitySearch,
+    tags: UserActivitySearch,
+) -> Result<Option<MovieLensUserProfile>, oracle::Error> {
+    let mut rows = conn.query(SELECT_MOVIE_LENS_USER_SUMMARY, &[&user_id, &user_id, &user_id])?;
+    let summary = match rows.next() {
+        Some(row) => UserSummary::from_row(row?)?,
+        None => return Ok(None),
+    };
+
+    let mut genres = Vec::new();
+    for row in conn.query(SELECT_USER_GENRE_RATINGS, &[&user_id])? {
+        genres.push(GenreAffinity::from_row(row?)?);
+    }
+
+    let mut top_tags = Vec::new();
+    for row in conn.query(SELECT_USER_TOP_TAGS, &[&user_id, &TOP_TAG_COUNT])? {
+        top_tags.push(TagUsage::from_row(row?)?);
+    }
+
+    let (count, query) = (ratings.count_query(Dialect::Oracle), ratings.page_query(Dialect::Oracle));
+    let (rows, total) = get_page_rows(conn, count, query, UserRating::from_row)?;
+    let ratings = ratings.into_rating_page(rows, total);
+    let (count, query) = (tags.count_query(Dialect::Oracle), tags.page_query(Dialect::Oracle));
+    let (rows, total) = get_page_rows(conn, count, query, UserTag::from_row)?;
+    let tags = tags.into_tag_page(rows, total);
+
+    Ok(Some(summary.into_profile(genres, top_tags, ratings, tags)))
+}
+
+/// Lignes d'une page et nombre total de lignes
+fn get_page_rows<T>(
+    conn: &Connection,
+    count: SqlQuery,
+    query: SqlQuery,
+    from_row: fn(oracle::Row) -> Result<T, oracle::Error>,
+) -> Result<(Vec<T>, u32), oracle::Error> {
+    let total: u32 = conn.query_row_as(&count.sql, &count.oracle_params())?;
+
+    let mut items = Vec::new();
+    for row in conn.query(&query.sql, &query.oracle_params())? {
+        items.push(from_row(row?)?);
+    }
+
+    Ok((items, total))
+}
+
+/* MOVIE-LENS RATINGS AND TAGS */
+pub fn get_movie_ratings_page(conn: &Connection, search: MovieActivitySearch) -> Result<Page<MovieRating>, oracle::Error> {
+    let (count, query) = (search.count_query(Dialect::Oracle), search.page_query(Dialect::Oracle));
+    let (rows, total) = get_page_rows(conn, count, query, MovieRating::from_row)?;
+    Ok(search.into_rating_page(rows, total))
+}
+
+pub fn get_movie_tags_page(conn: &Connection, search: MovieActivitySearch) -> Result<Page<MovieTag>, oracle::Error> {
+    let (count, query) = (search.count_query(Dialect::Oracle), search.page_query(Dialect::Oracle));
+    let (rows, total) = get_page_rows(conn, count, query, MovieTag::from_row)?;
+    Ok(search.into_tag_page(rows, total))
+}
+
+/* MOVIE-LENS TRASH */
+pub fn get_trash(conn: &Connection, search: TrashSearch) -> Result<Page<TrashItem>, oracle::Error> {
+    let (count, query) = (search.count_query(Dialect::Oracle), search.page_query(Dialect::Oracle));
+    let (rows, total) = get_page_rows(conn, count, query, TrashItem::from_row)?;
+    Ok(search.into_page(rows, total))
+}
+
+/// La procédure RestoreMovieLensDeletion retourne le nombre de notes et de tags restaurés
+pub fn restore_trash_item(conn: &Connection, deletion_id: i32) -> Result<TrashRestore, oracle::Error> {
+    let mut stmt = conn.statement(RESTORE_MOVIE_LENS_DELETION).build()?;
+    stmt.execute(&[&deletion_id, &OracleType::Number(0, 0), &OracleType::Number(0, 0)])?;
+
+    Ok(TrashRestore {
+        deletion_id,
+        restored_ratings: stmt.bind_value(2)?,
+        restored_tags: stmt.bind_value(3)?,
+    })
+}
+
+/* ADMIN AUDIT LOG */
+/// Seuls les administrateurs ont le droit de lecture sur Admin_Audit_Log
+pub fn get_audit_log(conn: &Connection, search: AuditLogSearch) -> Result<Page<AuditEntry>, oracle::Error> {
+    let (count, query) = (search.count_query(Dialect::Oracle), search.page_query(Dialect::Oracle));
+    let (rows, total) = get_page_rows(conn, count, query, AuditEntry::from_row)?;
+    Ok(search.into_page(rows, total))
+}
+
+/* MOVIE ADMIN INSERT / UPDATE */
+/// La procédure AddMovie insère le film, son lien et ses genres, et retourne le movie_id attribué
+pub fn add_movie(conn: &Connection, movie: &NewMovie) -> Result<i32, oracle::Error> {
+    let mut stmt = conn.statement(ADD_MOVIE).build()?;
+
+    stmt.execute(&[
+        &movie.tmdb_id,
+        &movie.imdb_id,
+        &movie.title,
+        &movie.overview,
+        &movie.release_date,
+        &movie.runtime,
+        &movie.poster_path,
+        &movie.original_language,
+        &(movie.adult as i32),
+        &movie.genres.join(", "),
+        &OracleType::Number(0, 0),
+    ])?;
+
+    stmt.bind_value(11)
+}
+
+/// La procédure UpdateMovie valide les champs et compare la version dans la même transaction
+pub fn update_movie(conn: &Connection, movie_id: i32, update: &MovieUpdate) -> Result<(), oracle::Error> {
+    conn.execute(UPDATE_MOVIE,
+        &[
+            &movie_id,
+            &update.version,
+            &update.title,
+            &update.overview,
+            &update.release_date,
+            &update.runtime,
+            &update.poster_path,
+        ],
+    )?;
+
+    Ok(())
+}
+
+/* MOVIE-LENS ADMIN WRITE */
+/// Appelle AddMovieLensRating ou UpdateMovieLensRating, qui retournent le timestamp de la note
+pub fn write_movie_lens_rating(conn: &Connection, procedure: &str, movie_id: i32, user_id: i32, rating: f32) -> Result<MovieRating, oracle::Error> {
+    let mut stmt = conn.statement(procedure).build()?;
+    stmt.execute(&[&movie_id, &user_id, &rating, &OracleType::Number(0, 0)])?;
+
+    Ok(MovieRating {
+        user_id,
+        rating,
+        timestamp: stmt.bind_value(4)?,
+    })
+}
+
+pub fn add_movie_lens_tag(conn: &Connection, movie_id: i32, user_id: i32, tag: &str) -> Result<MovieTag, oracle::Error> {
+    let mut stmt = conn.statement(ADD_MOVIE_LENS_TAG).build()?;
+    stmt.execute(&[&movie_id, &user_id, &tag, &OracleType::Number(0, 0)])?;
+
+    Ok(MovieTag {
+        user_id,
+        tag: tag.to_string(),
+        timestamp: stmt.bind_value(4)?,
+    })
+}
+
+pub fn rename_movie_lens_tag(conn: &Connection, movie_id: i32, user_id: i32, timestamp: i64, tag: &str) -> Result<MovieTag, oracle::Error> {
+    conn.execute(RENAME_MOVIE_LENS_TAG, &[&movie_id, &user_id, &timestamp, &tag])?;
+
+    Ok(MovieTag {
+        user_id,
+        tag: tag.to_string(),
+        timestamp,
+    })
+}
+
+/* MOVIE-LENS TAG MODERATION */
+/// Les droits Oracle ne protègent que les écritures : l'aperçu, réservé aux administrateurs
+/// comme sur SQLite, vérifie le statut de l'utilisateur connecté
+fn require_admin(conn: &Connection) -> Result<(), StoreError> {
+    if get_current_user_statut(conn)?.is_admin {
+        Ok(())
+    } else {
+        Err(StoreError::Message("Privilèges insuffisants".to_string()))
+    }
+}
+
+/// Tags trouvés par la règle ; seuls les tags candidats sont lus et seuls les tags trouvés sont gardés
+pub fn select_moderation_tags(conn: &Connection, rule: &TagRule) -> Result<Vec<TagRow>, oracle::Error> {
+    let params: Vec<&dyn ToSql> = rule.fragments().iter().map(|fragment| fragment as &dyn ToSql).collect();
+
+    let mut tags = Vec::new();
+    for row in conn.query(&select_tags_query(Dialect::Oracle, params.len()), &params)? {
+        let row = TagRow::from_row(row?)?;
+        if rule.matches(&row.tag) {
+            tags.push(row);
+        }
+    }
+    Ok(tags)
+}
+
+/// Dépose les tags trouvés dans Tag_Moderation_Changes puis les fait supprimer ou réécrire par
+/// ApplyTagModeration, qui journalise et valide la modération. Un tag modifié ou supprimé entre la
+/// lecture et l'écriture fait échouer la modération, comme un nombre de tags différent de l'aperçu
+pub fn apply_tag_moderation(conn: &Connection, rule: &TagRule, expected_matches: Option<u32>) -> Result<Vec<TagModerationSample>, StoreError> {
+    let changes = rule.plan(select_moderation_tags(conn, rule)?).map_err(StoreError::Message)?;
+    check_expected_matches(&changes, expected_matches).map_err(StoreError::Message)?;
+
+    let mut stage = conn.statement(STAGE_MODERATED_TAG).build()?;
+    for change in &changes {
+        stage.execute(&[&change.movie_id, &change.user_id, &change.timestamp, &change.tag, &change.new_tag])?;
+    }
+
+    conn.execute(APPLY_TAG_MODERATION, &[&rule.audit_rule()])?;
+    Ok(changes)
+}
+
 /* MOVIE-LENS ADMIN DELETE */
 pub fn delete_movie_lens_user(conn: &Connection, user_id: i32) -> Result<(), oracle::Error> {
     let _ = conn.execute(DELETE_MOVIE_LENS_USER,
@@ -1,5 +1,13 @@
 pub mod types;
 
 pub mod queries;
+pub mod config;
+pub mod content;
 pub mod database;
-
+pub mod moderation;
+pub mod profiles;
+pub mod recommendation;
+pub mod search;
+pub mod sqlite;
+pub mod store;
+pub mod trigram;
@@ -0,0 +1,392 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use regex::{NoExpand, Regex, RegexBuilder};
+
+use super::search::Dialect;
+use super::types::{
+    movie::MAX_TAG_LENGTH,
+    movie_lens_user::TagUsage,
+    tag_moderation::{
+        TagModeration, TagModerationAction, TagModerationPreview, TagModerationReport, TagModerationSample, TagModerationSummary,
+        TagPattern, TagRow, MAX_MODERATION_PATTERN_LENGTH, MAX_MODERATION_WORDS, MODERATION_TOP_TAG_COUNT,
+    },
+};
+
+// Taille maximale de l'expression compilée
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+/// Règle de modération compilée ; les deux backends appliquent ainsi exactement la même règle
+pub struct TagRule {
+    regex: Regex,
+    /// Remplacement d'une réécriture ; absent pour une suppression
+    replacement: Option<String>,
+    /// Les motifs Substring et Words sont littéraux : `$` n'y désigne pas un groupe
+    literal: bool,
+    /// Textes en minuscules dont un tag trouvé contient forcément l'un, pour ne lire que ces tags ;
+    /// vide quand tous les tags doivent être lus
+    fragments: Vec<String>,
+}
+
+impl TagRule {
+    pub fn compile(moderation: &TagModeration) -> Result<Self, String> {
+        let (pattern, literal, fragments) = match &moderation.pattern {
+            TagPattern::Substring { text } => {
+                if text.trim().is_empty() {
+                    return Err("La sous-chaîne recherchée est vide".to_string());
+                }
+                check_pattern_length(text)?;
+                (format!("(?i){}", regex::escape(text)), true, sql_fragments(&[text.as_str()]))
+            }
+            TagPattern::Regex { pattern } => {
+                if pattern.is_empty() {
+                    return Err("L'expression régulière est vide".to_string());
+                }
+                check_pattern_length(pattern)?;
+                (pattern.clone(), false, Vec::new())
+            }
+            TagPattern::Words { words } => {
+                let words: Vec<&str> = words.iter().map(|word| word.trim()).filter(|word| !word.is_empty()).collect();
+                if words.is_empty() {
+                    return Err("La liste de mots est vide".to_string());
+                }
+                if words.len() > MAX_MODERATION_WORDS {
+                    return Err(format!("Liste de mots trop longue : {} mots au plus", MAX_MODERATION_WORDS));
+                }
+                let escaped: Vec<String> = words.iter().map(|word| regex::escape(word)).collect();
+                (format!(r"(?i)\b(?:{})\b", escaped.join("|")), true, sql_fragments(&words))
+            }
+        };
+
+        let regex = RegexBuilder::new(&pattern)
+            .size_limit(REGEX_SIZE_LIMIT)
+            .build()
+            .map_err(|e| format!("Expression régulière invalide : {}", e))?;
+
+        let replacement = match &moderation.action {
+            TagModerationAction::Delete => None,
+            TagModerationAction::Rewrite { replacement } => Some(replacement.clone()),
+        };
+
+        Ok(Self {
+            regex,
+            replacement,
+            literal,
+            fragments,
+        })
+    }
+
+    /// Valeurs liées à `select_tags_query`, dans l'ordre des marqueurs
+    pub fn fragments(&self) -> &[String] {
+        &self.fragments
+    }
+
+    /// Le tag est-il trouvé par le motif ? Les tags lus par `select_tags_query` sont seulement candidats
+    pub fn matches(&self, tag: &str) -> bool {
+        self.regex.is_match(tag)
+    }
+
+    /// Règle journalisée : l'expression compilée et le remplacement. Sur Oracle, ApplyTagModeration
+    /// y ajoute les comptes qu'elle a appliqués
+    pub fn audit_rule(&self) -> String {
+        match &self.replacement {
+            None => format!("pattern={}, action=delete", self.regex.as_str()),
+            Some(replacement) => format!("pattern={}, action=rewrite, replacement={}", self.regex.as_str(), replacement),
+        }
+    }
+
+    /// Paramètres journalisés d'une modération appliquée : la règle et les comptes
+    pub fn audit_parameters(&self, changes: &[TagModerationSample]) -> String {
+        let deleted = changes.iter().filter(|change| change.new_tag.is_none()).count();
+        format!(
+            "{}, matched={}, deleted={}, rewritten={}",
+            self.audit_rule(),
+            changes.len(),
+            deleted,
+            changes.len() - deleted
+        )
+    }
+
+    /// Nouveau texte d'un tag trouvé : `None` pour une suppression
+    fn apply(&self, tag: &str) -> Result<Option<String>, String> {
+        let Some(replacement) = &self.replacement else {
+            return Ok(None);
+        };
+
+        let rewritten = if self.literal {
+            self.regex.replace_all(tag, NoExpand(replacement))
+        } else {
+            self.regex.replace_all(tag, replacement.as_str())
+        };
+
+        // Espaces laissés par le remplacement réduits à un seul, comme après `normalize_tag`
+        let rewritten = rewritten.split_whitespace().collect::<Vec<_>>().join(" ");
+        if rewritten.is_empty() {
+            return Ok(None);
+        }
+        if rewritten.len() > MAX_TAG_LENGTH {
+            return Err(format!("La réécriture de « {} » dépasse {} octets", tag, MAX_TAG_LENGTH));
+        }
+        Ok(Some(rewritten))
+    }
+
+    /// Tags à supprimer ou réécrire ; un tag que la réécriture laisse identique n'est pas retenu.
+    /// Les plus récents sont en tête, pour que les exemples montrent les derniers ajouts
+    pub fn plan(&self, tags: Vec<TagRow>) -> Result<Vec<TagModerationSample>, String> {
+        let mut changes = Vec::new();
+        for row in tags {
+            if !self.regex.is_match(&row.tag) {
+                continue;
+            }
+
+            let new_tag = self.apply(&row.tag)?;
+            if new_tag.as_deref() == Some(row.tag.as_str()) {
+                continue;
+            }
+
+            changes.push(TagModerationSample {
+                movie_id: row.movie_id,
+                user_id: row.user_id,
+                timestamp: row.timestamp,
+                tag: row.tag,
+                new_tag,
+            });
+        }
+
+        changes.sort_by(|a, b| {
+            b.timestamp
+                .cmp(&a.timestamp)
+                .then(a.movie_id.cmp(&b.movie_id))
+                .then(a.user_id.cmp(&b.user_id))
+        });
+        Ok(changes)
+    }
+}
+
+/// Textes recherchés par la base à la place de l'expression, si tous sont ASCII. Les bases ne
+/// mettent en minuscules que l'ASCII de façon sûre (`LOWER` de SQLite) et, parmi les autres caractères,
+/// seuls `ſ` et le signe kelvin se confondent avec une lettre ASCII : `select_tags_query` les remplace
+fn sql_fragments(texts: &[&str]) -> Vec<String> {
+    if texts.iter().all(|text| text.is_ascii()) {
+        texts.iter().map(|text| text.to_ascii_lowercase()).collect()
+    } else {
+        Vec::new()
+    }
+}
+
+/// Tags candidats d'une règle : ceux qui contiennent l'un de ses `fragment_count` textes,
+/// sans tenir compte de la casse, ou tous les tags sans texte
+pub fn select_tags_query(dialect: Dialect, fragment_count: usize) -> String {
+    let mut sql = "SELECT movie_id, user_id, timestamp, tag\nFROM MovieLens_Tags\nWHERE tag IS NOT NULL".to_string();
+    if fragment_count > 0 {
+        let conditions: Vec<String> = (1..=fragment_count)
+            .map(|index| {
+                format!(
+                    "INSTR(REPLACE(REPLACE(LOWER(tag), 'ſ', 's'), '\u{212A}', 'k'), {}) > 0",
+                    dialect.placeholder(index)
+                )
+            })
+            .collect();
+        sql.push_str(&format!("\nAND ({})", conditions.join("\n    OR ")));
+    }
+    sql
+}
+
+fn check_pattern_length(pattern: &str) -> Result<(), String> {
+    if pattern.chars().count() > MAX_MODERATION_PATTERN_LENGTH {
+        return Err(format!("Motif trop long : {} caractères au plus", MAX_MODERATION_PATTERN_LENGTH));
+    }
+    Ok(())
+}
+
+/// Comptes des tags retenus par `TagRule::plan`
+pub fn summarize(changes: &[TagModerationSample]) -> TagModerationSummary {
+    let deleted_count = changes.iter().filter(|change| change.new_tag.is_none()).count() as u32;
+    let users: HashSet<i32> = changes.iter().map(|change| change.user_id).collect();
+    let movies: HashSet<i32> = changes.iter().map(|change| change.movie_id).collect();
+
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    for change in changes {
+        *counts.entry(change.tag.as_str()).or_default() += 1;
+    }
+
+    let distinct_tags = counts.len() as u32;
+    let mut top_tags: Vec<TagUsage> = counts
+        .into_iter()
+        .map(|(tag, count)| TagUsage { tag: tag.to_string(), count })
+        .collect();
+    top_tags.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.cmp(&b.tag)));
+    top_tags.truncate(MODERATION_TOP_TAG_COUNT);
+
+    TagModerationSummary {
+        matched_count: changes.len() as u32,
+        deleted_count,
+        rewritten_count: changes.len() as u32 - deleted_count,
+        distinct_tags,
+        user_count: users.len() as u32,
+        movie_count: movies.len() as u32,
+        top_tags,
+    }
+}
+
+/// Aperçu : résumé et premiers tags retenus
+pub fn preview(mut changes: Vec<TagModerationSample>, sample_size: u32) -> TagModerationPreview {
+    let summary = summarize(&changes);
+    changes.truncate(sample_size as usize);
+    TagModerationPreview { summary, samples: changes }
+}
+
+/// Début d'une modération, pour dater et chronométrer son rapport
+pub struct ModerationRun {
+    started_at: i64,
+    started: Instant,
+}
+
+impl ModerationRun {
+    pub fn start() -> Self {
+        Self {
+            started_at: SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs() as i64).unwrap_or(0),
+            started: Instant::now(),
+        }
+    }
+
+    pub fn finish(self, changes: &[TagModerationSample]) -> TagModerationReport {
+        TagModerationReport {
+            summary: summarize(changes),
+            started_at: self.started_at,
+            duration_ms: self.started.elapsed().as_millis() as u64,
+        }
+    }
+}
+
+/// Erreur d'une modération dont les tags ont changé depuis l'aperçu
+pub fn check_expected_matches(changes: &[TagModerationSample], expected_matches: Option<u32>) -> Result<(), String> {
+    match expected_matches {
+        Some(expected) if expected != changes.len() as u32 => Err(format!(
+            "Les tags ont changé depuis l'aperçu : {} tags trouvés au lieu de {}. Relancez l'aperçu.",
+            changes.len(),
+            expected
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: TagPattern, replacement: Option<&str>) -> Result<TagRule, String> {
+        let action = match replacement {
+            None => TagModerationAction::Delete,
+            Some(replacement) => TagModerationAction::Rewrite {
+                replacement: replacement.to_string(),
+            },
+        };
+        TagRule::compile(&TagModeration { pattern, action })
+    }
+
+    fn substring(text: &str) -> TagPattern {
+        TagPattern::Substring { text: text.to_string() }
+    }
+
+    fn regex(pattern: &str) -> TagPattern {
+        TagPattern::Regex { pattern: pattern.to_string() }
+    }
+
+    fn words(words: &[&str]) -> TagPattern {
+        TagPattern::Words {
+            words: words.iter().map(|word| word.to_string()).collect(),
+        }
+    }
+
+    fn row(timestamp: i64, tag: &str) -> TagRow {
+        TagRow {
+            movie_id: 1,
+            user_id: 2,
+            timestamp,
+            tag: tag.to_string(),
+        }
+    }
+
+    #[test]
+    fn invalid_patterns_are_rejected() {
+        assert!(rule(substring("  "), None).is_err());
+        assert!(rule(regex(""), None).is_err());
+        assert!(rule(regex("(unclosed"), None).is_err());
+        assert!(rule(words(&[" ", ""]), None).is_err());
+        assert!(rule(substring(&"a".repeat(MAX_MODERATION_PATTERN_LENGTH + 1)), None).is_err());
+
+        let many: Vec<String> = (0..=MAX_MODERATION_WORDS).map(|index| format!("w{}", index)).collect();
+        let many: Vec<&str> = many.iter().map(String::as_str).collect();
+        assert!(rule(words(&many), None).is_err());
+    }
+
+    #[test]
+    fn substring_is_literal_and_case_insensitive() {
+        let rule = rule(substring("C++"), Some("$1 cpp")).unwrap();
+        assert_eq!(rule.apply("learn c++ fast").unwrap().as_deref(), Some("learn $1 cpp fast"));
+        assert!(!rule.regex.is_match("learn c fast"));
+    }
+
+    #[test]
+    fn regex_replacement_expands_groups() {
+        let rule = rule(regex(r"(\w+)-(\w+)"), Some("$2 $1")).unwrap();
+        assert_eq!(rule.apply("mind-bending").unwrap().as_deref(), Some("bending mind"));
+    }
+
+    #[test]
+    fn words_match_whole_words_only() {
+        let rule = rule(words(&["class"]), None).unwrap();
+        assert!(rule.regex.is_match("a CLASS act"));
+        assert!(!rule.regex.is_match("classic"));
+    }
+
+    #[test]
+    fn rewrite_collapses_whitespace_and_deletes_empty_tags() {
+        let rule = rule(words(&["very"]), Some("")).unwrap();
+        assert_eq!(rule.apply("very  good  movie").unwrap().as_deref(), Some("good movie"));
+        assert_eq!(rule.apply("very").unwrap(), None);
+    }
+
+    #[test]
+    fn rewrite_longer_than_a_tag_is_an_error() {
+        let rule = rule(substring("x"), Some(&"y".repeat(MAX_TAG_LENGTH))).unwrap();
+        assert!(rule.apply("xx").is_err());
+        assert!(rule.plan(vec![row(1, "xx")]).is_err());
+    }
+
+    #[test]
+    fn only_ascii_texts_are_searched_by_the_database() {
+        assert_eq!(rule(substring("Pixar"), None).unwrap().fragments(), ["pixar"]);
+        assert_eq!(rule(words(&[" Bad ", "UGLY"]), None).unwrap().fragments(), ["bad", "ugly"]);
+        assert!(rule(words(&["bad", "café"]), None).unwrap().fragments().is_empty());
+        assert!(rule(regex("^bad$"), None).unwrap().fragments().is_empty());
+
+        assert_eq!(
+            select_tags_query(Dialect::Sqlite, 0),
+            "SELECT movie_id, user_id, timestamp, tag\nFROM MovieLens_Tags\nWHERE tag IS NOT NULL"
+        );
+        let query = select_tags_query(Dialect::Oracle, 2);
+        assert!(query.ends_with("'k'), :1) > 0\n    OR INSTR(REPLACE(REPLACE(LOWER(tag), 'ſ', 's'), '\u{212A}', 'k'), :2) > 0)"));
+    }
+
+    #[test]
+    fn plan_keeps_changed_tags_newest_first() {
+        let rule = rule(substring("pixar"), Some("Pixar")).unwrap();
+        let changes = rule
+            .plan(vec![row(1, "pixar"), row(3, "pixar classic"), row(2, "Pixar"), row(4, "dreamworks")])
+            .unwrap();
+
+        // « Pixar » est déjà écrit ainsi : il n'est pas retenu
+        let tags: Vec<(i64, &str, Option<&str>)> = changes
+            .iter()
+            .map(|change| (change.timestamp, change.tag.as_str(), change.new_tag.as_deref()))
+            .collect();
+        assert_eq!(tags, [(3, "pixar classic", Some("Pixar classic")), (1, "pixar", Some("Pixar"))]);
+
+        let summary = summarize(&changes);
+        assert_eq!((summary.matched_count, summary.rewritten_count, summary.deleted_count), (2, 2, 0));
+    }
+}
@@ -0,0 +1,98 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use super::config::ConnectionProfile;
+
+// Fichier des profils, dans le dossier de configuration de l'application
+const PROFILES_FILE: &str = "connection_profiles.json";
+
+// Sérialise les lectures/écritures concurrentes du fichier
+lazy_static::lazy_static! {
+    static ref PROFILES_LOCK: Mutex<()> = Mutex::new(());
+}
+
+fn profiles_path(config_dir: &Path) -> PathBuf {
+    config_dir.join(PROFILES_FILE)
+}
+
+fn load(config_dir: &Path) -> Result<Vec<ConnectionProfile>, String> {
+    let path = profiles_path(config_dir);
+    if !path.exists() {
+        return Ok(ConnectionProfile::defaults());
+    }
+
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&content).map_err(|e| format!("Fichier de profils invalide : {}", e))
+}
+
+fn save(config_dir: &Path, profiles: &[ConnectionProfile]) -> Result<(), String> {
+    fs::create_dir_all(config_dir).map_err(|e| e.to_string())?;
+
+    // Écriture dans un fichier temporaire puis renommage, pour ne jamais laisser un fichier tronqué
+    let path = profiles_path(config_dir);
+    let tmp_path = path.with_extension("json.tmp");
+    let content = serde_json::to_string_pretty(profiles).map_err(|e| e.to_string())?;
+    fs::write(&tmp_path, content).map_err(|e| e.to_string())?;
+    fs::rename(&tmp_path, &path).map_err(|e| e.to_string())
+}
+
+fn find(profiles: &[ConnectionProfile], name: &str) -> Option<usize> {
+    profiles.iter().position(|p| p.name.eq_ignore_ascii_case(name.trim()))
+}
+
+pub fn list_profiles(config_dir: &Path) -> Result<Vec<ConnectionProfile>, String> {
+    let _lock = PROFILES_LOCK.lock().unwrap();
+    load(config_dir)
+}
+
+pub fn get_profile(config_dir: &Path, name: &str) -> Result<ConnectionProfile, String> {
+    let _lock = PROFILES_LOCK.lock().unwrap();
+    let mut profiles = load(config_dir)?;
+    match find(&profiles, name) {
+        Some(index) => Ok(profiles.swap_remove(index)),
+        None => Err(format!("Profil de connexion inconnu : {}", name)),
+    }
+}
+
+pub fn add_profile(config_dir: &Path, profile: ConnectionProfile) -> Result<(), String> {
+    profile.validate()?;
+
+    let _lock = PROFILES_LOCK.lock().unwrap();
+    let mut profiles = load(config_dir)?;
+    if find(&profiles, &profile.name).is_some() {
+        return Err(format!("Un profil nommé {} existe déjà", profile.name));
+    }
+
+    profiles.push(profile);
+    save(config_dir, &profiles)
+}
+
+/// Remplace le profil `name` ; le nouveau profil peut porter un autre nom
+pub fn edit_profile(config_dir: &Path, name: &str, profile: ConnectionProfile) -> Result<(), String> {
+    profile.validate()?;
+
+    let _lock = PROFILES_LOCK.lock().unwrap();
+    let mut profiles = load(config_dir)?;
+    let index = find(&profiles, name).ok_or_else(|| format!("Profil de connexion inconnu : {}", name))?;
+
+    if let Some(other) = find(&profiles, &profile.name) {
+        if other != index {
+            return Err(format!("Un profil nommé {} existe déjà", profile.name));
+        }
+    }
+
+    profiles[index] = profile;
+    save(config_dir, &profiles)
+}
+
+pub fn remove_profile(config_dir: &Path, name: &str) -> Result<(), String> {
+    let _lock = PROFILES_LOCK.lock().unwrap();
+    let mut profiles = load(config_dir)?;
+    let index = find(&profiles, name).ok_or_else(|| format!("Profil de connexion inconnu : {}", name))?;
+
+    profiles.remove(index);
+    save(config_dir, &profiles)
+}
@@ -1,11 +1,3 @@
-pub const SELECT_SHORT_MOVIES: &str = "
-    SELECT movie_id, tmdb_id, title, vote_average, poster_path
-    FROM {VIEW_NAME}
-    WHERE LOWER(title) LIKE :1
-    {GENRE_FILTER}
-    FETCH FIRST 100 ROWS ONLY
-";
-
 pub const GET_CURRENT_SQL_USERNAME: &str = "
     select user from dual
 ";
@@ -24,13 +16,43 @@ pub const SELECT_MOVIE_BY_ID: &str = "SELECT
     backdrop_path,
     overview,
     poster_path,
-    genres,
-    keywords
+    keywords,
+    row_version
 FROM 
     MovieDetailsView
 WHERE 
     movie_id = :1";
 
+// Catalogue des genres et nombre de films MovieLens de chacun
+pub const SELECT_GENRES: &str = "SELECT
+    g.genre_id,
+    g.name,
+    COUNT(l.movie_id) AS movie_count
+FROM
+    Genres g
+LEFT JOIN
+    Movie_Genres mg ON mg.genre_id = g.genre_id
+LEFT JOIN
+    MovieLens_Links l ON l.tmdb_id = mg.tmdb_id
+GROUP BY
+    g.genre_id, g.name
+ORDER BY
+    g.name";
+
+// Genres d'un film par son ID MovieLens, dans l'ordre d'origine
+pub const SELECT_MOVIE_GENRES: &str = "SELECT
+    g.name
+FROM
+    Movie_Genres mg
+JOIN
+    Genres g ON g.genre_id = mg.genre_id
+JOIN
+    MovieLens_Links l ON l.tmdb_id = mg.tmdb_id
+WHERE
+    l.movie_id = :1
+ORDER BY
+    mg.position";
+
 pub const SELECT_MOVIE_RATINGS: &str = "SELECT 
     user_id, 
     rating, 
@@ -40,6 +62,30 @@ FROM
 WHERE 
     movie_id = :1";
 
+// Statistiques des notes MovieLens d'un film
+pub const SELECT_MOVIE_RATING_SUMMARY: &str = "SELECT
+    COUNT(*),
+    AVG(rating),
+    MEDIAN(rating),
+    STDDEV_POP(rating)
+FROM
+    MovieLens_Ratings
+WHERE
+    movie_id = :1";
+
+// Nombre de notes d'un film par valeur de note
+pub const SELECT_MOVIE_RATING_HISTOGRAM: &str = "SELECT
+    rating,
+    COUNT(*)
+FROM
+    MovieLens_Ratings
+WHERE
+    movie_id = :1
+GROUP BY
+    rating
+ORDER BY
+    rating";
+
 pub const SELECT_MOVIE_TAGS: &str = "SELECT 
     user_id, 
     tag, 
@@ -49,6 +95,216 @@ FROM
 WHERE 
     movie_id = :1";
 
+// Autocomplétion : titres commençant par le préfixe (index TMDB_movie_dataset_lower_title_idx
+// sur LOWER(title)), les plus populaires d'abord
+pub const AUTOCOMPLETE_TITLES: &str = "SELECT
+    movie_id,
+    tmdb_id,
+    title,
+    release_year,
+    poster_path
+FROM
+    MovieShortView
+WHERE
+    LOWER(title) LIKE :1 ESCAPE '\\'
+ORDER BY
+    popularity DESC NULLS LAST, movie_id
+FETCH FIRST :2 ROWS ONLY";
+
+// Films les plus proches d'après les notes MovieLens (table Movie_Similarities), par rang
+pub const SELECT_SIMILAR_MOVIES: &str = "SELECT
+    v.movie_id,
+    v.tmdb_id,
+    v.title,
+    v.vote_average,
+    v.poster_path,
+    v.weighted_rating,
+    s.similarity,
+    s.co_ratings
+FROM
+    Movie_Similarities s
+JOIN
+    MovieShortView v ON v.movie_id = s.similar_movie_id
+WHERE
+    s.movie_id = :1
+ORDER BY
+    s.rank
+FETCH FIRST :2 ROWS ONLY";
+
+// Films les plus proches par leur contenu : Jaccard pondéré des caractéristiques
+// (somme des poids communs / somme des poids de l'union)
+pub const SELECT_SIMILAR_MOVIES_BY_CONTENT: &str = "SELECT
+    v.movie_id,
+    v.tmdb_id,
+    v.title,
+    v.vote_average,
+    v.poster_path,
+    v.weighted_rating,
+    s.similarity
+FROM (
+    SELECT
+        c.tmdb_id,
+        SUM(f.weight) / (t.total_weight + n.total_weight - SUM(f.weight)) AS similarity
+    FROM
+        MovieLens_Links l
+    JOIN
+        Movie_Content_Norms t ON t.tmdb_id = l.tmdb_id
+    JOIN
+        Movie_Content_Features target ON target.tmdb_id = l.tmdb_id
+    JOIN
+        Content_Features f ON f.feature_id = target.feature_id
+    JOIN
+        Movie_Content_Features c ON c.feature_id = target.feature_id AND c.tmdb_id <> l.tmdb_id
+    JOIN
+        Movie_Content_Norms n ON n.tmdb_id = c.tmdb_id
+    WHERE
+        l.movie_id = :1
+    GROUP BY
+        c.tmdb_id, t.total_weight, n.total_weight
+) s
+JOIN
+    MovieShortView v ON v.tmdb_id = s.tmdb_id
+ORDER BY
+    s.similarity DESC, v.movie_id
+FETCH FIRST :2 ROWS ONLY";
+
+/* RECOMMENDATIONS */
+// Moyenne globale du modèle de recommandation actif
+pub const SELECT_RATING_MODEL: &str = "SELECT
+    global_mean
+FROM
+    Rating_Models
+WHERE
+    active = 1";
+
+pub const SELECT_USER_FACTORS: &str = "SELECT
+    bias,
+    factors
+FROM
+    User_Factors
+WHERE
+    user_id = :1";
+
+// Facteurs des films que l'utilisateur n'a pas encore notés
+pub const SELECT_UNSEEN_MOVIE_FACTORS: &str = "SELECT
+    f.movie_id,
+    f.bias,
+    f.factors
+FROM
+    Movie_Factors f
+JOIN
+    MovieShortView v ON v.movie_id = f.movie_id
+WHERE
+    NOT EXISTS (
+        SELECT 1 FROM MovieLens_Ratings r
+        WHERE r.user_id = :1 AND r.movie_id = f.movie_id
+    )";
+
+/* MOVIE-LENS USER PROFILE */
+// Nombre de notes et de tags d'un utilisateur, note moyenne et dates extrêmes ; aucune ligne si l'utilisateur n'existe pas
+pub const SELECT_MOVIE_LENS_USER_SUMMARY: &str = "SELECT
+    u.user_id,
+    r.rating_count,
+    r.average_rating,
+    r.first_rating,
+    r.last_rating,
+    t.tag_count,
+    t.first_tag,
+    t.last_tag
+FROM
+    MovieLens_Users u
+CROSS JOIN (
+    SELECT COUNT(*) AS rating_count, AVG(rating) AS average_rating, MIN(timestamp) AS first_rating, MAX(timestamp) AS last_rating
+    FROM MovieLens_Ratings
+    WHERE user_id = :1
+) r
+CROSS JOIN (
+    SELECT COUNT(*) AS tag_count, MIN(timestamp) AS first_tag, MAX(timestamp) AS last_tag
+    FROM MovieLens_Tags
+    WHERE user_id = :2
+) t
+WHERE
+    u.user_id = :3";
+
+// Note moyenne de l'utilisateur sur chaque genre des films qu'il a notés
+pub const SELECT_USER_GENRE_RATINGS: &str = "SELECT
+    g.genre_id,
+    g.name,
+    COUNT(*) AS rating_count,
+    AVG(r.rating) AS average_rating
+FROM
+    MovieLens_Ratings r
+JOIN
+    MovieLens_Links l ON l.movie_id = r.movie_id
+JOIN
+    Movie_Genres mg ON mg.tmdb_id = l.tmdb_id
+JOIN
+    Genres g ON g.genre_id = mg.genre_id
+WHERE
+    r.user_id = :1
+GROUP BY
+    g.genre_id, g.name";
+
+// Tags les plus utilisés par l'utilisateur
+pub const SELECT_USER_TOP_TAGS: &str = "SELECT
+    tag,
+    COUNT(*) AS tag_count
+FROM
+    MovieLens_Tags
+WHERE
+    user_id = :1
+GROUP BY
+    tag
+ORDER BY
+    tag_count DESC, tag
+FETCH FIRST :2 ROWS ONLY";
+
+/* MOVIE-LENS ADMIN WRITE */
+pub const ADD_MOVIE_LENS_RATING: &str = "
+    BEGIN
+        -- Arguments: movie_id, user_id, rating ; retourne le timestamp de la note
+        AddMovieLensRating(:1, :2, :3, :4);
+    END;
+";
+
+pub const UPDATE_MOVIE_LENS_RATING: &str = "
+    BEGIN
+        -- Arguments: movie_id, user_id, rating ; retourne le nouveau timestamp de la note
+        UpdateMovieLensRating(:1, :2, :3, :4);
+    END;
+";
+
+pub const ADD_MOVIE_LENS_TAG: &str = "
+    BEGIN
+        -- Arguments: movie_id, user_id, tag ; retourne le timestamp du tag
+        AddMovieLensTag(:1, :2, :3, :4);
+    END;
+";
+
+pub const RENAME_MOVIE_LENS_TAG: &str = "
+    BEGIN
+        -- Arguments: movie_id, user_id, timestamp, tag
+        RenameMovieLensTag(:1, :2, :3, :4);
+    END;
+";
+
+/* MOVIE-LENS TAG MODERATION */
+// Les règles sont appliquées en Rust, comme sur SQLite, aux tags lus par `moderation::select_tags_query`.
+// La lecture ne verrouille rien : seuls les tags trouvés sont verrouillés, par leur suppression ou leur réécriture
+// Table temporaire propre à la session, lue par ApplyTagModeration ; new_tag NULL pour une suppression.
+// Le texte fait partie de la clé : deux tags de même timestamp ne sont pas confondus
+pub const STAGE_MODERATED_TAG: &str = "
+    INSERT INTO Tag_Moderation_Changes (movie_id, user_id, timestamp, tag, new_tag)
+    VALUES (:1, :2, :3, :4, :5)
+";
+
+pub const APPLY_TAG_MODERATION: &str = "
+    BEGIN
+        -- Arguments: rule
+        ApplyTagModeration(:1);
+    END;
+";
+
 /* MOVIE-LENS ADMIN DELETE */
 pub const DELETE_MOVIE_LENS_USER: &str = "
     BEGIN
@@ -64,6 +320,30 @@ pub const DELETE_MOVIE_LENS_TAG: &str = "
     END;
 ";
 
+/* MOVIE-LENS TRASH */
+pub const RESTORE_MOVIE_LENS_DELETION: &str = "
+    BEGIN
+        -- Arguments: deletion_id ; retourne le nombre de notes et de tags restaurés
+        RestoreMovieLensDeletion(:1, :2, :3);
+    END;
+";
+
+/* MOVIE ADMIN INSERT / UPDATE */
+pub const ADD_MOVIE: &str = "
+    BEGIN
+        -- Arguments: tmdb_id, imdb_id, title, overview, release_date, runtime, poster_path,
+        -- original_language, adult, genres ; retourne le movie_id attribué
+        AddMovie(:1, :2, :3, :4, :5, :6, :7, :8, :9, :10, :11);
+    END;
+";
+
+pub const UPDATE_MOVIE: &str = "
+    BEGIN
+        -- Arguments: movie_id, version, title, overview, release_date, runtime, poster_path
+        UpdateMovie(:1, :2, :3, :4, :5, :6, :7);
+    END;
+";
+
 /* STATS */
 pub const GET_STATS: &str = "
     DECLARE
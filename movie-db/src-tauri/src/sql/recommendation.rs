@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use movie_db_models::recommendation::predict;
+
+use super::{
+    search::Dialect,
+    types::movie::{MovieShort, Recommendation},
+};
+
+// Modèle entraîné par le même code que dans le script de migration
+pub use movie_db_models::recommendation::{FactorModel, Factors, EPOCHS, FACTOR_COUNT, LEARNING_RATE, MODEL_VERSION, REGULARIZATION};
+
+pub const NO_MODEL_MESSAGE: &str = "Aucun modèle de recommandation entraîné. Lancez `cargo run -- recommendations` dans le script de migration.";
+
+/// Les `n` films les mieux prédits, avec leur note bornée à l'échelle MovieLens.
+/// Le classement se fait avant bornage : les films prédits au-delà de 5 restent départagés.
+pub fn top_predictions(global_mean: f64, user: Option<&Factors>, movies: &[(i32, Factors)], n: usize) -> Vec<(i32, f64)> {
+    let mut predictions: Vec<(i32, f64)> = movies
+        .iter()
+        .map(|(movie_id, movie)| (*movie_id, predict(global_mean, user, movie)))
+        .collect();
+
+    predictions.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    predictions.truncate(n);
+
+    predictions
+        .into_iter()
+        .map(|(movie_id, score)| (movie_id, score.clamp(0.5, 5.0)))
+        .collect()
+}
+
+/// Films recommandés, lus d'après leurs identifiants ; l'ordre des prédictions est rétabli par l'appelant
+pub fn select_movies_query(dialect: Dialect, count: usize) -> String {
+    let placeholders: Vec<String> = (1..=count).map(|index| dialect.placeholder(index)).collect();
+    format!(
+        "SELECT movie_id, tmdb_id, title, vote_average, poster_path, weighted_rating FROM MovieShortView WHERE movie_id IN ({})",
+        placeholders.join(", ")
+    )
+}
+
+/// Associe chaque prédiction à son film, dans l'ordre des prédictions
+pub fn into_recommendations(predictions: Vec<(i32, f64)>, movies: Vec<MovieShort>) -> Vec<Recommendation> {
+    let mut movies: HashMap<i32, MovieShort> = movies.into_iter().map(|movie| (movie.movie_id, movie)).collect();
+
+    predictions
+        .into_iter()
+        .filter_map(|(movie_id, score)| movies.remove(&movie_id).map(|movie| Recommendation { movie, score }))
+        .collect()
+}
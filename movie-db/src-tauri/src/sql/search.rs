@@ -0,0 +1,1314 @@
+use super::config::non_empty;
+use super::trigram::{trigrams, SIMILARITY_THRESHOLD};
+use super::types::{
+    audit::{AuditEntry, AuditFilter},
+    facet::{FacetValue, MovieFacet},
+    movie::{MovieRating, MovieTag},
+    movie_lens_user::{UserRating, UserTag},
+    page::{Page, PageCursor, PageRequest, SortValue},
+    search_filter::{ActivitySort, ActivitySortField, SortDirection, SortKey, SortOrder},
+    search_query::{ActivityFilter, GenreMatch, SearchQuery, ValueRange},
+    trash::{TrashItem, TrashKind},
+};
+
+/// Variantes de syntaxe SQL entre les backends
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dialect {
+    Oracle,
+    Sqlite,
+}
+
+impl Dialect {
+    pub fn placeholder(&self, index: usize) -> String {
+        match self {
+            Dialect::Oracle => format!(":{}", index),
+            Dialect::Sqlite => format!("?{}", index),
+        }
+    }
+
+    /// Expression de tri sans NULL, pour que l'ordre et la comparaison du curseur coïncident
+    fn sort_expression(&self, key: &SortKey) -> String {
+        match (self, key.column) {
+            (Dialect::Oracle, "release_date") => {
+                "COALESCE(TO_CHAR(release_date, 'YYYY-MM-DD'), '0000-00-00')".to_string()
+            }
+            (Dialect::Sqlite, "release_date") => "COALESCE(release_date, '0000-00-00')".to_string(),
+            (_, column) if key.numeric => format!("COALESCE({}, 0)", column),
+            (_, column) => format!("COALESCE({}, ' ')", column),
+        }
+    }
+
+    /// Requête plein texte : les termes sont échappés et cumulés, un film qui en contient
+    /// davantage obtient un meilleur score
+    fn text_query(&self, terms: &[String]) -> String {
+        match self {
+            Dialect::Oracle => terms
+                .iter()
+                .map(|term| format!("{{{}}}", term))
+                .collect::<Vec<_>>()
+                .join(" ACCUM "),
+            Dialect::Sqlite => terms
+                .iter()
+                .map(|term| format!("\"{}\"", term))
+                .collect::<Vec<_>>()
+                .join(" OR "),
+        }
+    }
+
+    /// Source de la recherche plein texte : `MovieShortView` complétée du score de pertinence
+    fn text_source(&self, placeholder: &str) -> String {
+        match self {
+            Dialect::Oracle => format!(
+                "(SELECT v.*, SCORE(1) AS relevance, t.ROWID AS text_key
+    FROM MovieShortView v
+    JOIN TMDB_movie_dataset t ON t.id = v.tmdb_id
+    WHERE CONTAINS(t.overview, {}, 1) > 0)",
+                placeholder
+            ),
+            // bm25 est négatif, plus petit pour les meilleurs résultats ; le titre pèse davantage
+            Dialect::Sqlite => format!(
+                "(SELECT v.*,
+        -bm25(TMDB_movie_text, 10.0, 1.0, 2.0, 1.0) AS relevance,
+        snippet(TMDB_movie_text, -1, '{}', '{}', '…', 16) AS snippet
+    FROM TMDB_movie_text
+    JOIN MovieShortView v ON v.tmdb_id = TMDB_movie_text.rowid
+    WHERE TMDB_movie_text MATCH {})",
+                SNIPPET_START, SNIPPET_END, placeholder
+            ),
+        }
+    }
+
+    /// Source de la recherche approchée : similarité de Jaccard entre les trigrammes de la
+    /// recherche et ceux du titre ou du titre original, le meilleur des deux étant retenu
+    fn fuzzy_source(&self, trigrams: &[String], count: &str, threshold: &str) -> String {
+        format!(
+            "(SELECT v.*, s.similarity AS relevance
+    FROM MovieShortView v
+    JOIN (
+        SELECT i.tmdb_id, MAX(ROUND(1.0 * m.shared / (i.trigram_count + {count} - m.shared), 4)) AS similarity
+        FROM (
+            SELECT tmdb_id, variant, COUNT(*) AS shared
+            FROM Movie_Title_Trigrams
+            WHERE trigram IN ({trigrams})
+            GROUP BY tmdb_id, variant
+        ) m
+        JOIN Movie_Title_Index i ON i.tmdb_id = m.tmdb_id AND i.variant = m.variant
+        GROUP BY i.tmdb_id
+    ) s ON s.tmdb_id = v.tmdb_id
+    WHERE s.similarity >= {threshold})",
+            count = count,
+            trigrams = trigrams.join(", "),
+            threshold = threshold,
+        )
+    }
+}
+
+// Balises entourant les termes trouvés dans les extraits
+pub const SNIPPET_START: &str = "<mark>";
+pub const SNIPPET_END: &str = "</mark>";
+
+// Nombre de suggestions « Vouliez-vous dire » renvoyées quand une recherche est vide
+const SUGGESTION_COUNT: u32 = 5;
+
+// Clé de tri des recherches plein texte et approchée
+const RELEVANCE: SortKey = SortKey {
+    name: "relevance",
+    column: "relevance",
+    descending: true,
+    numeric: true,
+};
+
+/// Recherche sur le titre (`LIKE`), plein texte ou approchée sur le titre, ces deux
+/// dernières avec un score de pertinence
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchMode {
+    Title,
+    FullText,
+    Fuzzy,
+}
+
+/// Valeur liée à une requête construite dynamiquement
+#[derive(Debug, Clone)]
+pub enum BindValue {
+    Text(String),
+    Number(f64),
+    Integer(i64),
+}
+
+impl BindValue {
+    pub fn as_oracle(&self) -> &dyn oracle::sql_type::ToSql {
+        match self {
+            BindValue::Text(value) => value,
+            BindValue::Number(value) => value,
+            BindValue::Integer(value) => value,
+        }
+    }
+
+    pub fn as_sqlite(&self) -> &dyn rusqlite::ToSql {
+        match self {
+            BindValue::Text(value) => value,
+            BindValue::Number(value) => value,
+            BindValue::Integer(value) => value,
+        }
+    }
+}
+
+impl From<&SortValue> for BindValue {
+    fn from(value: &SortValue) -> Self {
+        match value {
+            SortValue::Number(n) => BindValue::Number(*n),
+            SortValue::Text(t) => BindValue::Text(t.clone()),
+        }
+    }
+}
+
+/// Requête SQL et ses paramètres positionnels
+#[derive(Debug)]
+pub struct SqlQuery {
+    pub sql: String,
+    pub params: Vec<BindValue>,
+}
+
+impl SqlQuery {
+    pub fn oracle_params(&self) -> Vec<&dyn oracle::sql_type::ToSql> {
+        self.params.iter().map(BindValue::as_oracle).collect()
+    }
+
+    pub fn sqlite_params(&self) -> Vec<&dyn rusqlite::ToSql> {
+        self.params.iter().map(BindValue::as_sqlite).collect()
+    }
+}
+
+struct Binder {
+    dialect: Dialect,
+    params: Vec<BindValue>,
+}
+
+impl Binder {
+    fn new(dialect: Dialect) -> Self {
+        Self { dialect, params: Vec::new() }
+    }
+
+    /// Ajoute une valeur et renvoie son marqueur (`:n` ou `?n`)
+    fn bind(&mut self, value: BindValue) -> String {
+        self.params.push(value);
+        self.dialect.placeholder(self.params.len())
+    }
+
+    /// Lie chaque identifiant distinct et renvoie les marqueurs, pour une liste `IN (...)`
+    fn id_list(&mut self, ids: &[i32]) -> Vec<String> {
+        let mut ids = ids.to_vec();
+        ids.sort_unstable();
+        ids.dedup();
+
+        ids.into_iter()
+            .map(|id| self.bind(BindValue::Integer(id as i64)))
+            .collect()
+    }
+
+    /// Conditions `>=` / `<=` pour les bornes renseignées d'un intervalle
+    fn range<T: Copy>(
+        &mut self,
+        clauses: &mut Vec<String>,
+        column: &str,
+        range: &ValueRange<T>,
+        to_value: fn(T) -> BindValue,
+    ) {
+        if let Some(min) = range.min {
+            clauses.push(format!("{} >= {}", column, self.bind(to_value(min))));
+        }
+        if let Some(max) = range.max {
+            clauses.push(format!("{} <= {}", column, self.bind(to_value(max))));
+        }
+    }
+
+    /// Condition de pagination par clé : les lignes situées après le curseur dans l'ordre
+    /// `(clé 1, ..., clé n, id)`. Chaque valeur est liée autant de fois qu'elle apparaît,
+    /// Oracle liant les marqueurs par position.
+    fn keyset_clause(&mut self, keys: &[(String, bool)], values: &[SortValue], id_column: &str, id: i32) -> String {
+        let mut alternatives = Vec::new();
+        for index in 0..=keys.len() {
+            let mut conditions = Vec::new();
+            for ((expression, _), value) in keys.iter().zip(values).take(index) {
+                conditions.push(format!("{} = {}", expression, self.bind(BindValue::from(value))));
+            }
+
+            match keys.get(index).zip(values.get(index)) {
+                Some(((expression, descending), value)) => {
+                    let comparison = if *descending { "<" } else { ">" };
+                    conditions.push(format!("{} {} {}", expression, comparison, self.bind(BindValue::from(value))));
+                }
+                None => conditions.push(format!("{} > {}", id_column, self.bind(BindValue::Integer(id as i64)))),
+            }
+
+            alternatives.push(conditions.join(" AND "));
+        }
+
+        format!("(({}))", alternatives.join(") OR ("))
+    }
+
+    /// Les marqueurs sont liés dans leur ordre d'apparition dans la requête
+    fn page_clause(&mut self, limit: i64, offset: i64) -> String {
+        match self.dialect {
+            Dialect::Oracle => {
+                let offset = self.bind(BindValue::Integer(offset));
+                let limit = self.bind(BindValue::Integer(limit));
+                format!("OFFSET {} ROWS FETCH NEXT {} ROWS ONLY", offset, limit)
+            }
+            Dialect::Sqlite => {
+                let limit = self.bind(BindValue::Integer(limit));
+                let offset = self.bind(BindValue::Integer(offset));
+                format!("LIMIT {} OFFSET {}", limit, offset)
+            }
+        }
+    }
+}
+
+/// Recherche paginée sur `MovieShortView`
+#[derive(Debug)]
+pub struct MovieSearch {
+    pub mode: SearchMode,
+    pub query: SearchQuery,
+    /// Critères de tri de la recherche par titre, sans doublon
+    pub sort: Vec<SortOrder>,
+    pub size: u32,
+    pub offset: u32,
+    pub cursor: Option<PageCursor>,
+}
+
+impl MovieSearch {
+    pub fn new(query: SearchQuery, sort: Vec<SortOrder>, page: PageRequest) -> Result<Self, String> {
+        Self::build(SearchMode::Title, query, sort, page)
+    }
+
+    /// Recherche plein texte sur le titre, le résumé, l'accroche et les mots-clés,
+    /// triée par pertinence ; `query.title` contient les termes recherchés
+    pub fn full_text(query: SearchQuery, page: PageRequest) -> Result<Self, String> {
+        let search = Self::build(SearchMode::FullText, query, Vec::new(), page)?;
+        if search.text_terms().is_empty() {
+            return Err("Saisissez au moins un mot à rechercher".to_string());
+        }
+        Ok(search)
+    }
+
+    /// Recherche tolérante aux fautes de frappe sur le titre et le titre original,
+    /// triée par similarité ; les autres critères de `query` s'appliquent
+    pub fn fuzzy(query: SearchQuery, page: PageRequest) -> Result<Self, String> {
+        let search = Self::build(SearchMode::Fuzzy, query, Vec::new(), page)?;
+        if trigrams(&search.query.title).is_empty() {
+            return Err("Saisissez au moins un mot à rechercher".to_string());
+        }
+        Ok(search)
+    }
+
+    /// Recherche approchée à lancer si cette recherche par titre ne renvoie rien
+    pub fn suggestion_search(&self) -> Option<MovieSearch> {
+        if self.mode != SearchMode::Title || trigrams(&self.query.title).is_empty() {
+            return None;
+        }
+
+        let page = PageRequest {
+            size: Some(SUGGESTION_COUNT),
+            ..PageRequest::default()
+        };
+        Self::fuzzy(self.query.clone(), page).ok()
+    }
+
+    fn build(mode: SearchMode, query: SearchQuery, sort: Vec<SortOrder>, page: PageRequest) -> Result<Self, String> {
+        let mut search = Self {
+            mode,
+            query,
+            sort: sort.into_iter().fold(Vec::new(), |mut sort, order| {
+                // Un champ déjà trié départage toutes ses égalités : les suivants sont ignorés
+                if !sort.iter().any(|o: &SortOrder| o.field == order.field) {
+                    sort.push(order);
+                }
+                sort
+            }),
+            size: page.size(),
+            offset: page.offset.unwrap_or(0),
+            cursor: None,
+        };
+        search.cursor = PageCursor::decode_for(page.cursor.as_deref(), &search.sort_name(), search.sort_keys().len())?;
+
+        Ok(search)
+    }
+
+    /// Clés de tri dans l'ordre ; la colonne `sort_n` de `page_query` contient la valeur de la n-ième
+    pub fn sort_keys(&self) -> Vec<SortKey> {
+        match self.mode {
+            SearchMode::Title => self.sort.iter().map(SortOrder::key).collect(),
+            SearchMode::FullText | SearchMode::Fuzzy => vec![RELEVANCE],
+        }
+    }
+
+    /// Mots de la recherche plein texte, sans ponctuation ni opérateurs
+    fn text_terms(&self) -> Vec<String> {
+        self.query
+            .title
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|term| !term.is_empty())
+            .map(str::to_lowercase)
+            .collect()
+    }
+
+    fn source(&self, binder: &mut Binder) -> String {
+        match self.mode {
+            SearchMode::Title => "MovieShortView".to_string(),
+            SearchMode::FullText => {
+                let text = binder.bind(BindValue::Text(binder.dialect.text_query(&self.text_terms())));
+                binder.dialect.text_source(&text)
+            }
+            SearchMode::Fuzzy => {
+                // Liés dans l'ordre où `fuzzy_source` place les paramètres dans le texte
+                let trigrams = trigrams(&self.query.title);
+                let count = binder.bind(BindValue::Integer(trigrams.len() as i64));
+                let placeholders: Vec<String> = trigrams
+                    .into_iter()
+                    .map(|trigram| binder.bind(BindValue::Text(trigram)))
+                    .collect();
+                let threshold = binder.bind(BindValue::Number(SIMILARITY_THRESHOLD));
+                binder.dialect.fuzzy_source(&placeholders, &count, &threshold)
+            }
+        }
+    }
+
+    fn sort_name(&self) -> String {
+        let keys: Vec<String> = self
+            .sort_keys()
+            .iter()
+            .map(|key| format!("{} {}", key.name, if key.descending { "desc" } else { "asc" }))
+            .collect();
+
+        if keys.is_empty() {
+            "none".to_string()
+        } else {
+            keys.join(", ")
+        }
+    }
+
+    fn filter_clauses(&self, binder: &mut Binder) -> Vec<String> {
+        let query = &self.query;
+        let mut clauses = Vec::new();
+
+        if self.mode == SearchMode::Title {
+            clauses.push(format!(
+                "LOWER(title) LIKE {}",
+                binder.bind(BindValue::Text(format!("%{}%", query.title.to_lowercase())))
+            ));
+        }
+
+        let genres = binder.id_list(&query.genres);
+        if !genres.is_empty() {
+            // Tous les genres : le film doit apparaître une fois par genre demandé
+            let having = match query.genre_match {
+                GenreMatch::Any => String::new(),
+                GenreMatch::All => format!(
+                    "\n        GROUP BY tmdb_id\n        HAVING COUNT(*) = {}",
+                    binder.bind(BindValue::Integer(genres.len() as i64))
+                ),
+            };
+
+            clauses.push(format!(
+                "tmdb_id IN (
+        SELECT tmdb_id
+        FROM Movie_Genres
+        WHERE genre_id IN ({}){}
+    )",
+                genres.join(", "),
+                having
+            ));
+        }
+
+        for facet in MovieFacet::ALL {
+            let values = binder.id_list(query.facet_ids(facet));
+            if !values.is_empty() {
+                clauses.push(format!(
+                    "tmdb_id IN (
+        SELECT tmdb_id
+        FROM {}
+        WHERE {} IN ({})
+    )",
+                    facet.junction(),
+                    facet.id_column(),
+                    values.join(", ")
+                ));
+            }
+        }
+
+        binder.range(&mut clauses, "release_year", &query.release_year, |v| BindValue::Integer(v as i64));
+        binder.range(&mut clauses, "runtime", &query.runtime, |v| BindValue::Integer(v as i64));
+        binder.range(&mut clauses, "vote_average", &query.vote_average, BindValue::Number);
+
+        if let Some(min_vote_count) = query.min_vote_count {
+            let value = binder.bind(BindValue::Integer(min_vote_count as i64));
+            clauses.push(format!("vote_count >= {}", value));
+        }
+
+        if let Some(language) = non_empty(&query.original_language) {
+            let value = binder.bind(BindValue::Text(language.to_lowercase()));
+            clauses.push(format!("LOWER(original_language) = {}", value));
+        }
+
+        if let Some(status) = non_empty(&query.status) {
+            let value = binder.bind(BindValue::Text(status.to_lowercase()));
+            clauses.push(format!("LOWER(status) = {}", value));
+        }
+
+        if !query.include_adult {
+            clauses.push("COALESCE(adult, 0) = 0".to_string());
+        }
+
+        clauses
+    }
+
+    /// Nombre total de films correspondant à la recherche, toutes pages confondues
+    pub fn count_query(&self, dialect: Dialect) -> SqlQuery {
+        let mut binder = Binder::new(dialect);
+        let source = self.source(&mut binder);
+        let clauses = self.filter_clauses(&mut binder);
+        let sql = format!("SELECT COUNT(*) FROM {}{}", source, where_clause(&clauses));
+
+        SqlQuery { sql, params: binder.params }
+    }
+
+    /// Une ligne de plus que la taille de page est lue pour savoir s'il reste une page suivante.
+    /// Les colonnes 7 et suivantes (`sort_0`, `sort_1`...) contiennent les valeurs des clés de tri
+    /// (la pertinence en plein texte et en recherche approchée) ; en plein texte, la colonne
+    /// suivante contient l'extrait (`snippet`).
+    pub fn page_query(&self, dialect: Dialect) -> SqlQuery {
+        let mut binder = Binder::new(dialect);
+        let sort_keys = self.sort_keys();
+
+        // Oracle : l'extrait n'est calculé que pour les lignes de la page, autour de la requête paginée
+        let oracle_snippet = match (self.mode, dialect) {
+            (SearchMode::FullText, Dialect::Oracle) => {
+                Some(binder.bind(BindValue::Text(dialect.text_query(&self.text_terms()))))
+            }
+            _ => None,
+        };
+
+        let mut columns = "movie_id, tmdb_id, title, vote_average, poster_path, weighted_rating".to_string();
+        for (index, key) in sort_keys.iter().enumerate() {
+            columns.push_str(&format!(", {} AS sort_{}", dialect.sort_expression(key), index));
+        }
+        if self.mode == SearchMode::FullText {
+            columns.push_str(match dialect {
+                Dialect::Oracle => ", text_key",
+                Dialect::Sqlite => ", snippet",
+            });
+        }
+
+        let source = self.source(&mut binder);
+        let mut clauses = self.filter_clauses(&mut binder);
+
+        if let Some(cursor) = &self.cursor {
+            let keys: Vec<(String, bool)> = sort_keys
+                .iter()
+                .map(|key| (dialect.sort_expression(key), key.descending))
+                .collect();
+            clauses.push(binder.keyset_clause(&keys, &cursor.values, "movie_id", cursor.tiebreak_id));
+        }
+
+        let mut order_by: Vec<String> = sort_keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| format!("sort_{} {}", index, if key.descending { "DESC" } else { "ASC" }))
+            .collect();
+        order_by.push("movie_id ASC".to_string());
+        let order_by = format!("ORDER BY {}", order_by.join(", "));
+
+        let offset = if self.cursor.is_some() { 0 } else { self.offset };
+        let page_clause = binder.page_clause(self.size as i64 + 1, offset as i64);
+
+        let mut sql = format!(
+            "SELECT {}\nFROM {}{}\n{}\n{}",
+            columns,
+            source,
+            where_clause(&clauses),
+            order_by,
+            page_clause
+        );
+
+        if let Some(text) = oracle_snippet {
+            let outer_columns: String = (0..sort_keys.len()).map(|index| format!(", sort_{}", index)).collect();
+            sql = format!(
+                "SELECT movie_id, tmdb_id, title, vote_average, poster_path, weighted_rating{},
+    CTX_DOC.SNIPPET('SYSTEM.TMDB_movie_text_idx', ROWIDTOCHAR(text_key), {}, '{}', '{}') AS snippet
+FROM (\n{}\n)\n{}",
+                outer_columns, text, SNIPPET_START, SNIPPET_END, sql, order_by
+            );
+        }
+
+        SqlQuery { sql, params: binder.params }
+    }
+
+    /// Construit la page à partir des lignes lues par `page_query`
+    pub fn into_page<T>(
+        self,
+        rows: Vec<(T, Vec<SortValue>)>,
+        total: u32,
+        id: fn(&T) -> i32,
+    ) -> Page<T> {
+        build_page(rows, total, self.size, &self.sort_name(), id)
+    }
+}
+
+// Clé de tri des valeurs d'un attribut : les plus fréquentes d'abord
+const MOVIE_COUNT: &str = "movie_count";
+
+/// Liste paginée des valeurs d'un attribut (mots-clés, sociétés...) avec leur nombre de films
+#[derive(Debug)]
+pub struct FacetSearch {
+    pub facet: MovieFacet,
+    pub name: String,
+    pub size: u32,
+    pub offset: u32,
+    pub cursor: Option<PageCursor>,
+}
+
+impl FacetSearch {
+    /// `name` filtre les valeurs dont le nom le contient, sans tenir compte de la casse
+    pub fn new(facet: MovieFacet, name: Option<String>, page: PageRequest) -> Result<Self, String> {
+        let cursor = PageCursor::decode_for(page.cursor.as_deref(), MOVIE_COUNT, 1)?;
+
+        Ok(Self {
+            facet,
+            name: name.unwrap_or_default().trim().to_lowercase(),
+            size: page.size(),
+            offset: page.offset.unwrap_or(0),
+            cursor,
+        })
+    }
+
+    /// Valeurs associées à au moins un film MovieLens, et leur nombre de films
+    fn source(&self, binder: &mut Binder) -> String {
+        let name = if self.name.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "\n    WHERE LOWER(f.name) LIKE {}",
+                binder.bind(BindValue::Text(format!("%{}%", self.name)))
+            )
+        };
+
+        format!(
+            "(SELECT f.{id} AS facet_id, f.name, COUNT(l.movie_id) AS movie_count
+    FROM {table} f
+    JOIN {junction} mf ON mf.{id} = f.{id}
+    JOIN MovieLens_Links l ON l.tmdb_id = mf.tmdb_id{name}
+    GROUP BY f.{id}, f.name)",
+            id = self.facet.id_column(),
+            table = self.facet.table(),
+            junction = self.facet.junction(),
+            name = name,
+        )
+    }
+
+    pub fn count_query(&self, dialect: Dialect) -> SqlQuery {
+        let mut binder = Binder::new(dialect);
+        let sql = format!("SELECT COUNT(*) FROM {}", self.source(&mut binder));
+
+        SqlQuery { sql, params: binder.params }
+    }
+
+    /// Colonnes : `facet_id`, `name`, `movie_count` ; une ligne de plus que la taille de page
+    pub fn page_query(&self, dialect: Dialect) -> SqlQuery {
+        let mut binder = Binder::new(dialect);
+        let source = self.source(&mut binder);
+
+        let mut clauses = Vec::new();
+        if let Some(cursor) = &self.cursor {
+            let keys = [("movie_count".to_string(), true)];
+            clauses.push(binder.keyset_clause(&keys, &cursor.values, "facet_id", cursor.tiebreak_id));
+        }
+
+        let offset = if self.cursor.is_some() { 0 } else { self.offset };
+        let page_clause = binder.page_clause(self.size as i64 + 1, offset as i64);
+
+        let sql = format!(
+            "SELECT facet_id, name, movie_count\nFROM {}{}\nORDER BY movie_count DESC, facet_id ASC\n{}",
+            source,
+            where_clause(&clauses),
+            page_clause
+        );
+
+        SqlQuery { sql, params: binder.params }
+    }
+
+    pub fn into_page(self, rows: Vec<FacetValue>, total: u32) -> Page<FacetValue> {
+        let rows = rows
+            .into_iter()
+            .map(|value| {
+                let count = SortValue::Number(value.movie_count as f64);
+                (value, vec![count])
+            })
+            .collect();
+
+        build_page(rows, total, self.size, MOVIE_COUNT, |value| value.id)
+    }
+}
+
+// Clés de tri des historiques d'un utilisateur : les plus récents d'abord
+const RATING_HISTORY: &str = "rating_history";
+const TAG_HISTORY: &str = "tag_history";
+
+/// Historique d'un utilisateur MovieLens : ses notes ou ses tags
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UserActivity {
+    Ratings,
+    Tags,
+}
+
+impl UserActivity {
+    fn sort_name(&self) -> &'static str {
+        match self {
+            UserActivity::Ratings => RATING_HISTORY,
+            UserActivity::Tags => TAG_HISTORY,
+        }
+    }
+
+    fn table(&self) -> &'static str {
+        match self {
+            UserActivity::Ratings => "MovieLens_Ratings",
+            UserActivity::Tags => "MovieLens_Tags",
+        }
+    }
+
+    /// Clés de tri avant `movie_id` : un utilisateur peut poser plusieurs tags sur un film au même instant
+    fn keys(&self) -> Vec<(String, bool)> {
+        match self {
+            UserActivity::Ratings => vec![("a.timestamp".to_string(), true)],
+            UserActivity::Tags => vec![("a.timestamp".to_string(), true), ("a.tag".to_string(), false)],
+        }
+    }
+}
+
+/// Page de l'historique des notes ou des tags d'un utilisateur MovieLens, avec le titre des films
+#[derive(Debug)]
+pub struct UserActivitySearch {
+    pub user_id: i32,
+    pub activity: UserActivity,
+    pub size: u32,
+    pub offset: u32,
+    pub cursor: Option<PageCursor>,
+}
+
+impl UserActivitySearch {
+    pub fn new(user_id: i32, activity: UserActivity, page: PageRequest) -> Result<Self, String> {
+        let cursor = PageCursor::decode_for(page.cursor.as_deref(), activity.sort_name(), activity.keys().len())?;
+
+        Ok(Self {
+            user_id,
+            activity,
+            size: page.size(),
+            offset: page.offset.unwrap_or(0),
+            cursor,
+        })
+    }
+
+    pub fn count_query(&self, dialect: Dialect) -> SqlQuery {
+        let mut binder = Binder::new(dialect);
+        let sql = format!(
+            "SELECT COUNT(*) FROM {} WHERE user_id = {}",
+            self.activity.table(),
+            binder.bind(BindValue::Integer(self.user_id as i64))
+        );
+
+        SqlQuery { sql, params: binder.params }
+    }
+
+    /// Colonnes : `movie_id`, `title`, puis `rating` ou `tag`, `timestamp` ; une ligne de plus que la taille de page.
+    /// Le titre est absent pour les films MovieLens sans correspondance TMDB.
+    pub fn page_query(&self, dialect: Dialect) -> SqlQuery {
+        let mut binder = Binder::new(dialect);
+
+        let mut clauses = vec![format!("a.user_id = {}", binder.bind(BindValue::Integer(self.user_id as i64)))];
+        if let Some(cursor) = &self.cursor {
+            clauses.push(binder.keyset_clause(&self.activity.keys(), &cursor.values, "a.movie_id", cursor.tiebreak_id));
+        }
+
+        let offset = if self.cursor.is_some() { 0 } else { self.offset };
+        let page_clause = binder.page_clause(self.size as i64 + 1, offset as i64);
+
+        let (value, order) = match self.activity {
+            UserActivity::Ratings => ("a.rating", "a.timestamp DESC, a.movie_id ASC"),
+            UserActivity::Tags => ("a.tag", "a.timestamp DESC, a.tag ASC, a.movie_id ASC"),
+        };
+
+        let sql = format!(
+            "SELECT a.movie_id, v.title, {}, a.timestamp\nFROM {} a\nLEFT JOIN MovieShortView v ON v.movie_id = a.movie_id{}\nORDER BY {}\n{}",
+            value,
+            self.activity.table(),
+            where_clause(&clauses),
+            order,
+            page_clause
+        );
+
+        SqlQuery { sql, params: binder.params }
+    }
+
+    pub fn into_rating_page(self, rows: Vec<UserRating>, total: u32) -> Page<UserRating> {
+        let rows = rows
+            .into_iter()
+            .map(|rating| {
+                let timestamp = SortValue::Number(rating.timestamp as f64);
+                (rating, vec![timestamp])
+            })
+            .collect();
+
+        build_page(rows, total, self.size, RATING_HISTORY, |rating| rating.movie_id)
+    }
+
+    pub fn into_tag_page(self, rows: Vec<UserTag>, total: u32) -> Page<UserTag> {
+        let rows = rows
+            .into_iter()
+            .map(|tag| {
+                let values = vec![SortValue::Number(tag.timestamp as f64), SortValue::Text(tag.tag.clone())];
+                (tag, values)
+            })
+            .collect();
+
+        build_page(rows, total, self.size, TAG_HISTORY, |tag| tag.movie_id)
+    }
+}
+
+/// Notes ou tags d'un film, filtrés, triés et paginés ; `user_id` départage les égalités
+#[derive(Debug)]
+pub struct MovieActivitySearch {
+    pub movie_id: i32,
+    pub activity: UserActivity,
+    pub filter: ActivityFilter,
+    pub sort: ActivitySort,
+    pub size: u32,
+    pub offset: u32,
+    pub cursor: Option<PageCursor>,
+}
+
+impl MovieActivitySearch {
+    pub fn new(
+        movie_id: i32,
+        activity: UserActivity,
+        filter: ActivityFilter,
+        sort: ActivitySort,
+        page: PageRequest,
+    ) -> Result<Self, String> {
+        if activity == UserActivity::Tags && sort.field == ActivitySortField::Rating {
+            return Err("Les tags ne peuvent pas être triés par note".to_string());
+        }
+
+        let tag = non_empty(&filter.tag).map(str::to_lowercase);
+        let mut search = Self {
+            movie_id,
+            activity,
+            filter: ActivityFilter { tag, ..filter },
+            sort,
+            size: page.size(),
+            offset: page.offset.unwrap_or(0),
+            cursor: None,
+        };
+        search.cursor = PageCursor::decode_for(page.cursor.as_deref(), &search.sort_name(), search.keys().len())?;
+
+        Ok(search)
+    }
+
+    fn sort_name(&self) -> String {
+        let direction = match self.sort.direction {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        };
+        format!("{}:{:?}:{}", self.activity.table(), self.sort.field, direction)
+    }
+
+    /// Clés de tri avant `user_id` : le critère demandé, puis l'horodatage le plus récent,
+    /// puis le texte du tag (un utilisateur peut poser plusieurs tags au même instant)
+    fn keys(&self) -> Vec<(String, bool)> {
+        let descending = self.sort.direction == SortDirection::Desc;
+        let mut keys = match self.sort.field {
+            ActivitySortField::Timestamp => vec![("a.timestamp".to_string(), descending)],
+            ActivitySortField::Rating => vec![("a.rating".to_string(), descending), ("a.timestamp".to_string(), true)],
+            ActivitySortField::User => vec![("a.user_id".to_string(), descending), ("a.timestamp".to_string(), true)],
+        };
+
+        if self.activity == UserActivity::Tags {
+            keys.push(("a.tag".to_string(), false));
+        }
+        keys
+    }
+
+    fn filter_clauses(&self, binder: &mut Binder) -> Vec<String> {
+        let mut clauses = vec![format!("a.movie_id = {}", binder.bind(BindValue::Integer(self.movie_id as i64)))];
+
+        match self.activity {
+            UserActivity::Ratings => binder.range(&mut clauses, "a.rating", &self.filter.rating, BindValue::Number),
+            UserActivity::Tags => {
+                if let Some(tag) = &self.filter.tag {
+                    clauses.push(format!("LOWER(a.tag) LIKE {}", binder.bind(BindValue::Text(format!("%{}%", tag)))));
+                }
+            }
+        }
+        binder.range(&mut clauses, "a.timestamp", &self.filter.timestamp, BindValue::Integer);
+
+        clauses
+    }
+
+    pub fn count_query(&self, dialect: Dialect) -> SqlQuery {
+        let mut binder = Binder::new(dialect);
+        let clauses = self.filter_clauses(&mut binder);
+        let sql = format!("SELECT COUNT(*)\nFROM {} a{}", self.activity.table(), where_clause(&clauses));
+
+        SqlQuery { sql, params: binder.params }
+    }
+
+    /// Colonnes : `user_id`, puis `rating` ou `tag`, `timestamp` ; une ligne de plus que la taille de page
+    pub fn page_query(&self, dialect: Dialect) -> SqlQuery {
+        let mut binder = Binder::new(dialect);
+        let keys = self.keys();
+
+        let mut clauses = self.filter_clauses(&mut binder);
+        if let Some(cursor) = &self.cursor {
+            clauses.push(binder.keyset_clause(&keys, &cursor.values, "a.user_id", cursor.tiebreak_id));
+        }
+
+        let offset = if self.cursor.is_some() { 0 } else { self.offset };
+        let page_clause = binder.page_clause(self.size as i64 + 1, offset as i64);
+
+        let value = match self.activity {
+            UserActivity::Ratings => "a.rating",
+            UserActivity::Tags => "a.tag",
+        };
+        let order: Vec<String> = keys
+            .iter()
+            .map(|(expression, descending)| format!("{} {}", expression, if *descending { "DESC" } else { "ASC" }))
+            .chain(std::iter::once("a.user_id ASC".to_string()))
+            .collect();
+
+        let sql = format!(
+            "SELECT a.user_id, {}, a.timestamp\nFROM {} a{}\nORDER BY {}\n{}",
+            value,
+            self.activity.table(),
+            where_clause(&clauses),
+            order.join(", "),
+            page_clause
+        );
+
+        SqlQuery { sql, params: binder.params }
+    }
+
+    /// Valeurs des clés de tri d'une ligne, dans l'ordre de `keys`
+    fn sort_values(&self, primary: SortValue, timestamp: i64, tag: Option<&str>) -> Vec<SortValue> {
+        let mut values = vec![primary];
+        if self.sort.field != ActivitySortField::Timestamp {
+            values.push(SortValue::Number(timestamp as f64));
+        }
+        if let Some(tag) = tag {
+            values.push(SortValue::Text(tag.to_string()));
+        }
+        values
+    }
+
+    pub fn into_rating_page(self, rows: Vec<MovieRating>, total: u32) -> Page<MovieRating> {
+        let sort_name = self.sort_name();
+        let rows = rows
+            .into_iter()
+            .map(|rating| {
+                let primary = match self.sort.field {
+                    ActivitySortField::Timestamp => SortValue::Number(rating.timestamp as f64),
+                    ActivitySortField::Rating => SortValue::Number(rating.rating as f64),
+                    ActivitySortField::User => SortValue::Number(rating.user_id as f64),
+                };
+                let values = self.sort_values(primary, rating.timestamp, None);
+                (rating, values)
+            })
+            .collect();
+
+        build_page(rows, total, self.size, &sort_name, |rating| rating.user_id)
+    }
+
+    pub fn into_tag_page(self, rows: Vec<MovieTag>, total: u32) -> Page<MovieTag> {
+        let sort_name = self.sort_name();
+        let rows = rows
+            .into_iter()
+            .map(|tag| {
+                let primary = match self.sort.field {
+                    ActivitySortField::User => SortValue::Number(tag.user_id as f64),
+                    _ => SortValue::Number(tag.timestamp as f64),
+                };
+                let values = self.sort_values(primary, tag.timestamp, Some(&tag.tag));
+                (tag, values)
+            })
+            .collect();
+
+        build_page(rows, total, self.size, &sort_name, |tag| tag.user_id)
+    }
+}
+
+// Clé de tri de la corbeille : les suppressions les plus récentes d'abord
+const TRASH: &str = "trash";
+
+/// Corbeille MovieLens paginée, éventuellement limitée à un type d'élément ; `deletion_id` départage les égalités
+#[derive(Debug)]
+pub struct TrashSearch {
+    pub kind: Option<TrashKind>,
+    pub size: u32,
+    pub offset: u32,
+    pub cursor: Option<PageCursor>,
+}
+
+impl TrashSearch {
+    pub fn new(kind: Option<TrashKind>, page: PageRequest) -> Result<Self, String> {
+        let cursor = PageCursor::decode_for(page.cursor.as_deref(), TRASH, 1)?;
+
+        Ok(Self {
+            kind,
+            size: page.size(),
+            offset: page.offset.unwrap_or(0),
+            cursor,
+        })
+    }
+
+    fn kind_clauses(&self, binder: &mut Binder) -> Vec<String> {
+        match self.kind {
+            Some(kind) => vec![format!("d.kind = {}", binder.bind(BindValue::Text(kind.as_str().to_string())))],
+            None => Vec::new(),
+        }
+    }
+
+    pub fn count_query(&self, dialect: Dialect) -> SqlQuery {
+        let mut binder = Binder::new(dialect);
+        let clauses = self.kind_clauses(&mut binder);
+        let sql = format!("SELECT COUNT(*) FROM MovieLens_Deletions d{}", where_clause(&clauses));
+
+        SqlQuery { sql, params: binder.params }
+    }
+
+    /// Colonnes de `TrashItem` ; une ligne de plus que la taille de page.
+    /// Le titre vient du lien archivé pour un film supprimé, du lien actuel sinon
+    pub fn page_query(&self, dialect: Dialect) -> SqlQuery {
+        let mut binder = Binder::new(dialect);
+
+        let mut clauses = self.kind_clauses(&mut binder);
+        if let Some(cursor) = &self.cursor {
+            let keys = [("d.deleted_at".to_string(), true)];
+            clauses.push(binder.keyset_clause(&keys, &cursor.values, "d.deletion_id", cursor.tiebreak_id));
+        }
+
+        let offset = if self.cursor.is_some() { 0 } else { self.offset };
+        let page_clause = binder.page_clause(self.size as i64 + 1, offset as i64);
+
+        let sql = format!(
+            "SELECT
+    d.deletion_id, d.kind, d.user_id, d.movie_id, m.title,
+    (SELECT MIN(t.tag) FROM Deleted_MovieLens_Tags t WHERE t.deletion_id = d.deletion_id AND d.kind = 'Tag') AS tag,
+    d.deleted_by, d.deleted_at,
+    (SELECT COUNT(*) FROM Deleted_MovieLens_Ratings r WHERE r.deletion_id = d.deletion_id) AS rating_count,
+    (SELECT COUNT(*) FROM Deleted_MovieLens_Tags t WHERE t.deletion_id = d.deletion_id) AS tag_count
+FROM MovieLens_Deletions d
+LEFT JOIN MovieLens_Links l ON l.movie_id = d.movie_id
+LEFT JOIN TMDB_movie_dataset m ON m.id = COALESCE(d.tmdb_id, l.tmdb_id){}
+ORDER BY d.deleted_at DESC, d.deletion_id ASC
+{}",
+            where_clause(&clauses),
+            page_clause
+        );
+
+        SqlQuery { sql, params: binder.params }
+    }
+
+    pub fn into_page(self, rows: Vec<TrashItem>, total: u32) -> Page<TrashItem> {
+        let rows = rows
+            .into_iter()
+            .map(|item| {
+                let deleted_at = SortValue::Number(item.deleted_at as f64);
+                (item, vec![deleted_at])
+            })
+            .collect();
+
+        build_page(rows, total, self.size, TRASH, |item| item.deletion_id)
+    }
+}
+
+// Clé de tri du journal d'audit : les actions les plus récentes d'abord
+const AUDIT_LOG: &str = "audit_log";
+
+/// Journal des actions d'administration paginé et filtré ; `audit_id` départage les égalités
+#[derive(Debug)]
+pub struct AuditLogSearch {
+    pub filter: AuditFilter,
+    pub size: u32,
+    pub offset: u32,
+    pub cursor: Option<PageCursor>,
+}
+
+impl AuditLogSearch {
+    pub fn new(filter: AuditFilter, page: PageRequest) -> Result<Self, String> {
+        let cursor = PageCursor::decode_for(page.cursor.as_deref(), AUDIT_LOG, 1)?;
+
+        let acted_by = non_empty(&filter.acted_by).map(str::to_uppercase);
+        let target = non_empty(&filter.target).map(str::to_string);
+        Ok(Self {
+            filter: AuditFilter { acted_by, target, ..filter },
+            size: page.size(),
+            offset: page.offset.unwrap_or(0),
+            cursor,
+        })
+    }
+
+    fn filter_clauses(&self, binder: &mut Binder) -> Vec<String> {
+        let mut clauses = Vec::new();
+        if let Some(action) = self.filter.action {
+            clauses.push(format!("a.action = {}", binder.bind(BindValue::Text(action.as_str().to_string()))));
+        }
+        if let Some(acted_by) = &self.filter.acted_by {
+            clauses.push(format!("UPPER(a.acted_by) = {}", binder.bind(BindValue::Text(acted_by.clone()))));
+        }
+        if let Some(target) = &self.filter.target {
+            clauses.push(format!("a.target = {}", binder.bind(BindValue::Text(target.clone()))));
+        }
+        binder.range(&mut clauses, "a.acted_at", &self.filter.acted_at, BindValue::Integer);
+
+        clauses
+    }
+
+    pub fn count_query(&self, dialect: Dialect) -> SqlQuery {
+        let mut binder = Binder::new(dialect);
+        let clauses = self.filter_clauses(&mut binder);
+        let sql = format!("SELECT COUNT(*) FROM Admin_Audit_Log a{}", where_clause(&clauses));
+
+        SqlQuery { sql, params: binder.params }
+    }
+
+    /// Colonnes de `AuditEntry` ; une ligne de plus que la taille de page
+    pub fn page_query(&self, dialect: Dialect) -> SqlQuery {
+        let mut binder = Binder::new(dialect);
+
+        let mut clauses = self.filter_clauses(&mut binder);
+        if let Some(cursor) = &self.cursor {
+            let keys = [("a.acted_at".to_string(), true)];
+            clauses.push(binder.keyset_clause(&keys, &cursor.values, "a.audit_id", cursor.tiebreak_id));
+        }
+
+        let offset = if self.cursor.is_some() { 0 } else { self.offset };
+        let page_clause = binder.page_clause(self.size as i64 + 1, offset as i64);
+
+        let sql = format!(
+            "SELECT a.audit_id, a.acted_by, a.acted_at, a.action, a.target, a.parameters\nFROM Admin_Audit_Log a{}\nORDER BY a.acted_at DESC, a.audit_id ASC\n{}",
+            where_clause(&clauses),
+            page_clause
+        );
+
+        SqlQuery { sql, params: binder.params }
+    }
+
+    pub fn into_page(self, rows: Vec<AuditEntry>, total: u32) -> Page<AuditEntry> {
+        let rows = rows
+            .into_iter()
+            .map(|entry| {
+                let acted_at = SortValue::Number(entry.acted_at as f64);
+                (entry, vec![acted_at])
+            })
+            .collect();
+
+        build_page(rows, total, self.size, AUDIT_LOG, |entry| entry.audit_id)
+    }
+}
+
+/// Garde `size` lignes ; la ligne supplémentaire indique qu'une page suivante existe
+fn build_page<T>(
+    mut rows: Vec<(T, Vec<SortValue>)>,
+    total: u32,
+    size: u32,
+    sort_name: &str,
+    id: fn(&T) -> i32,
+) -> Page<T> {
+    let has_more = rows.len() > size as usize;
+    rows.truncate(size as usize);
+
+    let next_cursor = match rows.last() {
+        Some((item, values)) if has_more => Some(
+            PageCursor {
+                sort: sort_name.to_string(),
+                values: values.clone(),
+                tiebreak_id: id(item),
+            }
+            .encode(),
+        ),
+        _ => None,
+    };
+
+    Page {
+        items: rows.into_iter().map(|(item, _)| item).collect(),
+        total,
+        next_cursor,
+        suggestions: Vec::new(),
+    }
+}
+
+fn where_clause(clauses: &[String]) -> String {
+    if clauses.is_empty() {
+        String::new()
+    } else {
+        format!("\nWHERE {}", clauses.join("\n    AND "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+    use crate::sql::types::search_filter::SortField;
+
+    /// Numéros des marqueurs dans leur ordre d'apparition dans le texte
+    fn placeholders(sql: &str) -> Vec<usize> {
+        Regex::new(r"[:?](\d+)")
+            .unwrap()
+            .captures_iter(sql)
+            .map(|capture| capture[1].parse().unwrap())
+            .collect()
+    }
+
+    /// Oracle lie les marqueurs par position : ils doivent apparaître dans l'ordre des paramètres
+    fn assert_bound_in_order(query: &SqlQuery) {
+        let expected: Vec<usize> = (1..=query.params.len()).collect();
+        assert_eq!(placeholders(&query.sql), expected, "{}", query.sql);
+    }
+
+    fn params(values: &[BindValue]) -> Vec<String> {
+        values.iter().map(|value| format!("{:?}", value)).collect()
+    }
+
+    fn cursor_page(search: &MovieSearch, values: Vec<SortValue>, tiebreak_id: i32) -> PageRequest {
+        let cursor = PageCursor {
+            sort: search.sort_name(),
+            values,
+            tiebreak_id,
+        };
+        PageRequest {
+            size: Some(10),
+            cursor: Some(cursor.encode()),
+            ..PageRequest::default()
+        }
+    }
+
+    #[test]
+    fn keyset_clause_repeats_each_value_in_placeholder_order() {
+        let mut binder = Binder::new(Dialect::Oracle);
+        let keys = vec![("a".to_string(), false), ("b".to_string(), true)];
+        let values = vec![SortValue::Number(1.0), SortValue::Text("x".to_string())];
+
+        let clause = binder.keyset_clause(&keys, &values, "movie_id", 7);
+
+        assert_eq!(clause, "((a > :1) OR (a = :2 AND b < :3) OR (a = :4 AND b = :5 AND movie_id > :6))");
+        assert_eq!(
+            params(&binder.params),
+            ["Number(1.0)", "Number(1.0)", "Text(\"x\")", "Number(1.0)", "Text(\"x\")", "Integer(7)"]
+        );
+    }
+
+    #[test]
+    fn page_clause_binds_in_text_order() {
+        let mut oracle = Binder::new(Dialect::Oracle);
+        assert_eq!(oracle.page_clause(51, 100), "OFFSET :1 ROWS FETCH NEXT :2 ROWS ONLY");
+        assert_eq!(params(&oracle.params), ["Integer(100)", "Integer(51)"]);
+
+        let mut sqlite = Binder::new(Dialect::Sqlite);
+        assert_eq!(sqlite.page_clause(51, 100), "LIMIT ?1 OFFSET ?2");
+        assert_eq!(params(&sqlite.params), ["Integer(51)", "Integer(100)"]);
+    }
+
+    #[test]
+    fn movie_queries_bind_in_text_order() {
+        let query = SearchQuery {
+            title: "star".to_string(),
+            genres: vec![12, 28],
+            genre_match: GenreMatch::All,
+            release_year: ValueRange { min: Some(1970), max: Some(2000) },
+            ..SearchQuery::default()
+        };
+        let sort = vec![
+            SortOrder::new(SortField::VoteAverage, SortDirection::Desc),
+            SortOrder::new(SortField::Title, SortDirection::Asc),
+        ];
+        let first = MovieSearch::new(query.clone(), sort.clone(), PageRequest::default()).unwrap();
+        let values = vec![SortValue::Number(8.2), SortValue::Text("Star Wars".to_string())];
+        let page = cursor_page(&first, values, 260);
+        let search = MovieSearch::new(query, sort, page).unwrap();
+
+        for dialect in [Dialect::Oracle, Dialect::Sqlite] {
+            assert_bound_in_order(&search.count_query(dialect));
+            assert_bound_in_order(&search.page_query(dialect));
+        }
+    }
+
+    #[test]
+    fn full_text_queries_bind_in_text_order() {
+        let query = SearchQuery {
+            title: "space rebellion".to_string(),
+            ..SearchQuery::default()
+        };
+        let first = MovieSearch::full_text(query.clone(), PageRequest::default()).unwrap();
+        let page = cursor_page(&first, vec![SortValue::Number(3.5)], 260);
+        let search = MovieSearch::full_text(query, page).unwrap();
+
+        for dialect in [Dialect::Oracle, Dialect::Sqlite] {
+            assert_bound_in_order(&search.count_query(dialect));
+            assert_bound_in_order(&search.page_query(dialect));
+        }
+    }
+
+    #[test]
+    fn fuzzy_queries_bind_in_text_order() {
+        let query = SearchQuery {
+            title: "Termnator".to_string(),
+            min_vote_count: Some(100),
+            ..SearchQuery::default()
+        };
+        let first = MovieSearch::fuzzy(query.clone(), PageRequest::default()).unwrap();
+        let page = cursor_page(&first, vec![SortValue::Number(0.5)], 1240);
+        let search = MovieSearch::fuzzy(query, page).unwrap();
+
+        for dialect in [Dialect::Oracle, Dialect::Sqlite] {
+            assert_bound_in_order(&search.count_query(dialect));
+            assert_bound_in_order(&search.page_query(dialect));
+        }
+    }
+
+    #[test]
+    fn cursor_round_trips() {
+        let cursor = PageCursor {
+            sort: "title asc".to_string(),
+            values: vec![SortValue::Text("Heat".to_string())],
+            tiebreak_id: 6,
+        };
+
+        let decoded = PageCursor::decode_for(Some(&cursor.encode()), "title asc", 1).unwrap().unwrap();
+        assert_eq!(decoded.sort, "title asc");
+        assert!(matches!(&decoded.values[..], [SortValue::Text(title)] if title == "Heat"));
+        assert_eq!(decoded.tiebreak_id, 6);
+
+        assert!(PageCursor::decode_for(None, "title asc", 1).unwrap().is_none());
+        assert!(PageCursor::decode_for(Some("not a cursor"), "title asc", 1).is_err());
+    }
+
+    #[test]
+    fn cursor_is_rejected_with_another_sort() {
+        let by_title = vec![SortOrder::new(SortField::Title, SortDirection::Asc)];
+        let first = MovieSearch::new(SearchQuery::default(), by_title, PageRequest::default()).unwrap();
+        let page = cursor_page(&first, vec![SortValue::Text("Heat".to_string())], 6);
+
+        let by_popularity = vec![SortOrder::new(SortField::Popularity, SortDirection::Desc)];
+        assert!(MovieSearch::new(SearchQuery::default(), by_popularity, page.clone()).is_err());
+        assert!(TrashSearch::new(None, page.clone()).is_err());
+        assert!(AuditLogSearch::new(AuditFilter::default(), page).is_err());
+
+        // Même tri, mais un nombre de valeurs différent
+        let encoded = PageCursor {
+            sort: "title asc".to_string(),
+            values: Vec::new(),
+            tiebreak_id: 6,
+        }
+        .encode();
+        assert!(PageCursor::decode_for(Some(&encoded), "title asc", 1).is_err());
+    }
+}
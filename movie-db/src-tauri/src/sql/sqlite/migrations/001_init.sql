@@ -0,0 +1,268 @@
+-- Schéma local équivalent à celui créé par movie-db-sql-migration-script
+
+CREATE TABLE TMDB_movie_dataset (
+    id INTEGER PRIMARY KEY,
+    title TEXT,
+    vote_average REAL,
+    vote_count INTEGER,
+    status TEXT,
+    release_date TEXT,
+    revenue INTEGER,
+    runtime INTEGER,
+    adult INTEGER CHECK (adult IN (0, 1)),
+    backdrop_path TEXT,
+    budget INTEGER,
+    homepage TEXT,
+    imdb_id TEXT,
+    original_language TEXT,
+    original_title TEXT,
+    overview TEXT,
+    popularity REAL,
+    poster_path TEXT,
+    tagline TEXT,
+    genres TEXT,
+    production_companies TEXT,
+    production_countries TEXT,
+    spoken_languages TEXT,
+    keywords TEXT
+);
+
+CREATE INDEX TMDB_movie_dataset_title_idx ON TMDB_movie_dataset(title);
+
+CREATE TABLE MovieLens_Ratings (
+    user_id INTEGER,
+    movie_id INTEGER,
+    rating REAL,
+    timestamp INTEGER
+);
+
+CREATE INDEX MovieLens_Ratings_user_id_idx ON MovieLens_Ratings(user_id);
+CREATE INDEX MovieLens_Ratings_movie_id_idx ON MovieLens_Ratings(movie_id);
+
+CREATE TABLE MovieLens_Tags (
+    user_id INTEGER,
+    movie_id INTEGER,
+    tag TEXT,
+    timestamp INTEGER
+);
+
+CREATE INDEX MovieLens_Tags_user_id_idx ON MovieLens_Tags(user_id);
+CREATE INDEX MovieLens_Tags_movie_id_idx ON MovieLens_Tags(movie_id);
+
+CREATE TABLE MovieLens_Users (
+    user_id INTEGER PRIMARY KEY
+);
+
+CREATE TABLE MovieLens_Links (
+    movie_id INTEGER PRIMARY KEY,
+    imdb_id TEXT,
+    tmdb_id INTEGER
+);
+
+CREATE INDEX MovieLens_Links_tmdb_id_idx ON MovieLens_Links(tmdb_id);
+
+-- Pas d'utilisateurs SQL en SQLite : les comptes sont simulés dans Users_Records
+CREATE TABLE Users_Records (
+    username TEXT PRIMARY KEY,
+    password TEXT NOT NULL,
+    is_admin INTEGER NOT NULL CHECK (is_admin IN (0, 1)),
+    created_at TEXT DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Triggers de suppression en cascade
+CREATE TRIGGER trg_delete_user_cleanup
+AFTER DELETE ON MovieLens_Users
+FOR EACH ROW
+BEGIN
+    DELETE FROM MovieLens_Ratings WHERE user_id = OLD.user_id;
+    DELETE FROM MovieLens_Tags WHERE user_id = OLD.user_id;
+END;
+
+CREATE TRIGGER trg_delete_movie_cleanup
+AFTER DELETE ON MovieLens_Links
+FOR EACH ROW
+BEGIN
+    DELETE FROM MovieLens_Ratings WHERE movie_id = OLD.movie_id;
+    DELETE FROM MovieLens_Tags WHERE movie_id = OLD.movie_id;
+END;
+
+-- Vues
+CREATE VIEW MovieDetailsView AS
+    SELECT
+        l.movie_id AS movie_id,
+        tmd.id AS tmdb_id,
+        tmd.title,
+        tmd.vote_average,
+        tmd.vote_count,
+        tmd.status,
+        tmd.release_date,
+        tmd.runtime,
+        tmd.adult,
+        tmd.backdrop_path,
+        tmd.overview,
+        tmd.poster_path,
+        tmd.genres,
+        tmd.keywords,
+        tmd.budget,
+        tmd.revenue
+    FROM
+        TMDB_movie_dataset tmd
+    JOIN
+        MovieLens_Links l ON l.tmdb_id = tmd.id;
+
+CREATE VIEW MovieShortView AS
+    SELECT
+        l.movie_id AS movie_id,
+        tmd.id AS tmdb_id,
+        tmd.title,
+        tmd.vote_average,
+        tmd.poster_path,
+        tmd.genres,
+        tmd.popularity,
+        tmd.release_date
+    FROM
+        TMDB_movie_dataset tmd
+    JOIN
+        MovieLens_Links l ON l.tmdb_id = tmd.id;
+
+CREATE VIEW MovieShortView_Alphabetical AS
+    SELECT * FROM MovieShortView ORDER BY title ASC;
+
+CREATE VIEW MovieShortView_ByPopularity AS
+    SELECT * FROM MovieShortView ORDER BY popularity DESC;
+
+CREATE VIEW MovieShortView_ByReleaseDate AS
+    SELECT * FROM MovieShortView ORDER BY release_date DESC;
+
+CREATE VIEW MovieShortView_ByRating AS
+    SELECT * FROM MovieShortView ORDER BY vote_average DESC;
+
+CREATE VIEW TopUsers AS
+    WITH RatingsCount AS (
+        SELECT user_id, COUNT(*) AS num_ratings
+        FROM MovieLens_Ratings
+        GROUP BY user_id
+    ),
+    TagsCount AS (
+        SELECT user_id, COUNT(*) AS num_tags
+        FROM MovieLens_Tags
+        GROUP BY user_id
+    )
+    SELECT
+        u.user_id,
+        COALESCE(r.num_ratings, 0) AS num_ratings,
+        COALESCE(t.num_tags, 0) AS num_tags,
+        COALESCE(r.num_ratings, 0) + COALESCE(t.num_tags, 0) AS total_contributions
+    FROM
+        (SELECT user_id FROM RatingsCount UNION SELECT user_id FROM TagsCount) u
+    LEFT JOIN RatingsCount r ON r.user_id = u.user_id
+    LEFT JOIN TagsCount t ON t.user_id = u.user_id
+    ORDER BY
+        total_contributions DESC;
+
+-- Données de démonstration
+INSERT INTO Users_Records (username, password, is_admin) VALUES
+    ('admin', 'pass', 1),
+    ('spectator', 'pass', 0);
+
+INSERT INTO TMDB_movie_dataset (
+    id, title, vote_average, vote_count, status, release_date, revenue,
+    runtime, adult, backdrop_path, budget, homepage, imdb_id,
+    original_language, original_title, overview, popularity,
+    poster_path, tagline, genres, production_companies,
+    production_countries, spoken_languages, keywords
+) VALUES
+    (862, 'Toy Story', 7.97, 17153, 'Released', '1995-10-30', 394436586, 81, 0, NULL, 30000000, NULL, 'tt0114709',
+     'en', 'Toy Story', 'A cowboy doll feels threatened when a new spaceman action figure becomes his owner''s favourite toy.', 100.2,
+     '/uXDfjJbdP4ijW5hWSBrPrlKpxab.jpg', 'The adventure takes off!', 'Animation, Adventure, Family, Comedy', 'Pixar',
+     'United States of America', 'English', 'toy, friendship, rivalry, boy next door'),
+    (8844, 'Jumanji', 7.24, 10000, 'Released', '1995-12-15', 262797249, 104, 0, NULL, 65000000, NULL, 'tt0113497',
+     'en', 'Jumanji', 'Two siblings discover a board game that releases jungle dangers into their small town.', 15.9,
+     '/vgpXmVaVyUL7GGiDeiK1mKEKzcX.jpg', 'Roll the dice and unleash the excitement!', 'Adventure, Fantasy, Family', 'TriStar Pictures',
+     'United States of America', 'English, French', 'board game, jungle, based on children''s book'),
+    (949, 'Heat', 7.9, 6855, 'Released', '1995-12-15', 187436818, 170, 0, NULL, 60000000, NULL, 'tt0113277',
+     'en', 'Heat', 'A veteran detective and a master thief circle each other across Los Angeles.', 48.9,
+     '/umSVjVdbVwtx5ryCA2QXL44Durm.jpg', 'A Los Angeles crime saga.', 'Crime, Drama, Action', 'Warner Bros. Pictures',
+     'United States of America', 'English, Spanish', 'robbery, detective, bank, los angeles, california'),
+    (11, 'Star Wars', 8.2, 19000, 'Released', '1977-05-25', 775398007, 121, 0, NULL, 11000000, NULL, 'tt0076759',
+     'en', 'Star Wars', 'A farm boy joins a rebellion to rescue a princess from a galactic empire.', 80.1,
+     '/6FfCtAuVAW8XJjZ7eWeLibRLWTw.jpg', 'A long time ago in a galaxy far, far away...', 'Adventure, Action, Science Fiction', 'Lucasfilm',
+     'United States of America', 'English', 'space opera, rebellion, galaxy, empire'),
+    (808, 'Shrek', 7.7, 16000, 'Released', '2001-05-18', 487853320, 90, 0, NULL, 60000000, NULL, 'tt0126029',
+     'en', 'Shrek', 'An ogre sets out to rescue a princess so he can win back the peace of his swamp.', 95.4,
+     '/iB64vpL3dIObOtMZgX3RqdVdQDc.jpg', 'The greatest fairy tale never told.', 'Animation, Comedy, Fantasy, Adventure, Family', 'DreamWorks Animation',
+     'United States of America', 'English', 'ogre, princess, fairy tale, donkey'),
+    (10681, 'WALL·E', 8.1, 18000, 'Released', '2008-06-22', 521311860, 98, 0, NULL, 180000000, NULL, 'tt0910970',
+     'en', 'WALL·E', 'A lonely waste-collecting robot follows a sleek probe back into outer space.', 70.3,
+     '/hbhFnRzzg6ZDmm8YAmxBnQpQIPh.jpg', 'After 700 years of doing what he was built for, he''ll discover what he''s meant for.', 'Animation, Family, Science Fiction', 'Pixar',
+     'United States of America', 'English', 'robot, space, loneliness, pollution'),
+    (603, 'The Matrix', 8.2, 25000, 'Released', '1999-03-31', 463517383, 136, 0, NULL, 63000000, NULL, 'tt0133093',
+     'en', 'The Matrix', 'A hacker learns that reality is a simulation and joins the fight against its machines.', 90.8,
+     '/f89U3ADr1oiB1s9GkdPOEpXUk5H.jpg', 'Welcome to the Real World.', 'Action, Science Fiction', 'Warner Bros. Pictures',
+     'United States of America', 'English', 'simulation, hacker, artificial intelligence, dystopia'),
+    (238, 'The Godfather', 8.7, 19000, 'Released', '1972-03-14', 245066411, 175, 0, NULL, 6000000, NULL, 'tt0068646',
+     'en', 'The Godfather', 'The aging patriarch of a crime dynasty hands control to his reluctant son.', 110.5,
+     '/3bhkrj58Vtu7enYsRolD1fZdja1.jpg', 'An offer you can''t refuse.', 'Drama, Crime', 'Paramount',
+     'United States of America', 'English, Italian', 'mafia, family, patriarch, new york city'),
+    (218, 'The Terminator', 7.7, 12000, 'Released', '1984-10-26', 78371200, 108, 0, NULL, 6400000, NULL, 'tt0088247',
+     'en', 'The Terminator', 'A cyborg assassin is sent back in time to kill the mother of a future resistance leader.', 60.2,
+     '/qvktm0BHcnmDpul4Hz01GIazWPr.jpg', 'Your future is in his hands.', 'Action, Thriller, Science Fiction', 'Orion Pictures',
+     'United States of America', 'English, Spanish', 'cyborg, time travel, future, los angeles, california'),
+    (27205, 'Inception', 8.4, 34000, 'Released', '2010-07-15', 825532764, 148, 0, NULL, 160000000, NULL, 'tt1375666',
+     'en', 'Inception', 'A thief who steals secrets through dreams is offered a chance to plant an idea instead.', 85.7,
+     '/oYuLEt3zVCKq57qu2F8dT7NIa6f.jpg', 'Your mind is the scene of the crime.', 'Action, Science Fiction, Adventure', 'Warner Bros. Pictures',
+     'United States of America, United Kingdom', 'English, French, Japanese', 'dream, heist, subconscious, mind');
+
+INSERT INTO MovieLens_Links (movie_id, imdb_id, tmdb_id) VALUES
+    (1, '0114709', 862),
+    (2, '0113497', 8844),
+    (6, '0113277', 949),
+    (260, '0076759', 11),
+    (4306, '0126029', 808),
+    (60069, '0910970', 10681),
+    (2571, '0133093', 603),
+    (858, '0068646', 238),
+    (1240, '0088247', 218),
+    (79132, '1375666', 27205);
+
+INSERT INTO MovieLens_Ratings (user_id, movie_id, rating, timestamp) VALUES
+    (1, 1, 4.0, 964982703),
+    (1, 260, 5.0, 964981680),
+    (1, 2571, 5.0, 964981710),
+    (1, 1240, 4.5, 964983250),
+    (2, 1, 3.5, 1141415820),
+    (2, 6, 4.0, 1141416150),
+    (2, 858, 5.0, 1141415910),
+    (2, 79132, 4.5, 1445714835),
+    (3, 1, 5.0, 1306463540),
+    (3, 2, 3.0, 1306464230),
+    (3, 4306, 4.0, 1306463600),
+    (3, 60069, 4.5, 1306463720),
+    (4, 260, 4.0, 945078211),
+    (4, 858, 4.5, 945078350),
+    (4, 2571, 3.5, 945078421),
+    (5, 1, 4.0, 847434962),
+    (5, 2, 2.5, 847435292),
+    (5, 6, 3.0, 847435320),
+    (6, 79132, 5.0, 1445715000),
+    (6, 60069, 4.0, 1445715100),
+    (6, 4306, 3.5, 1445715200),
+    (6, 2571, 4.5, 1445715300),
+    (7, 1240, 4.0, 1106635946),
+    (7, 260, 4.5, 1106635990),
+    (7, 858, 5.0, 1106636010);
+
+INSERT INTO MovieLens_Tags (user_id, movie_id, tag, timestamp) VALUES
+    (2, 79132, 'mind-bending', 1445714994),
+    (2, 79132, 'thought-provoking', 1445714996),
+    (3, 60069, 'pixar', 1306463800),
+    (3, 1, 'pixar', 1306463810),
+    (4, 858, 'classic', 945078500),
+    (6, 79132, 'dreams', 1445715400),
+    (7, 1240, 'time travel', 1106636100),
+    (7, 260, 'space', 1106636120);
+
+INSERT INTO MovieLens_Users (user_id)
+    SELECT user_id FROM MovieLens_Ratings
+    UNION
+    SELECT user_id FROM MovieLens_Tags;
@@ -0,0 +1,24 @@
+-- Colonnes de filtre de la recherche avancée (SearchQuery)
+
+DROP VIEW MovieShortView;
+
+CREATE VIEW MovieShortView AS
+    SELECT
+        l.movie_id AS movie_id,
+        tmd.id AS tmdb_id,
+        tmd.title,
+        tmd.vote_average,
+        tmd.poster_path,
+        tmd.genres,
+        tmd.popularity,
+        tmd.release_date,
+        CAST(SUBSTR(tmd.release_date, 1, 4) AS INTEGER) AS release_year,
+        tmd.vote_count,
+        tmd.runtime,
+        tmd.original_language,
+        tmd.status,
+        tmd.adult
+    FROM
+        TMDB_movie_dataset tmd
+    JOIN
+        MovieLens_Links l ON l.tmdb_id = tmd.id;
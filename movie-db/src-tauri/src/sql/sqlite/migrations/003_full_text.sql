@@ -0,0 +1,34 @@
+-- Index plein texte équivalent à l'index Oracle Text TMDB_movie_text_idx
+
+CREATE VIRTUAL TABLE TMDB_movie_text USING fts5(
+    title,
+    overview,
+    tagline,
+    keywords,
+    content = 'TMDB_movie_dataset',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO TMDB_movie_text (TMDB_movie_text) VALUES ('rebuild');
+
+-- Synchronisation de l'index avec la table de contenu
+CREATE TRIGGER TMDB_movie_text_insert AFTER INSERT ON TMDB_movie_dataset
+BEGIN
+    INSERT INTO TMDB_movie_text (rowid, title, overview, tagline, keywords)
+    VALUES (new.id, new.title, new.overview, new.tagline, new.keywords);
+END;
+
+CREATE TRIGGER TMDB_movie_text_delete AFTER DELETE ON TMDB_movie_dataset
+BEGIN
+    INSERT INTO TMDB_movie_text (TMDB_movie_text, rowid, title, overview, tagline, keywords)
+    VALUES ('delete', old.id, old.title, old.overview, old.tagline, old.keywords);
+END;
+
+CREATE TRIGGER TMDB_movie_text_update AFTER UPDATE ON TMDB_movie_dataset
+BEGIN
+    INSERT INTO TMDB_movie_text (TMDB_movie_text, rowid, title, overview, tagline, keywords)
+    VALUES ('delete', old.id, old.title, old.overview, old.tagline, old.keywords);
+    INSERT INTO TMDB_movie_text (rowid, title, overview, tagline, keywords)
+    VALUES (new.id, new.title, new.overview, new.tagline, new.keywords);
+END;
@@ -0,0 +1,24 @@
+-- Index des trigrammes des titres pour la recherche approchée, équivalent aux tables Oracle
+-- du même nom ; il est rempli par `index_movie_titles` (les trigrammes sont calculés en Rust)
+
+-- Une ligne par titre indexé : 'T' pour le titre, 'O' pour le titre original s'il diffère
+CREATE TABLE Movie_Title_Index (
+    tmdb_id INTEGER NOT NULL,
+    variant TEXT NOT NULL,
+    title TEXT NOT NULL,
+    trigram_count INTEGER NOT NULL,
+    PRIMARY KEY (tmdb_id, variant)
+);
+
+CREATE TABLE Movie_Title_Trigrams (
+    trigram TEXT NOT NULL,
+    tmdb_id INTEGER NOT NULL,
+    variant TEXT NOT NULL,
+    PRIMARY KEY (trigram, tmdb_id, variant)
+) WITHOUT ROWID;
+
+CREATE TRIGGER TMDB_movie_title_delete AFTER DELETE ON TMDB_movie_dataset
+BEGIN
+    DELETE FROM Movie_Title_Trigrams WHERE tmdb_id = old.id;
+    DELETE FROM Movie_Title_Index WHERE tmdb_id = old.id;
+END;
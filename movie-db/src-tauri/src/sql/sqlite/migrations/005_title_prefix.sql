@@ -0,0 +1,4 @@
+-- Index sur le titre en minuscules pour l'autocomplétion, équivalent à l'index
+-- fonctionnel Oracle TMDB_movie_dataset_lower_title_idx
+
+CREATE INDEX TMDB_movie_dataset_lower_title_idx ON TMDB_movie_dataset(LOWER(title));
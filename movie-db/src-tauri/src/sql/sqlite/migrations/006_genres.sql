@@ -0,0 +1,99 @@
+-- Genres normalisés, équivalents aux tables Oracle Genres et Movie_Genres
+
+CREATE TABLE Genres (
+    genre_id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+-- position : ordre du genre dans la liste d'origine
+CREATE TABLE Movie_Genres (
+    tmdb_id INTEGER NOT NULL REFERENCES TMDB_movie_dataset (id) ON DELETE CASCADE,
+    genre_id INTEGER NOT NULL REFERENCES Genres (genre_id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (tmdb_id, genre_id)
+) WITHOUT ROWID;
+
+CREATE INDEX Movie_Genres_genre_id_idx ON Movie_Genres(genre_id, tmdb_id);
+
+-- Découpe la liste des genres (séparés par des virgules) avec une CTE récursive
+CREATE TEMP TABLE GenreSplit AS
+    WITH RECURSIVE Split(tmdb_id, position, genre, rest) AS (
+        SELECT id, 0, '', genres || ','
+        FROM TMDB_movie_dataset
+        WHERE genres IS NOT NULL
+        UNION ALL
+        SELECT
+            tmdb_id,
+            position + 1,
+            TRIM(SUBSTR(rest, 1, INSTR(rest, ',') - 1)),
+            SUBSTR(rest, INSTR(rest, ',') + 1)
+        FROM Split
+        WHERE rest <> ''
+    )
+    SELECT tmdb_id, position, genre FROM Split WHERE genre <> '';
+
+-- Identifiants dans l'ordre alphabétique, comme la migration Oracle
+INSERT INTO Genres (genre_id, name)
+    SELECT ROW_NUMBER() OVER (ORDER BY genre), genre
+    FROM (SELECT DISTINCT genre FROM GenreSplit);
+
+INSERT OR IGNORE INTO Movie_Genres (tmdb_id, genre_id, position)
+    SELECT s.tmdb_id, g.genre_id, s.position
+    FROM GenreSplit s
+    JOIN Genres g ON g.name = s.genre
+    ORDER BY s.tmdb_id, s.position;
+
+DROP TABLE GenreSplit;
+
+-- Les clés étrangères ne sont pas activées : suppression en cascade par trigger
+CREATE TRIGGER TMDB_movie_genres_delete AFTER DELETE ON TMDB_movie_dataset
+BEGIN
+    DELETE FROM Movie_Genres WHERE tmdb_id = old.id;
+END;
+
+-- La colonne genres n'est plus exposée par les vues
+DROP VIEW MovieDetailsView;
+
+CREATE VIEW MovieDetailsView AS
+    SELECT
+        l.movie_id AS movie_id,
+        tmd.id AS tmdb_id,
+        tmd.title,
+        tmd.vote_average,
+        tmd.vote_count,
+        tmd.status,
+        tmd.release_date,
+        tmd.runtime,
+        tmd.adult,
+        tmd.backdrop_path,
+        tmd.overview,
+        tmd.poster_path,
+        tmd.keywords,
+        tmd.budget,
+        tmd.revenue
+    FROM
+        TMDB_movie_dataset tmd
+    JOIN
+        MovieLens_Links l ON l.tmdb_id = tmd.id;
+
+DROP VIEW MovieShortView;
+
+CREATE VIEW MovieShortView AS
+    SELECT
+        l.movie_id AS movie_id,
+        tmd.id AS tmdb_id,
+        tmd.title,
+        tmd.vote_average,
+        tmd.poster_path,
+        tmd.popularity,
+        tmd.release_date,
+        CAST(SUBSTR(tmd.release_date, 1, 4) AS INTEGER) AS release_year,
+        tmd.vote_count,
+        tmd.runtime,
+        tmd.original_language,
+        tmd.status,
+        tmd.adult
+    FROM
+        TMDB_movie_dataset tmd
+    JOIN
+        MovieLens_Links l ON l.tmdb_id = tmd.id;
@@ -0,0 +1,67 @@
+-- Mots-clés, sociétés de production, pays et langues parlées normalisés, équivalents
+-- aux tables Oracle ; alimentées ensuite par `split_movie_facets`
+
+CREATE TABLE Keywords (
+    keyword_id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE Movie_Keywords (
+    tmdb_id INTEGER NOT NULL REFERENCES TMDB_movie_dataset (id) ON DELETE CASCADE,
+    keyword_id INTEGER NOT NULL REFERENCES Keywords (keyword_id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (tmdb_id, keyword_id)
+) WITHOUT ROWID;
+
+CREATE INDEX Movie_Keywords_keyword_id_idx ON Movie_Keywords(keyword_id, tmdb_id);
+
+CREATE TABLE Companies (
+    company_id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE Movie_Companies (
+    tmdb_id INTEGER NOT NULL REFERENCES TMDB_movie_dataset (id) ON DELETE CASCADE,
+    company_id INTEGER NOT NULL REFERENCES Companies (company_id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (tmdb_id, company_id)
+) WITHOUT ROWID;
+
+CREATE INDEX Movie_Companies_company_id_idx ON Movie_Companies(company_id, tmdb_id);
+
+CREATE TABLE Countries (
+    country_id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE Movie_Countries (
+    tmdb_id INTEGER NOT NULL REFERENCES TMDB_movie_dataset (id) ON DELETE CASCADE,
+    country_id INTEGER NOT NULL REFERENCES Countries (country_id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (tmdb_id, country_id)
+) WITHOUT ROWID;
+
+CREATE INDEX Movie_Countries_country_id_idx ON Movie_Countries(country_id, tmdb_id);
+
+CREATE TABLE Languages (
+    language_id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE Movie_Languages (
+    tmdb_id INTEGER NOT NULL REFERENCES TMDB_movie_dataset (id) ON DELETE CASCADE,
+    language_id INTEGER NOT NULL REFERENCES Languages (language_id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (tmdb_id, language_id)
+) WITHOUT ROWID;
+
+CREATE INDEX Movie_Languages_language_id_idx ON Movie_Languages(language_id, tmdb_id);
+
+-- Les clés étrangères ne sont pas activées : suppression en cascade par trigger
+CREATE TRIGGER TMDB_movie_facets_delete AFTER DELETE ON TMDB_movie_dataset
+BEGIN
+    DELETE FROM Movie_Keywords WHERE tmdb_id = old.id;
+    DELETE FROM Movie_Companies WHERE tmdb_id = old.id;
+    DELETE FROM Movie_Countries WHERE tmdb_id = old.id;
+    DELETE FROM Movie_Languages WHERE tmdb_id = old.id;
+END;
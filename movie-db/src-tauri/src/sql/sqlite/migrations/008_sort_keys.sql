@@ -0,0 +1,29 @@
+-- Le tri est appliqué par la requête de recherche : les vues triées ne sont plus utilisées
+DROP VIEW MovieShortView_Alphabetical;
+DROP VIEW MovieShortView_ByPopularity;
+DROP VIEW MovieShortView_ByReleaseDate;
+DROP VIEW MovieShortView_ByRating;
+
+-- revenue devient une clé de tri
+DROP VIEW MovieShortView;
+
+CREATE VIEW MovieShortView AS
+    SELECT
+        l.movie_id AS movie_id,
+        tmd.id AS tmdb_id,
+        tmd.title,
+        tmd.vote_average,
+        tmd.poster_path,
+        tmd.popularity,
+        tmd.release_date,
+        CAST(SUBSTR(tmd.release_date, 1, 4) AS INTEGER) AS release_year,
+        tmd.vote_count,
+        tmd.runtime,
+        tmd.revenue,
+        tmd.original_language,
+        tmd.status,
+        tmd.adult
+    FROM
+        TMDB_movie_dataset tmd
+    JOIN
+        MovieLens_Links l ON l.tmdb_id = tmd.id;
@@ -0,0 +1,12 @@
+-- Voisins de chaque film d'après les notes, équivalent à la table Oracle du même nom ;
+-- elle est remplie par `compute_movie_similarities` (la similarité est calculée en Rust)
+
+-- rank : 1 pour le voisin le plus proche
+CREATE TABLE Movie_Similarities (
+    movie_id INTEGER NOT NULL,
+    similar_movie_id INTEGER NOT NULL,
+    rank INTEGER NOT NULL,
+    similarity REAL NOT NULL,
+    co_ratings INTEGER NOT NULL,
+    PRIMARY KEY (movie_id, similar_movie_id)
+);
@@ -0,0 +1,32 @@
+-- Modèle de recommandation par facteurs latents, équivalent aux tables Oracle du même nom ;
+-- il est entraîné par `train_rating_model` (la factorisation est calculée en Rust)
+
+-- Une ligne par entraînement ; active : modèle dont les facteurs sont dans User_Factors et Movie_Factors
+CREATE TABLE Rating_Models (
+    model_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    model_version INTEGER NOT NULL,
+    run_mode TEXT NOT NULL CHECK (run_mode IN ('TRAIN', 'EVALUATE')),
+    factor_count INTEGER NOT NULL,
+    epochs INTEGER NOT NULL,
+    learning_rate REAL NOT NULL,
+    regularization REAL NOT NULL,
+    global_mean REAL NOT NULL,
+    train_ratings INTEGER NOT NULL,
+    holdout_ratings INTEGER NOT NULL,
+    rmse REAL,
+    active INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT DEFAULT CURRENT_TIMESTAMP
+);
+
+-- factors : valeurs séparées par des virgules
+CREATE TABLE User_Factors (
+    user_id INTEGER PRIMARY KEY,
+    bias REAL NOT NULL,
+    factors TEXT NOT NULL
+);
+
+CREATE TABLE Movie_Factors (
+    movie_id INTEGER PRIMARY KEY,
+    bias REAL NOT NULL,
+    factors TEXT NOT NULL
+);
@@ -0,0 +1,24 @@
+-- Caractéristiques de contenu des films, équivalentes aux tables Oracle du même nom ;
+-- elles sont remplies par `compute_content_features` (les poids sont calculés en Rust)
+
+-- kind : 'G' genre, 'K' mot-clé, 'T' terme du résumé
+CREATE TABLE Content_Features (
+    feature_id INTEGER PRIMARY KEY,
+    kind TEXT NOT NULL CHECK (kind IN ('G', 'K', 'T')),
+    name TEXT NOT NULL,
+    movie_count INTEGER NOT NULL,
+    weight REAL NOT NULL
+);
+
+CREATE TABLE Movie_Content_Features (
+    tmdb_id INTEGER NOT NULL,
+    feature_id INTEGER NOT NULL,
+    PRIMARY KEY (tmdb_id, feature_id)
+) WITHOUT ROWID;
+
+CREATE INDEX Movie_Content_Features_ref_idx ON Movie_Content_Features(feature_id, tmdb_id);
+
+CREATE TABLE Movie_Content_Norms (
+    tmdb_id INTEGER PRIMARY KEY,
+    total_weight REAL NOT NULL
+);
@@ -0,0 +1,62 @@
+-- Score pondéré des films, équivalent à la table Oracle Movie_Weighted_Ratings et à la procédure
+-- RefreshWeightedRatings avec ses paramètres par défaut : 200 votes minimum, a priori égal à la
+-- moyenne des notes TMDB, moyenne MovieLens non prise en compte (part 0, 10 notes minimum)
+CREATE TABLE Movie_Weighted_Ratings (
+    movie_id INTEGER PRIMARY KEY,
+    weighted_rating REAL NOT NULL
+);
+
+INSERT INTO Movie_Weighted_Ratings (movie_id, weighted_rating)
+    WITH Prior(value) AS (
+        SELECT COALESCE(AVG(t.vote_average), 0)
+        FROM TMDB_movie_dataset t
+        JOIN MovieLens_Links l ON l.tmdb_id = t.id
+        WHERE t.vote_count > 0
+    ),
+    MovieLensRatings(movie_id, rating_count, rating_mean) AS (
+        SELECT movie_id, COUNT(*), AVG(rating)
+        FROM MovieLens_Ratings
+        GROUP BY movie_id
+    )
+    SELECT
+        l.movie_id,
+        ROUND(
+            (1 - 0.0)
+                * (COALESCE(t.vote_count, 0) * COALESCE(t.vote_average, 0) + 200.0 * p.value)
+                / (COALESCE(t.vote_count, 0) + 200.0)
+            + 0.0
+                * (COALESCE(r.rating_count, 0) * COALESCE(r.rating_mean, 0) * 2 + 10.0 * p.value)
+                / (COALESCE(r.rating_count, 0) + 10.0),
+            6
+        )
+    FROM MovieLens_Links l
+    JOIN TMDB_movie_dataset t ON t.id = l.tmdb_id
+    CROSS JOIN Prior p
+    LEFT JOIN MovieLensRatings r ON r.movie_id = l.movie_id;
+
+-- weighted_rating devient une colonne et une clé de tri
+DROP VIEW MovieShortView;
+
+CREATE VIEW MovieShortView AS
+    SELECT
+        l.movie_id AS movie_id,
+        tmd.id AS tmdb_id,
+        tmd.title,
+        tmd.vote_average,
+        tmd.poster_path,
+        tmd.popularity,
+        tmd.release_date,
+        CAST(SUBSTR(tmd.release_date, 1, 4) AS INTEGER) AS release_year,
+        tmd.vote_count,
+        tmd.runtime,
+        tmd.revenue,
+        tmd.original_language,
+        tmd.status,
+        tmd.adult,
+        w.weighted_rating
+    FROM
+        TMDB_movie_dataset tmd
+    JOIN
+        MovieLens_Links l ON l.tmdb_id = tmd.id
+    LEFT JOIN
+        Movie_Weighted_Ratings w ON w.movie_id = l.movie_id;
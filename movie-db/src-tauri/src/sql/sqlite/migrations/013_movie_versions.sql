@@ -0,0 +1,29 @@
+-- Version des lignes de TMDB_movie_dataset, incrémentée à chaque modification d'un film :
+-- `update_movie` refuse une modification faite à partir d'une version dépassée
+
+ALTER TABLE TMDB_movie_dataset ADD COLUMN row_version INTEGER NOT NULL DEFAULT 1;
+
+DROP VIEW MovieDetailsView;
+
+CREATE VIEW MovieDetailsView AS
+    SELECT
+        l.movie_id AS movie_id,
+        tmd.id AS tmdb_id,
+        tmd.title,
+        tmd.vote_average,
+        tmd.vote_count,
+        tmd.status,
+        tmd.release_date,
+        tmd.runtime,
+        tmd.adult,
+        tmd.backdrop_path,
+        tmd.overview,
+        tmd.poster_path,
+        tmd.keywords,
+        tmd.budget,
+        tmd.revenue,
+        tmd.row_version
+    FROM
+        TMDB_movie_dataset tmd
+    JOIN
+        MovieLens_Links l ON l.tmdb_id = tmd.id;
@@ -0,0 +1,84 @@
+-- Corbeille MovieLens, équivalente aux tables Oracle MovieLens_Deletions, Deleted_MovieLens_Ratings
+-- et Deleted_MovieLens_Tags : les triggers de suppression en cascade archivent les lignes supprimées.
+-- deleted_by est renseigné par l'application dans la même transaction (NULL pour une suppression manuelle)
+CREATE TABLE MovieLens_Deletions (
+    deletion_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    kind TEXT NOT NULL CHECK (kind IN ('User', 'Movie', 'Tag')),
+    user_id INTEGER,
+    movie_id INTEGER,
+    imdb_id TEXT,
+    tmdb_id INTEGER,
+    deleted_by TEXT,
+    deleted_at INTEGER NOT NULL
+);
+
+CREATE INDEX MovieLens_Deletions_user_id_idx ON MovieLens_Deletions(user_id);
+CREATE INDEX MovieLens_Deletions_movie_id_idx ON MovieLens_Deletions(movie_id);
+
+CREATE TABLE Deleted_MovieLens_Ratings (
+    deletion_id INTEGER NOT NULL,
+    user_id INTEGER,
+    movie_id INTEGER,
+    rating REAL,
+    timestamp INTEGER
+);
+
+CREATE INDEX Deleted_Ratings_deletion_idx ON Deleted_MovieLens_Ratings(deletion_id);
+
+CREATE TABLE Deleted_MovieLens_Tags (
+    deletion_id INTEGER NOT NULL,
+    user_id INTEGER,
+    movie_id INTEGER,
+    tag TEXT,
+    timestamp INTEGER
+);
+
+CREATE INDEX Deleted_Tags_deletion_idx ON Deleted_MovieLens_Tags(deletion_id);
+
+-- Les lignes sont archivées avant la suppression en cascade ; last_insert_rowid() change
+-- à chaque insertion du trigger, la suppression archivée est donc la dernière créée
+DROP TRIGGER trg_delete_user_cleanup;
+
+CREATE TRIGGER trg_delete_user_cleanup
+AFTER DELETE ON MovieLens_Users
+FOR EACH ROW
+BEGIN
+    INSERT INTO MovieLens_Deletions (kind, user_id, deleted_at)
+    VALUES ('User', OLD.user_id, CAST(strftime('%s', 'now') AS INTEGER));
+
+    INSERT INTO Deleted_MovieLens_Ratings (deletion_id, user_id, movie_id, rating, timestamp)
+    SELECT (SELECT MAX(deletion_id) FROM MovieLens_Deletions), user_id, movie_id, rating, timestamp
+    FROM MovieLens_Ratings
+    WHERE user_id = OLD.user_id;
+
+    INSERT INTO Deleted_MovieLens_Tags (deletion_id, user_id, movie_id, tag, timestamp)
+    SELECT (SELECT MAX(deletion_id) FROM MovieLens_Deletions), user_id, movie_id, tag, timestamp
+    FROM MovieLens_Tags
+    WHERE user_id = OLD.user_id;
+
+    DELETE FROM MovieLens_Ratings WHERE user_id = OLD.user_id;
+    DELETE FROM MovieLens_Tags WHERE user_id = OLD.user_id;
+END;
+
+DROP TRIGGER trg_delete_movie_cleanup;
+
+CREATE TRIGGER trg_delete_movie_cleanup
+AFTER DELETE ON MovieLens_Links
+FOR EACH ROW
+BEGIN
+    INSERT INTO MovieLens_Deletions (kind, movie_id, imdb_id, tmdb_id, deleted_at)
+    VALUES ('Movie', OLD.movie_id, OLD.imdb_id, OLD.tmdb_id, CAST(strftime('%s', 'now') AS INTEGER));
+
+    INSERT INTO Deleted_MovieLens_Ratings (deletion_id, user_id, movie_id, rating, timestamp)
+    SELECT (SELECT MAX(deletion_id) FROM MovieLens_Deletions), user_id, movie_id, rating, timestamp
+    FROM MovieLens_Ratings
+    WHERE movie_id = OLD.movie_id;
+
+    INSERT INTO Deleted_MovieLens_Tags (deletion_id, user_id, movie_id, tag, timestamp)
+    SELECT (SELECT MAX(deletion_id) FROM MovieLens_Deletions), user_id, movie_id, tag, timestamp
+    FROM MovieLens_Tags
+    WHERE movie_id = OLD.movie_id;
+
+    DELETE FROM MovieLens_Ratings WHERE movie_id = OLD.movie_id;
+    DELETE FROM MovieLens_Tags WHERE movie_id = OLD.movie_id;
+END;
@@ -0,0 +1,13 @@
+-- Journal des actions d'administration, équivalent à la table Oracle Admin_Audit_Log.
+-- Les lignes sont écrites par l'application dans la transaction de chaque action
+CREATE TABLE Admin_Audit_Log (
+    audit_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    acted_by TEXT NOT NULL,
+    acted_at INTEGER NOT NULL,
+    action TEXT NOT NULL,
+    target TEXT,
+    parameters TEXT
+);
+
+CREATE INDEX Admin_Audit_Log_acted_at_idx ON Admin_Audit_Log(acted_at);
+CREATE INDEX Admin_Audit_Log_target_idx ON Admin_Audit_Log(target);
@@ -0,0 +1,1171 @@
+pub mod queries;
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::BTreeMap,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use movie_db_models::{content::ContentIndex, similarity::movie_neighbours};
+use r2d2::{Pool, PooledConnection};
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{params, Connection, OptionalExtension, TransactionBehavior};
+
+use super::{
+    config::PoolConfig,
+    content::{attach_shared_features, shared_features_query},
+    moderation::{check_expected_matches, preview, select_tags_query, ModerationRun, TagRule},
+    recommendation::{
+        into_recommendations, select_movies_query, top_predictions, FactorModel, Factors, EPOCHS, FACTOR_COUNT, LEARNING_RATE,
+        MODEL_VERSION, NO_MODEL_MESSAGE, REGULARIZATION,
+    },
+    search::{AuditLogSearch, Dialect, FacetSearch, MovieActivitySearch, MovieSearch, SearchMode, SqlQuery, TrashSearch, UserActivitySearch},
+    store::{MovieStore, StoreError},
+    trigram::trigrams,
+    types::{
+        audit::{AdminAction, AuditEntry},
+        content::{ContentMatch, SharedFeature},
+        facet::{FacetValue, MovieFacet},
+        genre::Genre,
+        movie::{Movie, MovieDetails, MovieMatch, MovieRating, MovieShort, MovieTag, MovieUpdate, NewMovie, RatingSummary, Recommendation, SimilarMovie, TitleCompletion},
+        movie_lens_user::{GenreAffinity, MovieLensUserProfile, TagUsage, UserRating, UserSummary, UserTag, TOP_TAG_COUNT},
+        page::{Page, SortValue},
+        sql_user::{SqlUser, SqlUserCredentials},
+        stats::{CountStats, MovieGenreCountStats, Stats, TopMovieProfit, TopUserItem},
+        tag_moderation::{TagModerationPreview, TagModerationReport, TagRow},
+        trash::{TrashItem, TrashKind, TrashRestore},
+    },
+};
+use queries::{
+    ARCHIVE_MODERATED_TAG, ARCHIVE_MOVIE_LENS_TAG, AUTOCOMPLETE_TITLES, COUNT_DISTINCT_USERS, COUNT_MOVIES, COUNT_MOVIE_DUPLICATES, COUNT_MOVIE_LENS_TAG,
+    COUNT_MOVIE_LINK, COUNT_RATINGS, COUNT_RELINKED_MOVIE, COUNT_TAGS, COUNT_USER_MOVIE_RATINGS, CREATE_SQL_USER, DELETE_DELETED_RATINGS,
+    DELETE_DELETED_TAGS, DELETE_DELETION, DELETE_MODERATED_TAG, DELETE_MOVIE_LENS_TAG, DELETE_MOVIE_LENS_USER, DELETE_SQL_USER, GENRE_COUNT,
+    GET_SQL_USERS, INSERT_AUDIT_ENTRY, INSERT_GENRE, INSERT_MOVIE, INSERT_MOVIE_GENRE, INSERT_MOVIE_LENS_RATING, INSERT_MOVIE_LENS_TAG,
+    INSERT_MOVIE_LENS_USER, INSERT_MOVIE_LINK, INSERT_TAG_DELETION, RENAME_MOVIE_LENS_TAG, RESTORE_RATINGS, RESTORE_TAGS,
+    REFRESH_MOVIE_WEIGHTED_RATING, REWRITE_MODERATED_TAG, SELECT_BLOCKING_DELETION, SELECT_DELETION, SELECT_GENRES, SELECT_LAST_DELETION_ID, SELECT_NEXT_MOVIE_ID, SELECT_NEXT_TAG_TIMESTAMP, SELECT_MOVIE_BY_ID, SELECT_MOVIE_GENRES, SELECT_MOVIE_RATING_HISTOGRAM, SELECT_MOVIE_RATING_SUMMARY, SELECT_MOVIE_RATINGS,
+    SELECT_MOVIE_LENS_USER_SUMMARY, SELECT_MOVIE_TAGS, SELECT_RATING_MODEL, SELECT_SIMILAR_MOVIES, SELECT_SIMILAR_MOVIES_BY_CONTENT,
+    SELECT_MOVIE_TITLES, SELECT_MOVIE_VERSION, SELECT_SQL_USER, SELECT_UNSEEN_MOVIE_FACTORS, SELECT_USER_FACTORS,
+    SELECT_USER_GENRE_RATINGS, SELECT_USER_TOP_TAGS, SET_DELETIONS_AUTHOR, TOP_PROFITS_MOVIES, TOP_USERS, UPDATE_MOVIE,
+    UPDATE_MOVIE_LENS_RATING,
+};
+
+/// Étape de migration : script SQL ou traitement Rust
+enum Migration {
+    Sql(&'static str),
+    Code(fn(&Connection) -> rusqlite::Result<()>),
+}
+
+// Migrations appliquées dans l'ordre, suivies via `PRAGMA user_version`
+const MIGRATIONS: &[Migration] = &[
+    Migration::Sql(include_str!("migrations/001_init.sql")),
+    Migration::Sql(include_str!("migrations/002_search_columns.sql")),
+    Migration::Sql(include_str!("migrations/003_full_text.sql")),
+    Migration::Sql(include_str!("migrations/004_title_trigrams.sql")),
+    Migration::Code(index_movie_titles),
+    Migration::Sql(include_str!("migrations/005_title_prefix.sql")),
+    Migration::Sql(include_str!("migrations/006_genres.sql")),
+    Migration::Sql(include_str!("migrations/007_movie_facets.sql")),
+    Migration::Code(split_movie_facets),
+    Migration::Sql(include_str!("migrations/008_sort_keys.sql")),
+    Migration::Sql(include_str!("migrations/009_movie_similarities.sql")),
+    Migration::Code(compute_movie_similarities),
+    Migration::Sql(include_str!("migrations/010_rating_model.sql")),
+    Migration::Code(train_rating_model),
+    Migration::Sql(include_str!("migrations/011_content_features.sql")),
+    Migration::Code(compute_content_features),
+    Migration::Sql(include_str!("migrations/012_weighted_ratings.sql")),
+    Migration::Sql(include_str!("migrations/013_movie_versions.sql")),
+    Migration::Sql(include_str!("migrations/014_trash.sql")),
+    Migration::Sql(include_str!("migrations/015_audit_log.sql")),
+    Migration::Code(hash_stored_passwords),
+];
+
+/// Backend SQLite embarqué, pour travailler sans instance Oracle
+pub struct SqliteStore {
+    pool: Pool<SqliteConnectionManager>,
+    user: SqlUser,
+}
+
+impl SqliteStore {
+    /// Ouvre (ou crée) la base locale puis authentifie l'utilisateur
+    pub fn open(path: &str, user: SqlUserCredentials, config: &PoolConfig) -> Result<Self, StoreError> {
+        // WAL : les lectures concurrentes ne sont pas bloquées par une écriture
+        let manager = SqliteConnectionManager::file(path)
+            .with_init(|c| c.execute_batch("PRAGMA journal_mode = WAL; PRAGMA busy_timeout = 5000;"));
+
+        let pool = Pool::builder()
+            .min_idle(Some(config.min_connections))
+            .max_size(config.max_connections)
+            .build(manager)?;
+
+        let mut conn = pool.get()?;
+        migrate(&mut conn)?;
+
+        let account = conn
+            .query_row(SELECT_SQL_USER, params![user.username], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, i32>(2)?))
+            })
+            .optional()?;
+
+        match account {
+            Some((username, password_hash, is_admin)) if verify_password(&user.password, &password_hash) => Ok(Self {
+                pool,
+                user: SqlUser {
+                    username,
+                    is_admin: is_admin == 1,
+                    created_at: "".to_string(),
+                },
+            }),
+            _ => Err(StoreError::Message("Nom d'utilisateur ou mot de passe invalide".to_string())),
+        }
+    }
+
+    fn conn(&self) -> Result<PooledConnection<SqliteConnectionManager>, StoreError> {
+        Ok(self.pool.get()?)
+    }
+
+    fn require_admin(&self) -> Result<(), StoreError> {
+        if self.user.is_admin {
+            Ok(())
+        } else {
+            Err(StoreError::Message("Privilèges insuffisants".to_string()))
+        }
+    }
+
+    /// Journalise une action dans la transaction en cours, comme la procédure Oracle LogAdminAction
+    fn log_action(&self, conn: &Connection, action: AdminAction, target: Option<String>, parameters: Option<String>) -> rusqlite::Result<()> {
+        conn.execute(INSERT_AUDIT_ENTRY, params![self.user.username, unix_now(), action.as_str(), target, parameters])?;
+        Ok(())
+    }
+
+    fn get_movie_details(&self, movie_id: i32) -> Result<Option<MovieDetails>, StoreError> {
+        let conn = self.conn()?;
+        let mut details = match conn
+            .query_row(SELECT_MOVIE_BY_ID, params![movie_id], MovieDetails::from_sqlite_row)
+            .optional()?
+        {
+            Some(details) => details,
+            None => return Ok(None),
+        };
+
+        let mut stmt = conn.prepare(SELECT_MOVIE_GENRES)?;
+        details.genres = stmt
+            .query_map(params![movie_id], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Some(details))
+    }
+
+    fn get_movie_ratings(&self, movie_id: i32) -> Result<Vec<MovieRating>, StoreError> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(SELECT_MOVIE_RATINGS)?;
+        let ratings = stmt
+            .query_map(params![movie_id], MovieRating::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ratings)
+    }
+
+    fn get_movie_rating_summary(&self, movie_id: i32) -> Result<RatingSummary, StoreError> {
+        let conn = self.conn()?;
+        let summary = conn.query_row(SELECT_MOVIE_RATING_SUMMARY, params![movie_id], RatingSummary::from_sqlite_row)?;
+
+        let mut stmt = conn.prepare(SELECT_MOVIE_RATING_HISTOGRAM)?;
+        let counts = stmt
+            .query_map(params![movie_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(summary.with_histogram(counts))
+    }
+
+    fn get_movie_tags(&self, movie_id: i32) -> Result<Vec<MovieTag>, StoreError> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(SELECT_MOVIE_TAGS)?;
+        let tags = stmt
+            .query_map(params![movie_id], MovieTag::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(tags)
+    }
+}
+
+/// Applique les migrations manquantes dans une transaction
+fn migrate(conn: &mut Connection) -> Result<(), StoreError> {
+    let version: usize = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+        let tx = conn.transaction()?;
+        match migration {
+            Migration::Sql(sql) => tx.execute_batch(sql)?,
+            Migration::Code(run) => run(&tx)?,
+        }
+        tx.pragma_update(None, "user_version", index + 1)?;
+        tx.commit()?;
+    }
+
+    Ok(())
+}
+
+/// Remplace les mots de passe en clair de Users_Records, dont ceux des comptes de démonstration,
+/// par leur empreinte
+fn hash_stored_passwords(conn: &Connection) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare("SELECT username, password FROM Users_Records")?;
+    let accounts = stmt
+        .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (username, password) in accounts {
+        let password_hash = hash_password(&password).map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+        conn.execute("UPDATE Users_Records SET password = ?1 WHERE username = ?2", params![password_hash, username])?;
+    }
+    Ok(())
+}
+
+/// Empreinte Argon2 d'un mot de passe, salée, au format PHC (`$argon2id$...`)
+fn hash_password(password: &str) -> Result<String, argon2::password_hash::Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    Ok(Argon2::default().hash_password(password.as_bytes(), &salt)?.to_string())
+}
+
+/// Compare un mot de passe à son empreinte ; une empreinte illisible ne correspond à aucun mot de passe
+fn verify_password(password: &str, password_hash: &str) -> bool {
+    PasswordHash::new(password_hash)
+        .map(|hash| Argon2::default().verify_password(password.as_bytes(), &hash).is_ok())
+        .unwrap_or(false)
+}
+
+/// Indexe les trigrammes des titres de tous les films
+fn index_movie_titles(conn: &Connection) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare("SELECT id, title, original_title FROM TMDB_movie_dataset")?;
+    let movies = stmt
+        .query_map([], |row| Ok((row.get::<_, i32>(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<Result<Vec<(i32, Option<String>, Option<String>)>, _>>()?;
+
+    for (tmdb_id, title, original_title) in movies {
+        index_movie_title(conn, tmdb_id, title.as_deref(), original_title.as_deref())?;
+    }
+    Ok(())
+}
+
+/// Équivalent de la procédure Oracle `IndexMovieTitle`, à appeler après chaque
+/// modification du titre d'un film
+fn index_movie_title(
+    conn: &Connection,
+    tmdb_id: i32,
+    title: Option<&str>,
+    original_title: Option<&str>,
+) -> rusqlite::Result<()> {
+    conn.execute("DELETE FROM Movie_Title_Trigrams WHERE tmdb_id = ?1", params![tmdb_id])?;
+    conn.execute("DELETE FROM Movie_Title_Index WHERE tmdb_id = ?1", params![tmdb_id])?;
+
+    // Le titre original n'est indexé que s'il diffère du titre
+    let original_title = original_title.filter(|original| {
+        title.map(|title| title.to_lowercase() != original.to_lowercase()).unwrap_or(true)
+    });
+
+    for (variant, text) in [("T", title), ("O", original_title)] {
+        let Some(text) = text else { continue };
+        let trigrams = trigrams(text);
+        if trigrams.is_empty() {
+            continue;
+        }
+
+        conn.execute(
+            "INSERT INTO Movie_Title_Index (tmdb_id, variant, title, trigram_count) VALUES (?1, ?2, ?3, ?4)",
+            params![tmdb_id, variant, text, trigrams.len() as i64],
+        )?;
+
+        let mut insert = conn.prepare_cached(
+            "INSERT INTO Movie_Title_Trigrams (trigram, tmdb_id, variant) VALUES (?1, ?2, ?3)",
+        )?;
+        for trigram in trigrams {
+            insert.execute(params![trigram, tmdb_id, variant])?;
+        }
+    }
+    Ok(())
+}
+
+/// Découpe les listes brutes (séparées par des virgules) de chaque attribut dans sa table
+/// de référence et sa table d'association, comme `Lookup::insert` côté Oracle
+fn split_movie_facets(conn: &Connection) -> rusqlite::Result<()> {
+    for facet in MovieFacet::ALL {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, {} FROM TMDB_movie_dataset WHERE {} IS NOT NULL",
+            facet.source_column(),
+            facet.source_column()
+        ))?;
+        let movies = stmt
+            .query_map([], |row| Ok((row.get::<_, i32>(0)?, row.get::<_, String>(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Identifiants dans l'ordre alphabétique des noms
+        let mut ids = BTreeMap::new();
+        for (_, list) in &movies {
+            for name in list.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+                ids.insert(name, 0);
+            }
+        }
+
+        let mut insert = conn.prepare(&format!(
+            "INSERT INTO {} ({}, name) VALUES (?1, ?2)",
+            facet.table(),
+            facet.id_column()
+        ))?;
+        for (position, (name, id)) in ids.iter_mut().enumerate() {
+            *id = position as i64 + 1;
+            insert.execute(params![*id, name])?;
+        }
+
+        // position : rang de la valeur dans la liste d'origine, doublons ignorés
+        let mut insert = conn.prepare(&format!(
+            "INSERT OR IGNORE INTO {} (tmdb_id, {}, position) VALUES (?1, ?2, ?3)",
+            facet.junction(),
+            facet.id_column()
+        ))?;
+        for (tmdb_id, list) in &movies {
+            let mut position = 0;
+            for name in list.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+                if insert.execute(params![tmdb_id, ids[name], position + 1])? > 0 {
+                    position += 1;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Recalcule les voisins de chaque film à partir des notes, comme `MovieSimilarity::compute` côté Oracle
+fn compute_movie_similarities(conn: &Connection) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare(
+        "SELECT user_id, movie_id, rating
+        FROM MovieLens_Ratings
+        WHERE movie_id IN (SELECT movie_id FROM MovieLens_Links)
+        ORDER BY user_id, movie_id",
+    )?;
+    let ratings = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<Result<Vec<(i32, i32, f64)>, _>>()?;
+
+    conn.execute("DELETE FROM Movie_Similarities", [])?;
+
+    let mut insert = conn.prepare(
+        "INSERT INTO Movie_Similarities (movie_id, similar_movie_id, rank, similarity, co_ratings)
+        VALUES (?1, ?2, ?3, ?4, ?5)",
+    )?;
+    for n in movie_neighbours(&ratings) {
+        insert.execute(params![n.movie_id, n.similar_movie_id, n.rank, n.similarity, n.co_ratings])?;
+    }
+    Ok(())
+}
+
+/// Construit les caractéristiques de contenu des films MovieLens, comme `ContentFeatures::compute` côté Oracle
+fn compute_content_features(conn: &Connection) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare(
+        "SELECT id, genres, keywords, overview
+        FROM TMDB_movie_dataset
+        WHERE id IN (SELECT tmdb_id FROM MovieLens_Links)
+        ORDER BY id",
+    )?;
+    let movies = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)))?
+        .collect::<Result<Vec<(i32, Option<String>, Option<String>, Option<String>)>, _>>()?;
+
+    let index = ContentIndex::build(&movies);
+
+    conn.execute("DELETE FROM Movie_Content_Norms", [])?;
+    conn.execute("DELETE FROM Movie_Content_Features", [])?;
+    conn.execute("DELETE FROM Content_Features", [])?;
+
+    let mut insert = conn.prepare(
+        "INSERT INTO Content_Features (feature_id, kind, name, movie_count, weight) VALUES (?1, ?2, ?3, ?4, ?5)",
+    )?;
+    for (index, feature) in index.features.iter().enumerate() {
+        insert.execute(params![index as i64 + 1, feature.kind, feature.name, feature.movie_count, feature.weight])?;
+    }
+
+    let mut insert = conn.prepare("INSERT INTO Movie_Content_Features (tmdb_id, feature_id) VALUES (?1, ?2)")?;
+    for (tmdb_id, feature_id) in &index.links {
+        insert.execute(params![tmdb_id, feature_id])?;
+    }
+
+    let mut insert = conn.prepare("INSERT INTO Movie_Content_Norms (tmdb_id, total_weight) VALUES (?1, ?2)")?;
+    for (tmdb_id, total_weight) in &index.norms {
+        insert.execute(params![tmdb_id, total_weight])?;
+    }
+    Ok(())
+}
+
+/// Entraîne le modèle de recommandation sur toutes les notes, comme `RatingModel::train` côté Oracle
+fn train_rating_model(conn: &Connection) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare("SELECT user_id, movie_id, rating FROM MovieLens_Ratings ORDER BY user_id, movie_id")?;
+    let ratings = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<Result<Vec<(i32, i32, f64)>, _>>()?;
+
+    let model = FactorModel::train(&ratings);
+
+    conn.execute("DELETE FROM User_Factors", [])?;
+    conn.execute("DELETE FROM Movie_Factors", [])?;
+
+    let mut insert = conn.prepare("INSERT INTO User_Factors (user_id, bias, factors) VALUES (?1, ?2, ?3)")?;
+    for (user_id, factors) in model.user_ids.iter().zip(&model.user_factors) {
+        insert.execute(params![user_id, factors.bias, factors.format()])?;
+    }
+
+    let mut insert = conn.prepare("INSERT INTO Movie_Factors (movie_id, bias, factors) VALUES (?1, ?2, ?3)")?;
+    for (movie_id, factors) in model.movie_ids.iter().zip(&model.movie_factors) {
+        insert.execute(params![movie_id, factors.bias, factors.format()])?;
+    }
+
+    conn.execute("UPDATE Rating_Models SET active = 0", [])?;
+    conn.execute(
+        "INSERT INTO Rating_Models (
+            model_version, run_mode, factor_count, epochs, learning_rate, regularization,
+            global_mean, train_ratings, holdout_ratings, rmse, active
+        ) VALUES (?1, 'TRAIN', ?2, ?3, ?4, ?5, ?6, ?7, 0, NULL, 1)",
+        params![
+            MODEL_VERSION,
+            FACTOR_COUNT as i64,
+            EPOCHS as i64,
+            LEARNING_RATE,
+            REGULARIZATION,
+            model.global_mean,
+            ratings.len() as i64
+        ],
+    )?;
+    Ok(())
+}
+
+impl MovieStore for SqliteStore {
+    fn current_sql_username(&self) -> Result<String, StoreError> {
+        Ok(self.user.username.clone())
+    }
+
+    fn current_user(&self) -> Result<SqlUser, StoreError> {
+        Ok(SqlUser {
+            username: self.user.username.clone(),
+            is_admin: self.user.is_admin,
+            created_at: "".to_string(),
+        })
+    }
+
+    fn fetch_all_movies(&self, search: MovieSearch) -> Result<Page<MovieShort>, StoreError> {
+        let conn = self.conn()?;
+
+        let count = search.count_query(Dialect::Sqlite);
+        let total: u32 = conn.query_row(&count.sql, count.sqlite_params().as_slice(), |row| row.get(0))?;
+
+        let query = search.page_query(Dialect::Sqlite);
+        let sort_keys = search.sort_keys();
+        let mut stmt = conn.prepare(&query.sql)?;
+        let movies = stmt
+            .query_map(query.sqlite_params().as_slice(), |row| {
+                let mut sort_values = Vec::new();
+                for (index, key) in sort_keys.iter().enumerate() {
+                    sort_values.push(match key.numeric {
+                        true => SortValue::Number(row.get(6 + index)?),
+                        false => SortValue::Text(row.get(6 + index)?),
+                    });
+                }
+                Ok((MovieShort::from_sqlite_row(row)?, sort_values))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(search.into_page(movies, total, |movie| movie.movie_id))
+    }
+
+    fn search_movies_ranked(&self, search: MovieSearch) -> Result<Page<MovieMatch>, StoreError> {
+        let conn = self.conn()?;
+
+        let count = search.count_query(Dialect::Sqlite);
+        let total: u32 = conn.query_row(&count.sql, count.sqlite_params().as_slice(), |row| row.get(0))?;
+
+        let query = search.page_query(Dialect::Sqlite);
+        let with_snippet = search.mode == SearchMode::FullText;
+        let mut stmt = conn.prepare(&query.sql)?;
+        let matches = stmt
+            .query_map(query.sqlite_params().as_slice(), |row| {
+                let score: f64 = row.get(6)?;
+                let movie = MovieMatch {
+                    movie: MovieShort::from_sqlite_row(row)?,
+                    score,
+                    snippet: if with_snippet { row.get(7)? } else { None },
+                };
+                Ok((movie, vec![SortValue::Number(score)]))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(search.into_page(matches, total, |m| m.movie.movie_id))
+    }
+
+    fn autocomplete_titles(&self, prefix: &str, limit: u32) -> Result<Vec<TitleCompletion>, StoreError> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(AUTOCOMPLETE_TITLES)?;
+
+        // LOWER de SQLite ne convertit que l'ASCII
+        let titles = stmt
+            .query_map(params![prefix.to_ascii_lowercase(), limit], TitleCompletion::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(titles)
+    }
+
+    fn get_movie(&self, movie_id: i32, include_ratings: bool) -> Result<Option<Movie>, StoreError> {
+        let details = match self.get_movie_details(movie_id)? {
+            Some(details) => details,
+            None => return Ok(None),
+        };
+
+        let rating_summary = self.get_movie_rating_summary(movie_id)?;
+        let ratings = match include_ratings {
+            true => Some(self.get_movie_ratings(movie_id)?),
+            false => None,
+        };
+        let tags = self.get_movie_tags(movie_id)?;
+
+        Ok(Some(Movie {
+            details,
+            rating_summary,
+            ratings,
+            tags,
+        }))
+    }
+
+    fn get_similar_movies_by_ratings(&self, movie_id: i32, limit: u32) -> Result<Vec<SimilarMovie>, StoreError> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(SELECT_SIMILAR_MOVIES)?;
+        let movies = stmt
+            .query_map(params![movie_id, limit], SimilarMovie::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(movies)
+    }
+
+    fn get_similar_movies_by_content(&self, movie_id: i32, limit: u32) -> Result<Vec<ContentMatch>, StoreError> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(SELECT_SIMILAR_MOVIES_BY_CONTENT)?;
+        let mut matches = stmt
+            .query_map(params![movie_id, limit], ContentMatch::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        if matches.is_empty() {
+            return Ok(matches);
+        }
+
+        let mut params: Vec<i32> = vec![movie_id];
+        params.extend(matches.iter().map(|m| m.movie.tmdb_id));
+
+        let mut stmt = conn.prepare(&shared_features_query(Dialect::Sqlite, matches.len()))?;
+        let features = stmt
+            .query_map(rusqlite::params_from_iter(&params), SharedFeature::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        attach_shared_features(&mut matches, features);
+
+        Ok(matches)
+    }
+
+    fn get_recommendations_for_user(&self, user_id: i32, n: u32) -> Result<Vec<Recommendation>, StoreError> {
+        let conn = self.conn()?;
+
+        let global_mean: f64 = match conn.query_row(SELECT_RATING_MODEL, [], |row| row.get(0)).optional()? {
+            Some(global_mean) => global_mean,
+            None => return Err(StoreError::Message(NO_MODEL_MESSAGE.to_string())),
+        };
+
+        // Utilisateur absent de l'entraînement : seuls les biais des films sont utilisés
+        let user = conn
+            .query_row(SELECT_USER_FACTORS, params![user_id], |row| {
+                Ok(Factors::parse(row.get(0)?, &row.get::<_, String>(1)?))
+            })
+            .optional()?;
+
+        let mut stmt = conn.prepare(SELECT_UNSEEN_MOVIE_FACTORS)?;
+        let movies = stmt
+            .query_map(params![user_id], |row| {
+                Ok((row.get::<_, i32>(0)?, Factors::parse(row.get(1)?, &row.get::<_, String>(2)?)))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let predictions = top_predictions(global_mean, user.as_ref(), &movies, n as usize);
+        if predictions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let ids: Vec<i32> = predictions.iter().map(|&(movie_id, _)| movie_id).collect();
+        let mut stmt = conn.prepare(&select_movies_query(Dialect::Sqlite, ids.len()))?;
+        let shorts = stmt
+            .query_map(rusqlite::params_from_iter(&ids), MovieShort::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(into_recommendations(predictions, shorts))
+    }
+
+    fn get_genres(&self) -> Result<Vec<Genre>, StoreError> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(SELECT_GENRES)?;
+        let genres = stmt
+            .query_map([], Genre::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(genres)
+    }
+
+    fn get_facet_values(&self, search: FacetSearch) -> Result<Page<FacetValue>, StoreError> {
+        let conn = self.conn()?;
+
+        let count = search.count_query(Dialect::Sqlite);
+        let total: u32 = conn.query_row(&count.sql, count.sqlite_params().as_slice(), |row| row.get(0))?;
+
+        let query = search.page_query(Dialect::Sqlite);
+        let mut stmt = conn.prepare(&query.sql)?;
+        let values = stmt
+            .query_map(query.sqlite_params().as_slice(), FacetValue::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(search.into_page(values, total))
+    }
+
+    fn get_stats(&self) -> Result<Stats, StoreError> {
+        let conn = self.conn()?;
+        let count = |sql: &str| conn.query_row(sql, [], |row| row.get::<_, u32>(0));
+
+        let mut stmt = conn.prepare(GENRE_COUNT)?;
+        let genre_count = stmt
+            .query_map([], |row| {
+                Ok(MovieGenreCountStats {
+                    genre_name: row.get(0)?,
+                    genre_count: row.get(1)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut stmt = conn.prepare(TOP_USERS)?;
+        let top_users = stmt
+            .query_map([], TopUserItem::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut stmt = conn.prepare(TOP_PROFITS_MOVIES)?;
+        let top_profits_movies = stmt
+            .query_map([], TopMovieProfit::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Stats {
+            count: CountStats {
+                total_movies: count(COUNT_MOVIES)?,
+                total_ratings: count(COUNT_RATINGS)?,
+                total_tags: count(COUNT_TAGS)?,
+                total_users: count(COUNT_DISTINCT_USERS)?,
+                genre_count,
+            },
+            top_users,
+            top_profits_movies,
+        })
+    }
+
+    fn get_movie_ratings_page(&self, search: MovieActivitySearch) -> Result<Page<MovieRating>, StoreError> {
+        let conn = self.conn()?;
+        let (count, query) = (search.count_query(Dialect::Sqlite), search.page_query(Dialect::Sqlite));
+        let (rows, total) = get_page_rows(&conn, count, query, MovieRating::from_sqlite_row)?;
+        Ok(search.into_rating_page(rows, total))
+    }
+
+    fn get_movie_tags_page(&self, search: MovieActivitySearch) -> Result<Page<MovieTag>, StoreError> {
+        let conn = self.conn()?;
+        let (count, query) = (search.count_query(Dialect::Sqlite), search.page_query(Dialect::Sqlite));
+        let (rows, total) = get_page_rows(&conn, count, query, MovieTag::from_sqlite_row)?;
+        Ok(search.into_tag_page(rows, total))
+    }
+
+    fn get_movie_lens_user(
+        &self,
+        user_id: i32,
+        ratings: UserActivitySearch,
+        tags: UserActivitySearch,
+    ) -> Result<Option<MovieLensUserProfile>, StoreError> {
+        let conn = self.conn()?;
+        let summary = match conn
+            .query_row(SELECT_MOVIE_LENS_USER_SUMMARY, params![user_id], UserSummary::from_sqlite_row)
+            .optional()?
+        {
+            Some(summary) => summary,
+            None => return Ok(None),
+        };
+
+        let mut stmt = conn.prepare(SELECT_USER_GENRE_RATINGS)?;
+        let genres = stmt
+            .query_map(params![user_id], GenreAffinity::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut stmt = conn.prepare(SELECT_USER_TOP_TAGS)?;
+        let top_tags = stmt
+            .query_map(params![user_id, TOP_TAG_COUNT], TagUsage::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (count, query) = (ratings.count_query(Dialect::Sqlite), ratings.page_query(Dialect::Sqlite));
+        let (rows, total) = get_page_rows(&conn, count, query, UserRating::from_sqlite_row)?;
+        let ratings = ratings.into_rating_page(rows, total);
+        let (count, query) = (tags.count_query(Dialect::Sqlite), tags.page_query(Dialect::Sqlite));
+        let (rows, total) = get_page_rows(&conn, count, query, UserTag::from_sqlite_row)?;
+        let tags = tags.into_tag_page(rows, total);
+
+        Ok(Some(summary.into_profile(genres, top_tags, ratings, tags)))
+    }
+
+    fn add_movie(&self, movie: NewMovie) -> Result<MovieDetails, StoreError> {
+        self.require_admin()?;
+        let mut conn = self.conn()?;
+        // Transaction immédiate : deux ajouts simultanés ne peuvent pas recevoir le même movie_id
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+
+        // Mêmes erreurs que la procédure AddMovie
+        let (tmdb_count, imdb_count): (u32, u32) =
+            tx.query_row(COUNT_MOVIE_DUPLICATES, params![movie.tmdb_id, movie.imdb_id], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        if tmdb_count > 0 {
+            return Err(StoreError::Message(format!("Le film TMDB {} existe déjà", movie.tmdb_id)));
+        }
+        if imdb_count > 0 {
+            return Err(StoreError::Message(format!("Le film IMDb tt{} existe déjà", movie.imdb_id)));
+        }
+
+        let movie_id: i32 = tx.query_row(SELECT_NEXT_MOVIE_ID, [], |row| row.get(0))?;
+        tx.execute(
+            INSERT_MOVIE,
+            params![
+                movie.tmdb_id,
+                movie.title,
+                movie.release_date,
+                movie.runtime,
+                movie.adult as i32,
+                movie.imdb_id,
+                movie.original_language,
+                movie.overview,
+                movie.poster_path,
+                (!movie.genres.is_empty()).then(|| movie.genres.join(", "))
+            ],
+        )?;
+        tx.execute(INSERT_MOVIE_LINK, params![movie_id, movie.imdb_id, movie.tmdb_id])?;
+        tx.execute(REFRESH_MOVIE_WEIGHTED_RATING, params![movie_id])?;
+
+        for (position, genre) in movie.genres.iter().enumerate() {
+            tx.execute(INSERT_GENRE, params![genre])?;
+            tx.execute(INSERT_MOVIE_GENRE, params![movie.tmdb_id, genre, position as i64 + 1])?;
+        }
+
+        // Les triggers n'indexent que le texte intégral ; les trigrammes sont calculés ici
+        index_movie_title(&tx, movie.tmdb_id, Some(&movie.title), Some(&movie.title))?;
+
+        self.log_action(
+            &tx,
+            AdminAction::AddMovie,
+            Some(format!("movie:{}", movie_id)),
+            Some(format!("tmdb_id={}, imdb_id=tt{}, title={}", movie.tmdb_id, movie.imdb_id, movie.title)),
+        )?;
+        tx.commit()?;
+        drop(conn);
+
+        self.get_movie_details(movie_id)?
+            .ok_or_else(|| StoreError::Message(format!("Film introuvable : {}", movie_id)))
+    }
+
+    fn update_movie(&self, movie_id: i32, update: MovieUpdate) -> Result<MovieDetails, StoreError> {
+        self.require_admin()?;
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+
+        let updated = tx.execute(
+            UPDATE_MOVIE,
+            params![
+                movie_id,
+                update.version,
+                update.title,
+                update.overview,
+                update.release_date,
+                update.runtime,
+                update.poster_path
+            ],
+        )?;
+
+        // Mêmes erreurs que la procédure UpdateMovie
+        if updated == 0 {
+            let version: Option<i64> = tx.query_row(SELECT_MOVIE_VERSION, params![movie_id], |row| row.get(0)).optional()?;
+            return Err(StoreError::Message(match version {
+                None => format!("Film introuvable : {}", movie_id),
+                Some(version) => format!(
+                    "Le film {} a été modifié par un autre administrateur (version {}, attendue {}). Rechargez-le avant de le modifier.",
+                    movie_id, version, update.version
+                ),
+            }));
+        }
+
+        // L'index des trigrammes n'est pas maintenu par un trigger sur SQLite
+        if update.title.is_some() {
+            let (tmdb_id, title, original_title): (i32, Option<String>, Option<String>) =
+                tx.query_row(SELECT_MOVIE_TITLES, params![movie_id], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+            index_movie_title(&tx, tmdb_id, title.as_deref(), original_title.as_deref())?;
+        }
+
+        // Mêmes paramètres que la procédure UpdateMovie : la version modifiée et les champs renseignés
+        let fields: Vec<&str> = [
+            ("title", update.title.is_some()),
+            ("overview", update.overview.is_some()),
+            ("release_date", update.release_date.is_some()),
+            ("runtime", update.runtime.is_some()),
+            ("poster_path", update.poster_path.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(field, set)| set.then_some(field))
+        .collect();
+        self.log_action(
+            &tx,
+            AdminAction::UpdateMovie,
+            Some(format!("movie:{}", movie_id)),
+            Some(format!("version={}, fields={}", update.version, fields.join(","))),
+        )?;
+        tx.commit()?;
+        drop(conn);
+
+        self.get_movie_details(movie_id)?
+            .ok_or_else(|| StoreError::Message(format!("Film introuvable : {}", movie_id)))
+    }
+
+    fn add_movie_lens_rating(&self, movie_id: i32, user_id: i32, rating: f32) -> Result<MovieRating, StoreError> {
+        self.require_admin()?;
+        let mut conn = self.conn()?;
+        // Transaction immédiate : deux notes simultanées du même utilisateur ne peuvent pas passer toutes les deux
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        require_movie_link(&tx, movie_id)?;
+
+        // Mêmes erreurs que la procédure AddMovieLensRating
+        let count: u32 = tx.query_row(COUNT_USER_MOVIE_RATINGS, params![movie_id, user_id], |row| row.get(0))?;
+        if count > 0 {
+            return Err(StoreError::Message(format!("L'utilisateur {} a déjà noté le film {}", user_id, movie_id)));
+        }
+
+        let timestamp = unix_now();
+        tx.execute(INSERT_MOVIE_LENS_USER, params![user_id])?;
+        tx.execute(INSERT_MOVIE_LENS_RATING, params![movie_id, user_id, rating, timestamp])?;
+        tx.execute(REFRESH_MOVIE_WEIGHTED_RATING, params![movie_id])?;
+        self.log_action(
+            &tx,
+            AdminAction::AddMovieLensRating,
+            Some(format!("movie:{}", movie_id)),
+            Some(format!("user_id={}, rating={:.1}", user_id, rating)),
+        )?;
+        tx.commit()?;
+
+        Ok(MovieRating { user_id, rating, timestamp })
+    }
+
+    fn update_movie_lens_rating(&self, movie_id: i32, user_id: i32, rating: f32) -> Result<MovieRating, StoreError> {
+        self.require_admin()?;
+        let timestamp = unix_now();
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+        let updated = tx.execute(UPDATE_MOVIE_LENS_RATING, params![movie_id, user_id, rating, timestamp])?;
+        if updated == 0 {
+            return Err(StoreError::Message(format!("Aucune note de l'utilisateur {} pour le film {}", user_id, movie_id)));
+        }
+        tx.execute(REFRESH_MOVIE_WEIGHTED_RATING, params![movie_id])?;
+        self.log_action(
+            &tx,
+            AdminAction::UpdateMovieLensRating,
+            Some(format!("movie:{}", movie_id)),
+            Some(format!("user_id={}, rating={:.1}", user_id, rating)),
+        )?;
+        tx.commit()?;
+
+        Ok(MovieRating { user_id, rating, timestamp })
+    }
+
+    fn add_movie_lens_tag(&self, movie_id: i32, user_id: i32, tag: &str) -> Result<MovieTag, StoreError> {
+        self.require_admin()?;
+        let mut conn = self.conn()?;
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        require_movie_link(&tx, movie_id)?;
+
+        let timestamp: i64 = tx.query_row(SELECT_NEXT_TAG_TIMESTAMP, params![movie_id, user_id, unix_now()], |row| row.get(0))?;
+        tx.execute(INSERT_MOVIE_LENS_USER, params![user_id])?;
+        tx.execute(INSERT_MOVIE_LENS_TAG, params![movie_id, user_id, tag, timestamp])?;
+        self.log_action(
+            &tx,
+            AdminAction::AddMovieLensTag,
+            Some(format!("movie:{}", movie_id)),
+            Some(format!("user_id={}, timestamp={}, tag={}", user_id, timestamp, tag)),
+        )?;
+        tx.commit()?;
+
+        Ok(MovieTag {
+            user_id,
+            tag: tag.to_string(),
+            timestamp,
+        })
+    }
+
+    fn rename_movie_lens_tag(&self, movie_id: i32, user_id: i32, timestamp: i64, tag: &str) -> Result<MovieTag, StoreError> {
+        self.require_admin()?;
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+        let updated = tx.execute(RENAME_MOVIE_LENS_TAG, params![movie_id, user_id, timestamp, tag])?;
+        if updated == 0 {
+            return Err(StoreError::Message(format!(
+                "Tag introuvable pour user_id={}, movie_id={}, timestamp={}",
+                user_id, movie_id, timestamp
+            )));
+        }
+        self.log_action(
+            &tx,
+            AdminAction::RenameMovieLensTag,
+            Some(format!("movie:{}", movie_id)),
+            Some(format!("user_id={}, timestamp={}, tag={}", user_id, timestamp, tag)),
+        )?;
+        tx.commit()?;
+
+        Ok(MovieTag {
+            user_id,
+            tag: tag.to_string(),
+            timestamp,
+        })
+    }
+
+    fn preview_tag_moderation(&self, rule: &TagRule, sample_size: u32) -> Result<TagModerationPreview, StoreError> {
+        self.require_admin()?;
+        let conn = self.conn()?;
+        let changes = rule.plan(select_moderation_tags(&conn, rule)?).map_err(StoreError::Message)?;
+        Ok(preview(changes, sample_size))
+    }
+
+    fn apply_tag_moderation(&self, rule: &TagRule, expected_matches: Option<u32>) -> Result<TagModerationReport, StoreError> {
+        self.require_admin()?;
+        let mut conn = self.conn()?;
+        let run = ModerationRun::start();
+        // Transaction immédiate : aucun tag ne peut être ajouté entre la lecture et les modifications
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let changes = rule.plan(select_moderation_tags(&tx, rule)?).map_err(StoreError::Message)?;
+        check_expected_matches(&changes, expected_matches).map_err(StoreError::Message)?;
+
+        {
+            let mut insert_deletion = tx.prepare(INSERT_TAG_DELETION)?;
+            let mut archive = tx.prepare(ARCHIVE_MODERATED_TAG)?;
+            let mut delete = tx.prepare(DELETE_MODERATED_TAG)?;
+            let mut rewrite = tx.prepare(REWRITE_MODERATED_TAG)?;
+            let deleted_at = unix_now();
+            for change in &changes {
+                match &change.new_tag {
+                    // Chaque tag supprimé a sa propre entrée dans la corbeille, comme avec delete_movie_lens_tag
+                    None => {
+                        insert_deletion.execute(params![change.movie_id, change.user_id, self.user.username, deleted_at])?;
+                        let deletion_id = tx.last_insert_rowid();
+                        archive.execute(params![change.movie_id, change.user_id, change.timestamp, change.tag, deletion_id])?;
+                        delete.execute(params![change.movie_id, change.user_id, change.timestamp, change.tag])?
+                    }
+                    Some(new_tag) => rewrite.execute(params![change.movie_id, change.user_id, change.timestamp, change.tag, new_tag])?,
+                };
+            }
+        }
+        self.log_action(&tx, AdminAction::ApplyTagModeration, None, Some(rule.audit_parameters(&changes)))?;
+        tx.commit()?;
+
+        Ok(run.finish(&changes))
+    }
+
+    fn delete_movie_lens_user(&self, user_id: i32) -> Result<(), StoreError> {
+        self.require_admin()?;
+        let mut conn = self.conn()?;
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+
+        // Le trigger archive l'utilisateur, ses notes et ses tags ; l'auteur est renseigné ensuite
+        let last_deletion: i64 = tx.query_row(SELECT_LAST_DELETION_ID, [], |row| row.get(0))?;
+        let deleted = tx.execute(DELETE_MOVIE_LENS_USER, params![user_id])?;
+        tx.execute(SET_DELETIONS_AUTHOR, params![last_deletion, self.user.username])?;
+        if deleted > 0 {
+            self.log_action(&tx, AdminAction::DeleteMovieLensUser, Some(format!("movielens_user:{}", user_id)), None)?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn delete_movie_lens_tag(&self, movie_id: i32, user_id: i32, timestamp: i64) -> Result<(), StoreError> {
+        self.require_admin()?;
+        let mut conn = self.conn()?;
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+
+        // Archive le tag dans la corbeille, comme la procédure DeleteMovieLensTag
+        let count: u32 = tx.query_row(COUNT_MOVIE_LENS_TAG, params![movie_id, user_id, timestamp], |row| row.get(0))?;
+        if count > 0 {
+            tx.execute(INSERT_TAG_DELETION, params![movie_id, user_id, self.user.username, unix_now()])?;
+            let deletion_id = tx.last_insert_rowid();
+            tx.execute(ARCHIVE_MOVIE_LENS_TAG, params![movie_id, user_id, timestamp, deletion_id])?;
+            tx.execute(DELETE_MOVIE_LENS_TAG, params![movie_id, user_id, timestamp])?;
+            self.log_action(
+                &tx,
+                AdminAction::DeleteMovieLensTag,
+                Some(format!("movie:{}", movie_id)),
+                Some(format!("user_id={}, timestamp={}", user_id, timestamp)),
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn get_trash(&self, search: TrashSearch) -> Result<Page<TrashItem>, StoreError> {
+        self.require_admin()?;
+        let conn = self.conn()?;
+        let (count, query) = (search.count_query(Dialect::Sqlite), search.page_query(Dialect::Sqlite));
+        let (rows, total) = get_page_rows(&conn, count, query, TrashItem::from_sqlite_row)?;
+        Ok(search.into_page(rows, total))
+    }
+
+    fn restore_trash_item(&self, deletion_id: i32) -> Result<TrashRestore, StoreError> {
+        self.require_admin()?;
+        let mut conn = self.conn()?;
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+
+        // Mêmes étapes et mêmes erreurs que la procédure RestoreMovieLensDeletion
+        let (kind, user_id, movie_id, imdb_id, tmdb_id): (String, Option<i32>, Option<i32>, Option<String>, Option<i32>) = tx
+            .query_row(SELECT_DELETION, params![deletion_id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?))
+            })
+            .optional()?
+            .ok_or_else(|| StoreError::Message(format!("Élément introuvable dans la corbeille : {}", deletion_id)))?;
+
+        let blocking: Option<i32> = tx.query_row(SELECT_BLOCKING_DELETION, params![deletion_id], |row| row.get(0))?;
+        if let Some(blocking) = blocking {
+            return Err(StoreError::Message(format!("Restaurez d'abord l'élément {} de la corbeille", blocking)));
+        }
+
+        match TrashKind::parse(&kind) {
+            TrashKind::User => {
+                tx.execute(INSERT_MOVIE_LENS_USER, params![user_id])?;
+            }
+            TrashKind::Movie => {
+                let count: u32 = tx.query_row(COUNT_RELINKED_MOVIE, params![movie_id, tmdb_id], |row| row.get(0))?;
+                if count > 0 {
+                    return Err(StoreError::Message(format!(
+                        "Le film {} (TMDB {}) est de nouveau relié",
+                        movie_id.unwrap_or_default(),
+                        tmdb_id.unwrap_or_default()
+                    )));
+                }
+                tx.execute(INSERT_MOVIE_LINK, params![movie_id, imdb_id, tmdb_id])?;
+            }
+            TrashKind::Tag => {}
+        }
+
+        let restored_ratings = tx.execute(RESTORE_RATINGS, params![deletion_id])? as u32;
+        let restored_tags = tx.execute(RESTORE_TAGS, params![deletion_id])? as u32;
+
+        tx.execute(DELETE_DELETED_RATINGS, params![deletion_id])?;
+        tx.execute(DELETE_DELETED_TAGS, params![deletion_id])?;
+        tx.execute(DELETE_DELETION, params![deletion_id])?;
+        self.log_action(
+            &tx,
+            AdminAction::RestoreTrashItem,
+            Some(format!("trash:{}", deletion_id)),
+            Some(format!("kind={}, restored_ratings={}, restored_tags={}", kind, restored_ratings, restored_tags)),
+        )?;
+        tx.commit()?;
+
+        Ok(TrashRestore {
+            deletion_id,
+            restored_ratings,
+            restored_tags,
+        })
+    }
+
+    fn create_sql_user(&self, username: &str, password: &str, is_admin: bool) -> Result<(), StoreError> {
+        self.require_admin()?;
+        let is_admin = if is_admin { 1 } else { 0 };
+        let password_hash = hash_password(password).map_err(|e| StoreError::Message(e.to_string()))?;
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+        tx.execute(CREATE_SQL_USER, params![username, password_hash, is_admin])?;
+        // Jamais le mot de passe, comme la procédure CreateUser
+        self.log_action(
+            &tx,
+            AdminAction::CreateSqlUser,
+            Some(format!("sql_user:{}", username.to_uppercase())),
+            Some(format!("is_admin={}", is_admin)),
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn delete_sql_user(&self, username: &str) -> Result<(), StoreError> {
+        self.require_admin()?;
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+        if tx.execute(DELETE_SQL_USER, params![username])? > 0 {
+            self.log_action(&tx, AdminAction::DeleteSqlUser, Some(format!("sql_user:{}", username.to_uppercase())), None)?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn get_audit_log(&self, search: AuditLogSearch) -> Result<Page<AuditEntry>, StoreError> {
+        self.require_admin()?;
+        let conn = self.conn()?;
+        let (count, query) = (search.count_query(Dialect::Sqlite), search.page_query(Dialect::Sqlite));
+        let (rows, total) = get_page_rows(&conn, count, query, AuditEntry::from_sqlite_row)?;
+        Ok(search.into_page(rows, total))
+    }
+
+    fn get_sql_users(&self) -> Result<Vec<SqlUser>, StoreError> {
+        self.require_admin()?;
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(GET_SQL_USERS)?;
+        let users = stmt
+            .query_map([], SqlUser::from_sqlite_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(users)
+    }
+
+    fn close(&self) -> Result<(), StoreError> {
+        // Les connexions SQLite sont fermées à la libération du pool
+        Ok(())
+    }
+}
+
+/// Tags trouvés par la règle ; seuls les tags candidats sont lus et seuls les tags trouvés sont gardés
+fn select_moderation_tags(conn: &Connection, rule: &TagRule) -> rusqlite::Result<Vec<TagRow>> {
+    let mut stmt = conn.prepare(&select_tags_query(Dialect::Sqlite, rule.fragments().len()))?;
+    let mut rows = stmt.query(rusqlite::params_from_iter(rule.fragments()))?;
+
+    let mut tags = Vec::new();
+    while let Some(row) = rows.next()? {
+        let row = TagRow::from_sqlite_row(row)?;
+        if rule.matches(&row.tag) {
+            tags.push(row);
+        }
+    }
+    Ok(tags)
+}
+
+/// Erreur de la procédure Oracle si le film n'a pas de lien MovieLens
+fn require_movie_link(conn: &Connection, movie_id: i32) -> Result<(), StoreError> {
+    let count: u32 = conn.query_row(COUNT_MOVIE_LINK, params![movie_id], |row| row.get(0))?;
+    if count == 0 {
+        return Err(StoreError::Message(format!("Film introuvable : {}", movie_id)));
+    }
+    Ok(())
+}
+
+/// Timestamp Unix courant, comme ceux des notes et tags MovieLens
+fn unix_now() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs() as i64).unwrap_or(0)
+}
+
+/// Lignes d'une page et nombre total de lignes
+fn get_page_rows<T>(
+    conn: &Connection,
+    count: SqlQuery,
+    query: SqlQuery,
+    from_row: fn(&rusqlite::Row) -> rusqlite::Result<T>,
+) -> rusqlite::Result<(Vec<T>, u32)> {
+    let total: u32 = conn.query_row(&count.sql, count.sqlite_params().as_slice(), |row| row.get(0))?;
+
+    let mut stmt = conn.prepare(&query.sql)?;
+    let items = stmt
+        .query_map(query.sqlite_params().as_slice(), from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((items, total))
+}
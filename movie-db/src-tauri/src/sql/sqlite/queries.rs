@@ -0,0 +1,654 @@
+// Récupérer les détails d'un film par son ID MovieLens
+pub const SELECT_MOVIE_BY_ID: &str = "SELECT
+    movie_id,
+    tmdb_id,
+    title,
+    vote_average,
+    vote_count,
+    status,
+    release_date,
+    runtime,
+    adult,
+    backdrop_path,
+    overview,
+    poster_path,
+    keywords,
+    row_version
+FROM
+    MovieDetailsView
+WHERE
+    movie_id = ?1";
+
+// Catalogue des genres et nombre de films MovieLens de chacun
+pub const SELECT_GENRES: &str = "SELECT
+    g.genre_id,
+    g.name,
+    COUNT(l.movie_id) AS movie_count
+FROM
+    Genres g
+LEFT JOIN
+    Movie_Genres mg ON mg.genre_id = g.genre_id
+LEFT JOIN
+    MovieLens_Links l ON l.tmdb_id = mg.tmdb_id
+GROUP BY
+    g.genre_id, g.name
+ORDER BY
+    g.name";
+
+// Genres d'un film par son ID MovieLens, dans l'ordre d'origine
+pub const SELECT_MOVIE_GENRES: &str = "SELECT
+    g.name
+FROM
+    Movie_Genres mg
+JOIN
+    Genres g ON g.genre_id = mg.genre_id
+JOIN
+    MovieLens_Links l ON l.tmdb_id = mg.tmdb_id
+WHERE
+    l.movie_id = ?1
+ORDER BY
+    mg.position";
+
+pub const SELECT_MOVIE_RATINGS: &str = "SELECT
+    user_id,
+    rating,
+    timestamp
+FROM
+    MovieLens_Ratings
+WHERE
+    movie_id = ?1";
+
+// Statistiques des notes MovieLens d'un film : la médiane est la moyenne des une ou deux
+// valeurs centrales, la 4e colonne est la variance (SQLite n'a pas de fonction racine carrée)
+pub const SELECT_MOVIE_RATING_SUMMARY: &str = "SELECT
+    COUNT(*),
+    AVG(rating),
+    (
+        SELECT AVG(rating)
+        FROM (
+            SELECT
+                rating,
+                ROW_NUMBER() OVER (ORDER BY rating) AS position,
+                COUNT(*) OVER () AS total
+            FROM MovieLens_Ratings
+            WHERE movie_id = ?1
+        )
+        WHERE position IN ((total + 1) / 2, (total + 2) / 2)
+    ),
+    AVG(rating * rating) - AVG(rating) * AVG(rating)
+FROM
+    MovieLens_Ratings
+WHERE
+    movie_id = ?1";
+
+// Nombre de notes d'un film par valeur de note
+pub const SELECT_MOVIE_RATING_HISTOGRAM: &str = "SELECT
+    rating,
+    COUNT(*)
+FROM
+    MovieLens_Ratings
+WHERE
+    movie_id = ?1
+GROUP BY
+    rating
+ORDER BY
+    rating";
+
+pub const SELECT_MOVIE_TAGS: &str = "SELECT
+    user_id,
+    tag,
+    timestamp
+FROM
+    MovieLens_Tags
+WHERE
+    movie_id = ?1";
+
+// Intervalle plutôt que LIKE : SQLite n'utilise pas l'index avec une clause ESCAPE
+pub const AUTOCOMPLETE_TITLES: &str = "SELECT
+    movie_id,
+    tmdb_id,
+    title,
+    release_year,
+    poster_path
+FROM
+    MovieShortView
+WHERE
+    LOWER(title) >= ?1 AND LOWER(title) < ?1 || char(1114111)
+ORDER BY
+    popularity DESC, movie_id
+LIMIT ?2";
+
+// Films les plus proches d'après les notes MovieLens (table Movie_Similarities), par rang
+pub const SELECT_SIMILAR_MOVIES: &str = "SELECT
+    v.movie_id,
+    v.tmdb_id,
+    v.title,
+    v.vote_average,
+    v.poster_path,
+    v.weighted_rating,
+    s.similarity,
+    s.co_ratings
+FROM
+    Movie_Similarities s
+JOIN
+    MovieShortView v ON v.movie_id = s.similar_movie_id
+WHERE
+    s.movie_id = ?1
+ORDER BY
+    s.rank
+LIMIT ?2";
+
+// Films les plus proches par leur contenu : Jaccard pondéré des caractéristiques
+// (somme des poids communs / somme des poids de l'union)
+pub const SELECT_SIMILAR_MOVIES_BY_CONTENT: &str = "SELECT
+    v.movie_id,
+    v.tmdb_id,
+    v.title,
+    v.vote_average,
+    v.poster_path,
+    v.weighted_rating,
+    s.similarity
+FROM (
+    SELECT
+        c.tmdb_id,
+        SUM(f.weight) / (t.total_weight + n.total_weight - SUM(f.weight)) AS similarity
+    FROM
+        MovieLens_Links l
+    JOIN
+        Movie_Content_Norms t ON t.tmdb_id = l.tmdb_id
+    JOIN
+        Movie_Content_Features target ON target.tmdb_id = l.tmdb_id
+    JOIN
+        Content_Features f ON f.feature_id = target.feature_id
+    JOIN
+        Movie_Content_Features c ON c.feature_id = target.feature_id AND c.tmdb_id <> l.tmdb_id
+    JOIN
+        Movie_Content_Norms n ON n.tmdb_id = c.tmdb_id
+    WHERE
+        l.movie_id = ?1
+    GROUP BY
+        c.tmdb_id, t.total_weight, n.total_weight
+) s
+JOIN
+    MovieShortView v ON v.tmdb_id = s.tmdb_id
+ORDER BY
+    s.similarity DESC, v.movie_id
+LIMIT ?2";
+
+/* RECOMMENDATIONS */
+// Moyenne globale du modèle de recommandation actif
+pub const SELECT_RATING_MODEL: &str = "SELECT
+    global_mean
+FROM
+    Rating_Models
+WHERE
+    active = 1";
+
+pub const SELECT_USER_FACTORS: &str = "SELECT
+    bias,
+    factors
+FROM
+    User_Factors
+WHERE
+    user_id = ?1";
+
+// Facteurs des films que l'utilisateur n'a pas encore notés
+pub const SELECT_UNSEEN_MOVIE_FACTORS: &str = "SELECT
+    f.movie_id,
+    f.bias,
+    f.factors
+FROM
+    Movie_Factors f
+JOIN
+    MovieShortView v ON v.movie_id = f.movie_id
+WHERE
+    NOT EXISTS (
+        SELECT 1 FROM MovieLens_Ratings r
+        WHERE r.user_id = ?1 AND r.movie_id = f.movie_id
+    )";
+
+/* MOVIE-LENS USER PROFILE */
+// Nombre de notes et de tags d'un utilisateur, note moyenne et dates extrêmes ; aucune ligne si l'utilisateur n'existe pas
+pub const SELECT_MOVIE_LENS_USER_SUMMARY: &str = "SELECT
+    u.user_id,
+    r.rating_count,
+    r.average_rating,
+    r.first_rating,
+    r.last_rating,
+    t.tag_count,
+    t.first_tag,
+    t.last_tag
+FROM
+    MovieLens_Users u
+CROSS JOIN (
+    SELECT COUNT(*) AS rating_count, AVG(rating) AS average_rating, MIN(timestamp) AS first_rating, MAX(timestamp) AS last_rating
+    FROM MovieLens_Ratings
+    WHERE user_id = ?1
+) r
+CROSS JOIN (
+    SELECT COUNT(*) AS tag_count, MIN(timestamp) AS first_tag, MAX(timestamp) AS last_tag
+    FROM MovieLens_Tags
+    WHERE user_id = ?1
+) t
+WHERE
+    u.user_id = ?1";
+
+// Note moyenne de l'utilisateur sur chaque genre des films qu'il a notés
+pub const SELECT_USER_GENRE_RATINGS: &str = "SELECT
+    g.genre_id,
+    g.name,
+    COUNT(*) AS rating_count,
+    AVG(r.rating) AS average_rating
+FROM
+    MovieLens_Ratings r
+JOIN
+    MovieLens_Links l ON l.movie_id = r.movie_id
+JOIN
+    Movie_Genres mg ON mg.tmdb_id = l.tmdb_id
+JOIN
+    Genres g ON g.genre_id = mg.genre_id
+WHERE
+    r.user_id = ?1
+GROUP BY
+    g.genre_id, g.name";
+
+// Tags les plus utilisés par l'utilisateur
+pub const SELECT_USER_TOP_TAGS: &str = "SELECT
+    tag,
+    COUNT(*) AS tag_count
+FROM
+    MovieLens_Tags
+WHERE
+    user_id = ?1
+GROUP BY
+    tag
+ORDER BY
+    tag_count DESC, tag
+LIMIT ?2";
+
+/* MOVIE-LENS ADMIN WRITE */
+// Écriture des notes et des tags : mêmes contrôles que les procédures Oracle
+pub const COUNT_MOVIE_LINK: &str = "
+    SELECT COUNT(*) FROM MovieLens_Links WHERE movie_id = ?1
+";
+
+pub const COUNT_USER_MOVIE_RATINGS: &str = "
+    SELECT COUNT(*) FROM MovieLens_Ratings WHERE movie_id = ?1 AND user_id = ?2
+";
+
+// Un nouvel identifiant d'utilisateur est ajouté à MovieLens_Users
+pub const INSERT_MOVIE_LENS_USER: &str = "
+    INSERT OR IGNORE INTO MovieLens_Users (user_id) VALUES (?1)
+";
+
+pub const INSERT_MOVIE_LENS_RATING: &str = "
+    INSERT INTO MovieLens_Ratings (user_id, movie_id, rating, timestamp) VALUES (?2, ?1, ?3, ?4)
+";
+
+pub const UPDATE_MOVIE_LENS_RATING: &str = "
+    UPDATE MovieLens_Ratings
+    SET rating = ?3, timestamp = ?4
+    WHERE movie_id = ?1
+    AND user_id = ?2
+";
+
+// Score pondéré d'un film, même calcul que la migration 012_weighted_ratings.sql : recalculé après
+// l'ajout du film et après chaque note ; les autres films gardent le leur, calculé avec l'a priori précédent
+pub const REFRESH_MOVIE_WEIGHTED_RATING: &str = "
+    INSERT OR REPLACE INTO Movie_Weighted_Ratings (movie_id, weighted_rating)
+    WITH Prior(value) AS (
+        SELECT COALESCE(AVG(t.vote_average), 0)
+        FROM TMDB_movie_dataset t
+        JOIN MovieLens_Links l ON l.tmdb_id = t.id
+        WHERE t.vote_count > 0
+    ),
+    MovieLensRatings(movie_id, rating_count, rating_mean) AS (
+        SELECT movie_id, COUNT(*), AVG(rating)
+        FROM MovieLens_Ratings
+        WHERE movie_id = ?1
+        GROUP BY movie_id
+    )
+    SELECT
+        l.movie_id,
+        ROUND(
+            (1 - 0.0)
+                * (COALESCE(t.vote_count, 0) * COALESCE(t.vote_average, 0) + 200.0 * p.value)
+                / (COALESCE(t.vote_count, 0) + 200.0)
+            + 0.0
+                * (COALESCE(r.rating_count, 0) * COALESCE(r.rating_mean, 0) * 2 + 10.0 * p.value)
+                / (COALESCE(r.rating_count, 0) + 10.0),
+            6
+        )
+    FROM MovieLens_Links l
+    JOIN TMDB_movie_dataset t ON t.id = l.tmdb_id
+    CROSS JOIN Prior p
+    LEFT JOIN MovieLensRatings r ON r.movie_id = l.movie_id
+    WHERE l.movie_id = ?1
+";
+
+// Le timestamp identifie le tag : deux tags du même utilisateur sur le même film ne le partagent pas
+pub const SELECT_NEXT_TAG_TIMESTAMP: &str = "
+    SELECT MAX(?3, COALESCE(MAX(timestamp) + 1, 0))
+    FROM MovieLens_Tags
+    WHERE movie_id = ?1
+    AND user_id = ?2
+";
+
+pub const INSERT_MOVIE_LENS_TAG: &str = "
+    INSERT INTO MovieLens_Tags (user_id, movie_id, tag, timestamp) VALUES (?2, ?1, ?3, ?4)
+";
+
+pub const RENAME_MOVIE_LENS_TAG: &str = "
+    UPDATE MovieLens_Tags
+    SET tag = ?4
+    WHERE movie_id = ?1
+    AND user_id = ?2
+    AND timestamp = ?3
+";
+
+/* MOVIE-LENS TAG MODERATION */
+// Les tags supprimés sont archivés dans la corbeille avec INSERT_TAG_DELETION, comme DeleteMovieLensTag
+pub const ARCHIVE_MODERATED_TAG: &str = "
+    INSERT INTO Deleted_MovieLens_Tags (deletion_id, user_id, movie_id, tag, timestamp)
+    SELECT ?5, user_id, movie_id, tag, timestamp
+    FROM MovieLens_Tags
+    WHERE movie_id = ?1
+    AND user_id = ?2
+    AND timestamp = ?3
+    AND tag = ?4
+";
+
+pub const DELETE_MODERATED_TAG: &str = "
+    DELETE FROM MovieLens_Tags
+    WHERE movie_id = ?1
+    AND user_id = ?2
+    AND timestamp = ?3
+    AND tag = ?4
+";
+
+pub const REWRITE_MODERATED_TAG: &str = "
+    UPDATE MovieLens_Tags
+    SET tag = ?5
+    WHERE movie_id = ?1
+    AND user_id = ?2
+    AND timestamp = ?3
+    AND tag = ?4
+";
+
+/* MOVIE-LENS ADMIN DELETE */
+// Les triggers archivent les suppressions en cascade ; leur auteur est renseigné ensuite
+pub const SELECT_LAST_DELETION_ID: &str = "
+    SELECT COALESCE(MAX(deletion_id), 0) FROM MovieLens_Deletions
+";
+
+pub const SET_DELETIONS_AUTHOR: &str = "
+    UPDATE MovieLens_Deletions
+    SET deleted_by = ?2
+    WHERE deletion_id > ?1
+    AND deleted_by IS NULL
+";
+
+pub const COUNT_MOVIE_LENS_TAG: &str = "
+    SELECT COUNT(*)
+    FROM MovieLens_Tags
+    WHERE movie_id = ?1
+    AND user_id = ?2
+    AND timestamp = ?3
+";
+
+pub const INSERT_TAG_DELETION: &str = "
+    INSERT INTO MovieLens_Deletions (kind, user_id, movie_id, deleted_by, deleted_at)
+    VALUES ('Tag', ?2, ?1, ?3, ?4)
+";
+
+pub const ARCHIVE_MOVIE_LENS_TAG: &str = "
+    INSERT INTO Deleted_MovieLens_Tags (deletion_id, user_id, movie_id, tag, timestamp)
+    SELECT ?4, user_id, movie_id, tag, timestamp
+    FROM MovieLens_Tags
+    WHERE movie_id = ?1
+    AND user_id = ?2
+    AND timestamp = ?3
+";
+
+pub const DELETE_MOVIE_LENS_USER: &str = "
+    DELETE FROM MovieLens_Users WHERE user_id = ?1
+";
+
+pub const DELETE_MOVIE_LENS_TAG: &str = "
+    DELETE FROM MovieLens_Tags
+    WHERE movie_id = ?1
+    AND user_id = ?2
+    AND timestamp = ?3
+";
+
+/* ADMIN AUDIT LOG */
+// Mêmes colonnes que la procédure Oracle LogAdminAction
+pub const INSERT_AUDIT_ENTRY: &str = "
+    INSERT INTO Admin_Audit_Log (acted_by, acted_at, action, target, parameters)
+    VALUES (?1, ?2, ?3, ?4, ?5)
+";
+
+/* MOVIE-LENS TRASH */
+// Mêmes étapes que la procédure Oracle RestoreMovieLensDeletion
+pub const SELECT_DELETION: &str = "
+    SELECT kind, user_id, movie_id, imdb_id, tmdb_id
+    FROM MovieLens_Deletions
+    WHERE deletion_id = ?1
+";
+
+// Élément de la corbeille dont dépendent les lignes archivées : utilisateur ou film encore supprimé
+pub const SELECT_BLOCKING_DELETION: &str = "
+    SELECT MIN(d.deletion_id)
+    FROM MovieLens_Deletions d
+    WHERE d.deletion_id <> ?1
+    AND (
+        (
+            d.kind = 'User'
+            AND d.user_id IN (
+                SELECT user_id FROM Deleted_MovieLens_Ratings WHERE deletion_id = ?1
+                UNION
+                SELECT user_id FROM Deleted_MovieLens_Tags WHERE deletion_id = ?1
+            )
+            AND NOT EXISTS (SELECT 1 FROM MovieLens_Users u WHERE u.user_id = d.user_id)
+        )
+        OR (
+            d.kind = 'Movie'
+            AND d.movie_id IN (
+                SELECT movie_id FROM Deleted_MovieLens_Ratings WHERE deletion_id = ?1
+                UNION
+                SELECT movie_id FROM Deleted_MovieLens_Tags WHERE deletion_id = ?1
+            )
+            AND NOT EXISTS (SELECT 1 FROM MovieLens_Links l WHERE l.movie_id = d.movie_id)
+        )
+    )
+";
+
+pub const COUNT_RELINKED_MOVIE: &str = "
+    SELECT COUNT(*) FROM MovieLens_Links WHERE movie_id = ?1 OR tmdb_id = ?2
+";
+
+pub const RESTORE_RATINGS: &str = "
+    INSERT INTO MovieLens_Ratings (user_id, movie_id, rating, timestamp)
+    SELECT r.user_id, r.movie_id, r.rating, r.timestamp
+    FROM Deleted_MovieLens_Ratings r
+    WHERE r.deletion_id = ?1
+    AND NOT EXISTS (
+        SELECT 1 FROM MovieLens_Ratings x
+        WHERE x.user_id = r.user_id AND x.movie_id = r.movie_id
+    )
+";
+
+pub const RESTORE_TAGS: &str = "
+    INSERT INTO MovieLens_Tags (user_id, movie_id, tag, timestamp)
+    SELECT t.user_id, t.movie_id, t.tag, t.timestamp
+    FROM Deleted_MovieLens_Tags t
+    WHERE t.deletion_id = ?1
+    AND NOT EXISTS (
+        SELECT 1 FROM MovieLens_Tags x
+        WHERE x.user_id = t.user_id AND x.movie_id = t.movie_id AND x.timestamp = t.timestamp AND x.tag = t.tag
+    )
+";
+
+// Pas de clés étrangères en SQLite : les lignes archivées sont supprimées explicitement
+pub const DELETE_DELETED_RATINGS: &str = "
+    DELETE FROM Deleted_MovieLens_Ratings WHERE deletion_id = ?1
+";
+
+pub const DELETE_DELETED_TAGS: &str = "
+    DELETE FROM Deleted_MovieLens_Tags WHERE deletion_id = ?1
+";
+
+pub const DELETE_DELETION: &str = "
+    DELETE FROM MovieLens_Deletions WHERE deletion_id = ?1
+";
+
+// Identifiants TMDB ou IMDb déjà présents, dans le film ou dans son lien MovieLens
+pub const COUNT_MOVIE_DUPLICATES: &str = "
+    SELECT
+        (SELECT COUNT(*) FROM TMDB_movie_dataset WHERE id = ?1)
+            + (SELECT COUNT(*) FROM MovieLens_Links WHERE tmdb_id = ?1),
+        (SELECT COUNT(*) FROM TMDB_movie_dataset WHERE imdb_id = 'tt' || ?2)
+            + (SELECT COUNT(*) FROM MovieLens_Links WHERE imdb_id = ?2)
+";
+
+// Un film de la corbeille garde son movie_id, qui n'est pas réattribué
+pub const SELECT_NEXT_MOVIE_ID: &str = "
+    SELECT MAX(
+        COALESCE((SELECT MAX(movie_id) FROM MovieLens_Links), 0),
+        COALESCE((SELECT MAX(movie_id) FROM MovieLens_Deletions WHERE kind = 'Movie'), 0)
+    ) + 1
+";
+
+// Mêmes valeurs par défaut que la procédure Oracle AddMovie
+pub const INSERT_MOVIE: &str = "
+    INSERT INTO TMDB_movie_dataset (
+        id, title, vote_average, vote_count, status, release_date, revenue, runtime, adult, budget,
+        imdb_id, original_language, original_title, overview, popularity, poster_path, genres
+    ) VALUES (?1, ?2, 0, 0, 'Released', ?3, 0, ?4, ?5, 0, 'tt' || ?6, ?7, ?2, ?8, 0, ?9, ?10)
+";
+
+pub const INSERT_MOVIE_LINK: &str = "
+    INSERT INTO MovieLens_Links (movie_id, imdb_id, tmdb_id) VALUES (?1, ?2, ?3)
+";
+
+pub const INSERT_GENRE: &str = "
+    INSERT OR IGNORE INTO Genres (name) VALUES (?1)
+";
+
+pub const INSERT_MOVIE_GENRE: &str = "
+    INSERT OR IGNORE INTO Movie_Genres (tmdb_id, genre_id, position)
+    SELECT ?1, genre_id, ?3 FROM Genres WHERE name = ?2
+";
+
+// Modification partielle d'un film, seulement s'il n'a pas changé depuis la version lue
+pub const UPDATE_MOVIE: &str = "
+    UPDATE TMDB_movie_dataset
+    SET
+        title = COALESCE(?3, title),
+        overview = COALESCE(?4, overview),
+        release_date = COALESCE(?5, release_date),
+        runtime = COALESCE(?6, runtime),
+        poster_path = COALESCE(?7, poster_path),
+        row_version = row_version + 1
+    WHERE id = (SELECT tmdb_id FROM MovieLens_Links WHERE movie_id = ?1)
+    AND row_version = ?2
+";
+
+pub const SELECT_MOVIE_VERSION: &str = "
+    SELECT tmd.row_version
+    FROM TMDB_movie_dataset tmd
+    JOIN MovieLens_Links l ON l.tmdb_id = tmd.id
+    WHERE l.movie_id = ?1
+";
+
+pub const SELECT_MOVIE_TITLES: &str = "
+    SELECT tmd.id, tmd.title, tmd.original_title
+    FROM TMDB_movie_dataset tmd
+    JOIN MovieLens_Links l ON l.tmdb_id = tmd.id
+    WHERE l.movie_id = ?1
+";
+
+/* STATS */
+pub const COUNT_MOVIES: &str = "
+    SELECT COUNT(DISTINCT l.movie_id)
+    FROM MovieLens_Links l
+    JOIN TMDB_movie_dataset tmd ON l.tmdb_id = tmd.id
+";
+
+pub const COUNT_RATINGS: &str = "SELECT COUNT(*) FROM MovieLens_Ratings";
+
+pub const COUNT_TAGS: &str = "SELECT COUNT(*) FROM MovieLens_Tags";
+
+pub const COUNT_DISTINCT_USERS: &str = "
+    SELECT COUNT(*)
+    FROM (
+        SELECT user_id FROM MovieLens_Ratings
+        UNION
+        SELECT user_id FROM MovieLens_Tags
+    )
+";
+
+pub const GENRE_COUNT: &str = "
+    SELECT
+        g.name AS genre,
+        COUNT(*) AS num_movies
+    FROM
+        Movie_Genres mg
+    JOIN
+        Genres g ON g.genre_id = mg.genre_id
+    JOIN
+        MovieLens_Links l ON l.tmdb_id = mg.tmdb_id
+    GROUP BY
+        g.name
+    ORDER BY
+        num_movies DESC
+";
+
+pub const TOP_USERS: &str = "
+    SELECT user_id, num_ratings, num_tags
+    FROM TopUsers
+    LIMIT 5
+";
+
+pub const TOP_PROFITS_MOVIES: &str = "
+    SELECT
+        movie_id,
+        title,
+        poster_path,
+        (revenue - budget) AS profit
+    FROM
+        MovieDetailsView
+    ORDER BY
+        profit DESC
+    LIMIT 5
+";
+
+/* USER */
+pub const SELECT_SQL_USER: &str = "
+    SELECT
+        username,
+        password,
+        is_admin
+    FROM
+        Users_Records
+    WHERE
+        username = ?1
+";
+
+pub const CREATE_SQL_USER: &str = "
+    INSERT INTO Users_Records (username, password, is_admin) VALUES (?1, ?2, ?3)
+";
+
+pub const DELETE_SQL_USER: &str = "
+    DELETE FROM Users_Records WHERE UPPER(username) = UPPER(?1)
+";
+
+pub const GET_SQL_USERS: &str = "
+    SELECT
+        username,
+        is_admin,
+        created_at
+    FROM
+        Users_Records
+    ORDER BY
+        created_at DESC";
@@ -0,0 +1,374 @@
+use std::{fs, path::PathBuf, process};
+
+use super::SqliteStore;
+use crate::sql::{
+    config::PoolConfig,
+    moderation::TagRule,
+    search::{AuditLogSearch, MovieSearch, TrashSearch},
+    store::{MovieStore, StoreError},
+    types::{
+        audit::{AdminAction, AuditFilter},
+        movie::NewMovie,
+        page::PageRequest,
+        search_query::SearchQuery,
+        sql_user::SqlUserCredentials,
+        tag_moderation::{TagModeration, TagModerationAction, TagPattern},
+        trash::TrashKind,
+    },
+};
+
+/// Base de test dans un fichier temporaire, supprimé à la fin du test : chaque connexion
+/// d'un pool sur `:memory:` ouvrirait sa propre base vide
+struct TestDb {
+    path: PathBuf,
+}
+
+impl TestDb {
+    fn new(name: &str) -> Self {
+        let db = Self {
+            path: std::env::temp_dir().join(format!("movie-db-test-{}-{}.sqlite", process::id(), name)),
+        };
+        db.remove_files();
+        db
+    }
+
+    /// Ouvre la base (et applique les migrations) avec un compte de démonstration
+    fn open(&self, username: &str, password: &str) -> Result<SqliteStore, StoreError> {
+        let user = SqlUserCredentials {
+            username: username.to_string(),
+            password: password.to_string(),
+        };
+        SqliteStore::open(self.path.to_str().unwrap(), user, &PoolConfig::default())
+    }
+
+    fn admin(&self) -> SqliteStore {
+        self.open("admin", "pass").unwrap()
+    }
+
+    fn remove_files(&self) {
+        for suffix in ["", "-wal", "-shm"] {
+            let mut path = self.path.clone().into_os_string();
+            path.push(suffix);
+            fs::remove_file(path).ok();
+        }
+    }
+}
+
+impl Drop for TestDb {
+    fn drop(&mut self) {
+        self.remove_files();
+    }
+}
+
+fn audit_actions(store: &SqliteStore, action: Option<AdminAction>) -> Vec<String> {
+    let filter = AuditFilter { action, ..AuditFilter::default() };
+    let search = AuditLogSearch::new(filter, PageRequest::default()).unwrap();
+    store.get_audit_log(search).unwrap().items.into_iter().map(|entry| entry.action).collect()
+}
+
+fn rule(pattern: TagPattern, action: TagModerationAction) -> TagRule {
+    TagRule::compile(&TagModeration { pattern, action }).unwrap()
+}
+
+#[test]
+fn login_checks_the_password() {
+    let db = TestDb::new("login");
+
+    assert!(db.open("admin", "wrong").is_err());
+    assert!(db.open("nobody", "pass").is_err());
+
+    let admin = db.admin();
+    assert!(admin.current_user().unwrap().is_admin);
+    let spectator = db.open("spectator", "pass").unwrap();
+    assert!(!spectator.current_user().unwrap().is_admin);
+}
+
+#[test]
+fn stored_passwords_are_hashed() {
+    let db = TestDb::new("hashes");
+    let admin = db.admin();
+
+    admin.create_sql_user("alice", "secret", false).unwrap();
+    assert!(db.open("alice", "secret").is_ok());
+    assert!(db.open("alice", "pass").is_err());
+
+    let conn = admin.conn().unwrap();
+    let mut stmt = conn.prepare("SELECT password FROM Users_Records").unwrap();
+    let passwords: Vec<String> = stmt
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(passwords.len(), 3);
+    assert!(passwords.iter().all(|password| password.starts_with("$argon2")));
+}
+
+#[test]
+fn spectator_cannot_use_admin_commands() {
+    let db = TestDb::new("spectator");
+    let spectator = db.open("spectator", "pass").unwrap();
+    let delete_pixar = rule(TagPattern::Substring { text: "pixar".to_string() }, TagModerationAction::Delete);
+
+    assert!(spectator.add_movie_lens_rating(2, 1, 4.0).is_err());
+    assert!(spectator.add_movie_lens_tag(2, 1, "jungle").is_err());
+    assert!(spectator.delete_movie_lens_user(7).is_err());
+    assert!(spectator.preview_tag_moderation(&delete_pixar, 20).is_err());
+    assert!(spectator.apply_tag_moderation(&delete_pixar, None).is_err());
+    assert!(spectator.get_trash(TrashSearch::new(None, PageRequest::default()).unwrap()).is_err());
+    assert!(spectator
+        .get_audit_log(AuditLogSearch::new(AuditFilter::default(), PageRequest::default()).unwrap())
+        .is_err());
+    assert!(spectator.create_sql_user("mallory", "secret", true).is_err());
+
+    // Rien n'a été modifié ni journalisé
+    let admin = db.admin();
+    assert!(admin.get_movie_tags(1).unwrap().iter().any(|tag| tag.tag == "pixar"));
+    assert!(audit_actions(&admin, None).is_empty());
+}
+
+#[test]
+fn movies_are_paged_and_searched() {
+    let db = TestDb::new("paging");
+    let store = db.open("spectator", "pass").unwrap();
+
+    let page = PageRequest {
+        size: Some(4),
+        ..PageRequest::default()
+    };
+    let first = store.fetch_all_movies(MovieSearch::new(SearchQuery::default(), Vec::new(), page).unwrap()).unwrap();
+    assert_eq!(first.total, 10);
+    assert_eq!(first.items.len(), 4);
+
+    let page = PageRequest {
+        size: Some(4),
+        cursor: first.next_cursor.clone(),
+        ..PageRequest::default()
+    };
+    let second = store.fetch_all_movies(MovieSearch::new(SearchQuery::default(), Vec::new(), page).unwrap()).unwrap();
+    assert_eq!(second.items.len(), 4);
+    assert!(second.items.iter().all(|movie| first.items.iter().all(|seen| seen.movie_id != movie.movie_id)));
+
+    let query = SearchQuery {
+        title: "matrix".to_string(),
+        ..SearchQuery::default()
+    };
+    let found = store.fetch_all_movies(MovieSearch::new(query, Vec::new(), PageRequest::default()).unwrap()).unwrap();
+    assert_eq!(found.total, 1);
+    assert_eq!(found.items[0].title, "The Matrix");
+    assert!(found.next_cursor.is_none());
+}
+
+#[test]
+fn ratings_and_tags_are_written_and_logged() {
+    let db = TestDb::new("writes");
+    let admin = db.admin();
+
+    admin.add_movie_lens_rating(2, 1, 4.0).unwrap();
+    // Même erreur que la procédure AddMovieLensRating
+    assert!(admin.add_movie_lens_rating(2, 1, 3.0).is_err());
+    admin.update_movie_lens_rating(2, 1, 3.5).unwrap();
+    assert!(admin.update_movie_lens_rating(2, 99, 3.5).is_err());
+
+    let ratings = admin.get_movie_ratings(2).unwrap();
+    assert!(ratings.iter().any(|rating| rating.user_id == 1 && rating.rating == 3.5));
+
+    let tag = admin.add_movie_lens_tag(2, 1, "jungle").unwrap();
+    admin.rename_movie_lens_tag(2, 1, tag.timestamp, "board game").unwrap();
+    let tags = admin.get_movie_tags(2).unwrap();
+    assert!(tags.iter().any(|t| t.user_id == 1 && t.tag == "board game"));
+    assert!(tags.iter().all(|t| t.tag != "jungle"));
+
+    let actions = audit_actions(&admin, None);
+    assert_eq!(actions.len(), 4);
+    for action in ["AddMovieLensRating", "UpdateMovieLensRating", "AddMovieLensTag", "RenameMovieLensTag"] {
+        assert!(actions.iter().any(|a| a == action), "{} absent du journal", action);
+    }
+}
+
+#[test]
+fn weighted_ratings_are_refreshed_after_writes() {
+    let db = TestDb::new("weighted");
+    let admin = db.admin();
+    let conn = admin.conn().unwrap();
+    let unscored = || -> u32 {
+        let sql = "SELECT COUNT(*) FROM MovieShortView WHERE weighted_rating IS NULL";
+        conn.query_row(sql, [], |row| row.get(0)).unwrap()
+    };
+
+    let movie = NewMovie {
+        tmdb_id: 999001,
+        imdb_id: "9990001".to_string(),
+        title: "Weighted Test".to_string(),
+        overview: "A movie without votes.".to_string(),
+        release_date: "2024-01-01".to_string(),
+        runtime: 90,
+        poster_path: String::new(),
+        original_language: None,
+        adult: false,
+        genres: Vec::new(),
+    };
+    let added = admin.add_movie(movie).unwrap();
+    assert_eq!(unscored(), 0);
+
+    // Sans vote, le score est l'a priori : la moyenne des notes TMDB des films reliés
+    let (score, prior): (f64, f64) = conn
+        .query_row(
+            "SELECT w.weighted_rating, (SELECT ROUND(AVG(vote_average), 6) FROM MovieShortView WHERE vote_count > 0)
+             FROM Movie_Weighted_Ratings w WHERE w.movie_id = ?1",
+            [added.movie_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(score, prior);
+
+    // Une note ajoutée ou modifiée recalcule le score de son film
+    conn.execute("DELETE FROM Movie_Weighted_Ratings", []).unwrap();
+    admin.add_movie_lens_rating(added.movie_id, 1, 4.0).unwrap();
+    assert_eq!(unscored(), 10);
+    admin.update_movie_lens_rating(1, 1, 4.5).unwrap();
+    assert_eq!(unscored(), 9);
+}
+
+#[test]
+fn tag_moderation_is_previewed_then_applied() {
+    let db = TestDb::new("moderation");
+    let admin = db.admin();
+    let rewrite = rule(
+        TagPattern::Substring { text: "pixar".to_string() },
+        TagModerationAction::Rewrite {
+            replacement: "Pixar".to_string(),
+        },
+    );
+
+    let preview = admin.preview_tag_moderation(&rewrite, 20).unwrap();
+    assert_eq!(preview.summary.matched_count, 2);
+    assert_eq!(preview.summary.rewritten_count, 2);
+    assert_eq!(preview.samples.len(), 2);
+
+    // Les tags ont changé depuis l'aperçu : rien n'est modifié
+    assert!(admin.apply_tag_moderation(&rewrite, Some(3)).is_err());
+    assert!(admin.get_movie_tags(1).unwrap().iter().any(|tag| tag.tag == "pixar"));
+
+    let report = admin.apply_tag_moderation(&rewrite, Some(preview.summary.matched_count)).unwrap();
+    assert_eq!(report.summary.rewritten_count, 2);
+    assert!(admin.get_movie_tags(1).unwrap().iter().any(|tag| tag.tag == "Pixar"));
+    assert!(admin.get_movie_tags(60069).unwrap().iter().any(|tag| tag.tag == "Pixar"));
+
+    let delete = rule(
+        TagPattern::Words {
+            words: vec!["classic".to_string()],
+        },
+        TagModerationAction::Delete,
+    );
+    let report = admin.apply_tag_moderation(&delete, None).unwrap();
+    assert_eq!(report.summary.deleted_count, 1);
+    assert!(admin.get_movie_tags(858).unwrap().is_empty());
+
+    assert_eq!(audit_actions(&admin, Some(AdminAction::ApplyTagModeration)).len(), 2);
+}
+
+#[test]
+fn tag_moderation_reads_candidates_without_missing_case_variants() {
+    let db = TestDb::new("moderation-case");
+    let admin = db.admin();
+    admin.add_movie_lens_tag(1, 1, "PIXAR magic").unwrap();
+    // Signe kelvin : le motif le trouve sans tenir compte de la casse, mais LOWER de SQLite le laisse tel quel
+    admin.add_movie_lens_tag(1, 2, "\u{212A}ids").unwrap();
+
+    let pixar = rule(TagPattern::Substring { text: "Pixar".to_string() }, TagModerationAction::Delete);
+    assert_eq!(admin.preview_tag_moderation(&pixar, 20).unwrap().summary.matched_count, 3);
+
+    let kids = rule(
+        TagPattern::Words {
+            words: vec!["kids".to_string()],
+        },
+        TagModerationAction::Delete,
+    );
+    let preview = admin.preview_tag_moderation(&kids, 20).unwrap();
+    assert_eq!(preview.summary.matched_count, 1);
+    assert_eq!(preview.samples[0].tag, "\u{212A}ids");
+}
+
+#[test]
+fn deleted_tag_is_restored_from_the_trash() {
+    let db = TestDb::new("trash-tag");
+    let admin = db.admin();
+
+    admin.delete_movie_lens_tag(858, 4, 945078500).unwrap();
+    assert!(admin.get_movie_tags(858).unwrap().is_empty());
+
+    let trash = admin.get_trash(TrashSearch::new(None, PageRequest::default()).unwrap()).unwrap();
+    assert_eq!(trash.total, 1);
+    let item = &trash.items[0];
+    assert_eq!(item.kind, TrashKind::Tag);
+    assert_eq!(item.tag.as_deref(), Some("classic"));
+    assert_eq!(item.deleted_by.as_deref(), Some("admin"));
+
+    let restore = admin.restore_trash_item(item.deletion_id).unwrap();
+    assert_eq!(restore.restored_tags, 1);
+    assert_eq!(admin.get_movie_tags(858).unwrap()[0].tag, "classic");
+    assert_eq!(admin.get_trash(TrashSearch::new(None, PageRequest::default()).unwrap()).unwrap().total, 0);
+
+    // Déjà restauré
+    assert!(admin.restore_trash_item(item.deletion_id).is_err());
+    let mut actions = audit_actions(&admin, None);
+    actions.sort();
+    assert_eq!(actions, ["DeleteMovieLensTag", "RestoreTrashItem"]);
+}
+
+#[test]
+fn moderated_tags_are_restored_from_the_trash() {
+    let db = TestDb::new("trash-moderation");
+    let admin = db.admin();
+    let delete = rule(TagPattern::Substring { text: "pixar".to_string() }, TagModerationAction::Delete);
+
+    let report = admin.apply_tag_moderation(&delete, Some(2)).unwrap();
+    assert_eq!(report.summary.deleted_count, 2);
+    assert!(admin.get_movie_tags(1).unwrap().is_empty());
+    assert!(admin.get_movie_tags(60069).unwrap().is_empty());
+
+    // Une entrée de corbeille par tag supprimé
+    let trash = admin.get_trash(TrashSearch::new(None, PageRequest::default()).unwrap()).unwrap();
+    assert_eq!(trash.total, 2);
+    assert!(trash.items.iter().all(|item| item.kind == TrashKind::Tag
+        && item.tag.as_deref() == Some("pixar")
+        && item.deleted_by.as_deref() == Some("admin")));
+
+    let item = trash.items.iter().find(|item| item.movie_id == Some(1)).unwrap();
+    let restore = admin.restore_trash_item(item.deletion_id).unwrap();
+    assert_eq!(restore.restored_tags, 1);
+    assert_eq!(admin.get_movie_tags(1).unwrap()[0].tag, "pixar");
+    assert!(admin.get_movie_tags(60069).unwrap().is_empty());
+    assert_eq!(admin.get_trash(TrashSearch::new(None, PageRequest::default()).unwrap()).unwrap().total, 1);
+}
+
+#[test]
+fn deleted_user_is_restored_with_ratings_and_tags() {
+    let db = TestDb::new("trash-user");
+    let admin = db.admin();
+
+    admin.delete_movie_lens_user(7).unwrap();
+    assert!(admin.get_movie_ratings(1240).unwrap().iter().all(|rating| rating.user_id != 7));
+    assert!(admin.get_movie_tags(1240).unwrap().is_empty());
+
+    let search = TrashSearch::new(Some(TrashKind::User), PageRequest::default()).unwrap();
+    let trash = admin.get_trash(search).unwrap();
+    assert_eq!(trash.total, 1);
+    let item = &trash.items[0];
+    assert_eq!(item.user_id, Some(7));
+    assert_eq!((item.rating_count, item.tag_count), (3, 2));
+
+    let restore = admin.restore_trash_item(item.deletion_id).unwrap();
+    assert_eq!((restore.restored_ratings, restore.restored_tags), (3, 2));
+    assert!(admin.get_movie_ratings(1240).unwrap().iter().any(|rating| rating.user_id == 7));
+    assert_eq!(admin.get_movie_tags(1240).unwrap()[0].tag, "time travel");
+
+    let filter = AuditFilter {
+        target: Some("movielens_user:7".to_string()),
+        ..AuditFilter::default()
+    };
+    let log = admin.get_audit_log(AuditLogSearch::new(filter, PageRequest::default()).unwrap()).unwrap();
+    assert_eq!(log.total, 1);
+    assert_eq!(log.items[0].action, "DeleteMovieLensUser");
+    assert_eq!(log.items[0].acted_by, "admin");
+}
@@ -0,0 +1,244 @@
+use std::{
+    fmt,
+    sync::{Arc, RwLock},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::console;
+
+use super::{
+    config::{ConnectionProfile, PoolConfig, ProfileBackend},
+    database::OracleStore,
+    moderation::TagRule,
+    search::{AuditLogSearch, FacetSearch, MovieActivitySearch, MovieSearch, TrashSearch, UserActivitySearch},
+    sqlite::SqliteStore,
+    types::{
+        audit::AuditEntry,
+        content::ContentMatch,
+        facet::FacetValue,
+        genre::Genre,
+        movie::{Movie, MovieDetails, MovieMatch, MovieRating, MovieShort, MovieTag, MovieUpdate, NewMovie, Recommendation, SimilarMovie, TitleCompletion},
+        movie_lens_user::MovieLensUserProfile,
+        page::Page,
+        sql_user::{SqlUser, SqlUserCredentials},
+        stats::Stats,
+        tag_moderation::{TagModerationPreview, TagModerationReport},
+        trash::{TrashItem, TrashRestore},
+    },
+};
+
+/// Erreur commune aux différents backends de stockage
+#[derive(Debug)]
+pub enum StoreError {
+    Oracle(oracle::Error),
+    Sqlite(rusqlite::Error),
+    Pool(r2d2::Error),
+    Message(String),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::Oracle(e) => write!(f, "{}", e),
+            StoreError::Sqlite(e) => write!(f, "{}", e),
+            StoreError::Pool(e) => write!(f, "{}", e),
+            StoreError::Message(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+impl From<oracle::Error> for StoreError {
+    fn from(e: oracle::Error) -> Self {
+        StoreError::Oracle(e)
+    }
+}
+
+impl From<rusqlite::Error> for StoreError {
+    fn from(e: rusqlite::Error) -> Self {
+        StoreError::Sqlite(e)
+    }
+}
+
+impl From<r2d2::Error> for StoreError {
+    fn from(e: r2d2::Error) -> Self {
+        StoreError::Pool(e)
+    }
+}
+
+/// Ensemble des requêtes exposées par l'application, quel que soit le backend.
+/// Les implémentations s'appuient sur un pool : chaque appel emprunte sa propre connexion.
+pub trait MovieStore: Send + Sync {
+    fn current_sql_username(&self) -> Result<String, StoreError>;
+    fn current_user(&self) -> Result<SqlUser, StoreError>;
+
+    fn fetch_all_movies(&self, search: MovieSearch) -> Result<Page<MovieShort>, StoreError>;
+    /// Recherche plein texte ou approchée, triée par pertinence
+    fn search_movies_ranked(&self, search: MovieSearch) -> Result<Page<MovieMatch>, StoreError>;
+    /// Titres commençant par `prefix` (sans tenir compte de la casse), les plus populaires d'abord
+    fn autocomplete_titles(&self, prefix: &str, limit: u32) -> Result<Vec<TitleCompletion>, StoreError>;
+    /// Détails et statistiques des notes d'un film ; les notes une à une seulement si `include_ratings`
+    fn get_movie(&self, movie_id: i32, include_ratings: bool) -> Result<Option<Movie>, StoreError>;
+    /// Voisins précalculés d'un film d'après les notes MovieLens, les plus proches d'abord
+    fn get_similar_movies_by_ratings(&self, movie_id: i32, limit: u32) -> Result<Vec<SimilarMovie>, StoreError>;
+    /// Films les plus proches par leurs genres, mots-clés et termes du résumé
+    fn get_similar_movies_by_content(&self, movie_id: i32, limit: u32) -> Result<Vec<ContentMatch>, StoreError>;
+    /// Films non notés par l'utilisateur les mieux prédits par le modèle de recommandation
+    fn get_recommendations_for_user(&self, user_id: i32, n: u32) -> Result<Vec<Recommendation>, StoreError>;
+    fn get_genres(&self) -> Result<Vec<Genre>, StoreError>;
+    /// Valeurs d'un attribut (mots-clés, sociétés...), les plus fréquentes d'abord
+    fn get_facet_values(&self, search: FacetSearch) -> Result<Page<FacetValue>, StoreError>;
+
+    fn get_stats(&self) -> Result<Stats, StoreError>;
+
+    /// Notes d'un film, filtrées, triées et paginées
+    fn get_movie_ratings_page(&self, search: MovieActivitySearch) -> Result<Page<MovieRating>, StoreError>;
+    /// Tags d'un film, filtrés, triés et paginés
+    fn get_movie_tags_page(&self, search: MovieActivitySearch) -> Result<Page<MovieTag>, StoreError>;
+
+    /// Profil d'un utilisateur MovieLens : statistiques, affinités par genre, tags favoris et historiques paginés
+    fn get_movie_lens_user(
+        &self,
+        user_id: i32,
+        ratings: UserActivitySearch,
+        tags: UserActivitySearch,
+    ) -> Result<Option<MovieLensUserProfile>, StoreError>;
+    /// Ajoute un film et son lien MovieLens en une transaction, puis renvoie ses détails
+    fn add_movie(&self, movie: NewMovie) -> Result<MovieDetails, StoreError>;
+    /// Modifie les champs renseignés d'un film si sa version n'a pas changé, puis renvoie ses nouveaux détails
+    fn update_movie(&self, movie_id: i32, update: MovieUpdate) -> Result<MovieDetails, StoreError>;
+    /// Note un film ; un identifiant d'utilisateur inconnu est ajouté à MovieLens_Users
+    fn add_movie_lens_rating(&self, movie_id: i32, user_id: i32, rating: f32) -> Result<MovieRating, StoreError>;
+    /// Change une note existante, datée du moment de la modification
+    fn update_movie_lens_rating(&self, movie_id: i32, user_id: i32, rating: f32) -> Result<MovieRating, StoreError>;
+    /// Ajoute un tag ; un identifiant d'utilisateur inconnu est ajouté à MovieLens_Users
+    fn add_movie_lens_tag(&self, movie_id: i32, user_id: i32, tag: &str) -> Result<MovieTag, StoreError>;
+    /// Change le texte d'un tag, identifié comme pour sa suppression
+    fn rename_movie_lens_tag(&self, movie_id: i32, user_id: i32, timestamp: i64, tag: &str) -> Result<MovieTag, StoreError>;
+    /// Tags trouvés par une règle de modération, sans modifier la base
+    fn preview_tag_moderation(&self, rule: &TagRule, sample_size: u32) -> Result<TagModerationPreview, StoreError>;
+    /// Supprime ou réécrit en une transaction les tags trouvés par une règle ; refusé si leur nombre
+    /// n'est plus celui de l'aperçu (`expected_matches`)
+    fn apply_tag_moderation(&self, rule: &TagRule, expected_matches: Option<u32>) -> Result<TagModerationReport, StoreError>;
+    /// Supprime un utilisateur ; lui, ses notes et ses tags sont archivés dans la corbeille
+    fn delete_movie_lens_user(&self, user_id: i32) -> Result<(), StoreError>;
+    /// Supprime un tag, archivé dans la corbeille
+    fn delete_movie_lens_tag(&self, movie_id: i32, user_id: i32, timestamp: i64) -> Result<(), StoreError>;
+    /// Éléments de la corbeille, les plus récemment supprimés d'abord
+    fn get_trash(&self, search: TrashSearch) -> Result<Page<TrashItem>, StoreError>;
+    /// Restaure un élément de la corbeille avec tout ce qui a été supprimé en cascade avec lui
+    fn restore_trash_item(&self, deletion_id: i32) -> Result<TrashRestore, StoreError>;
+    /// Journal des actions d'administration, les plus récentes d'abord ; réservé aux administrateurs
+    fn get_audit_log(&self, search: AuditLogSearch) -> Result<Page<AuditEntry>, StoreError>;
+
+    fn create_sql_user(&self, username: &str, password: &str, is_admin: bool) -> Result<(), StoreError>;
+    fn delete_sql_user(&self, username: &str) -> Result<(), StoreError>;
+    fn get_sql_users(&self) -> Result<Vec<SqlUser>, StoreError>;
+
+    /// Ferme le pool une fois toutes les connexions rendues
+    fn close(&self) -> Result<(), StoreError>;
+}
+
+// Durée maximale d'attente des requêtes en cours lors de la déconnexion
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Backend partagé : le verrou n'est tenu que le temps de cloner l'`Arc`
+lazy_static::lazy_static! {
+    static ref STORE: RwLock<Option<Arc<dyn MovieStore>>> = RwLock::new(None);
+}
+
+/// Ouvre le backend décrit par le profil
+fn open_store(
+    profile: &ConnectionProfile,
+    pool: &PoolConfig,
+    user: SqlUserCredentials,
+) -> Result<Arc<dyn MovieStore>, StoreError> {
+    Ok(match &profile.backend {
+        ProfileBackend::Oracle(oracle) => Arc::new(OracleStore::connect(user, oracle, pool)?),
+        ProfileBackend::Sqlite { path } => Arc::new(SqliteStore::open(path, user, pool)?),
+    })
+}
+
+/// Initialise le pool du backend décrit par le profil. Un backend déjà ouvert est remplacé,
+/// puis fermé une fois ses requêtes terminées : un changement de profil ou d'utilisateur prend effet
+pub async fn init_store(profile: ConnectionProfile, user: SqlUserCredentials) -> Result<(), StoreError> {
+    console::state("Db", &format!("Connecting to {}...", profile.name));
+    let store = run_blocking(move || open_store(&profile, &profile.pool, user)).await??;
+
+    let previous = STORE.write().unwrap().replace(store);
+    console::success("Db", "Connected");
+
+    match previous {
+        Some(previous) => run_blocking(move || drain_and_close(previous)).await?,
+        None => Ok(()),
+    }
+}
+
+/// Vérifie qu'un profil permet de se connecter, sans toucher à la connexion courante
+pub async fn test_profile(profile: ConnectionProfile, user: SqlUserCredentials) -> Result<String, StoreError> {
+    run_blocking(move || {
+        let pool = PoolConfig {
+            min_connections: 1,
+            max_connections: 1,
+            connection_increment: 1,
+        };
+
+        let store = open_store(&profile, &pool, user)?;
+        let username = store.current_sql_username();
+        store.close()?;
+        username
+    })
+    .await?
+}
+
+/// Retire le backend puis attend la fin des requêtes en cours avant de fermer le pool
+pub async fn close_store() -> Result<(), StoreError> {
+    let store = match STORE.write().unwrap().take() {
+        Some(store) => store,
+        None => return Ok(()),
+    };
+
+    run_blocking(move || drain_and_close(store)).await?
+}
+
+/// Ferme un backend retiré de `STORE`, après la fin des requêtes qui l'utilisent encore
+fn drain_and_close(store: Arc<dyn MovieStore>) -> Result<(), StoreError> {
+    let started = Instant::now();
+    while Arc::strong_count(&store) > 1 && started.elapsed() < DRAIN_TIMEOUT {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    store.close()?;
+    console::success("Db", "Connection closed");
+    Ok(())
+}
+
+/// Exécute `f` sur le backend courant, hors du runtime async
+pub async fn with_store<T, F>(f: F) -> Result<T, String>
+where
+    T: Send + 'static,
+    F: FnOnce(&dyn MovieStore) -> Result<T, StoreError> + Send + 'static,
+{
+    let store = STORE
+        .read()
+        .map_err(|_| "Erreur lors de l'accès à la connexion".to_string())?
+        .clone()
+        .ok_or_else(|| "Connexion non initialisée. Connectez-vous avec `login_user`.".to_string())?;
+
+    run_blocking(move || f(store.as_ref()))
+        .await
+        .and_then(|res| res)
+        .map_err(|e| e.to_string())
+}
+
+async fn run_blocking<T, F>(f: F) -> Result<T, StoreError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    tauri::async_runtime::spawn_blocking(f)
+        .await
+        .map_err(|e| StoreError::Message(e.to_string()))
+}
@@ -0,0 +1,47 @@
+use std::collections::BTreeSet;
+
+// Seuil de similarité (Jaccard sur les trigrammes) en dessous duquel un titre est ignoré
+pub const SIMILARITY_THRESHOLD: f64 = 0.3;
+
+/// Trigrammes distincts d'un texte, calculés comme la procédure Oracle `IndexMovieTitle` :
+/// minuscules, ponctuation remplacée par des espaces, chaque mot complété en `"  mot "`
+pub fn trigrams(text: &str) -> Vec<String> {
+    let normalized: String = text
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    let mut trigrams = BTreeSet::new();
+    for word in normalized.split_whitespace() {
+        let padded: Vec<char> = format!("  {} ", word).chars().collect();
+        for window in padded.windows(3) {
+            trigrams.insert(window.iter().collect::<String>());
+        }
+    }
+
+    trigrams.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_are_padded_like_index_movie_title() {
+        assert_eq!(trigrams("Heat"), ["  h", " he", "at ", "eat", "hea"]);
+    }
+
+    #[test]
+    fn case_punctuation_and_repeats_are_ignored() {
+        assert_eq!(trigrams("HEAT!"), trigrams("heat"));
+        assert_eq!(trigrams("WALL·E"), trigrams("wall e"));
+        assert_eq!(trigrams("aaaa"), ["  a", " aa", "aa ", "aaa"]);
+    }
+
+    #[test]
+    fn text_without_words_has_no_trigram() {
+        assert!(trigrams("").is_empty());
+        assert!(trigrams(" - !").is_empty());
+    }
+}
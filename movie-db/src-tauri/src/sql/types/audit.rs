@@ -0,0 +1,92 @@
+use oracle::{Error, Row};
+use serde::{Deserialize, Serialize};
+
+use super::search_query::ValueRange;
+
+/// Action d'administration journalisée dans Admin_Audit_Log
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AdminAction {
+    CreateSqlUser,
+    DeleteSqlUser,
+    DeleteMovieLensUser,
+    DeleteMovieLensTag,
+    AddMovie,
+    UpdateMovie,
+    AddMovieLensRating,
+    UpdateMovieLensRating,
+    AddMovieLensTag,
+    RenameMovieLensTag,
+    ApplyTagModeration,
+    RestoreTrashItem,
+}
+
+impl AdminAction {
+    /// Valeur de la colonne `action`, écrite telle quelle par les procédures Oracle
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdminAction::CreateSqlUser => "CreateSqlUser",
+            AdminAction::DeleteSqlUser => "DeleteSqlUser",
+            AdminAction::DeleteMovieLensUser => "DeleteMovieLensUser",
+            AdminAction::DeleteMovieLensTag => "DeleteMovieLensTag",
+            AdminAction::AddMovie => "AddMovie",
+            AdminAction::UpdateMovie => "UpdateMovie",
+            AdminAction::AddMovieLensRating => "AddMovieLensRating",
+            AdminAction::UpdateMovieLensRating => "UpdateMovieLensRating",
+            AdminAction::AddMovieLensTag => "AddMovieLensTag",
+            AdminAction::RenameMovieLensTag => "RenameMovieLensTag",
+            AdminAction::ApplyTagModeration => "ApplyTagModeration",
+            AdminAction::RestoreTrashItem => "RestoreTrashItem",
+        }
+    }
+}
+
+/// Ligne du journal. `target` désigne l'élément modifié (`movie:10`, `movielens_user:42`, `sql_user:BOB`, `trash:5`),
+/// `parameters` les autres arguments sous la forme `clé=valeur, ...` ; aucun mot de passe n'est journalisé
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub audit_id: i32,
+    /// Utilisateur SQL à l'origine de l'action
+    pub acted_by: String,
+    /// Timestamp Unix de l'action
+    pub acted_at: i64,
+    pub action: String,
+    pub target: Option<String>,
+    pub parameters: Option<String>,
+}
+
+impl AuditEntry {
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        Ok(Self {
+            audit_id: row.get(0)?,
+            acted_by: row.get(1)?,
+            acted_at: row.get(2)?,
+            action: row.get(3)?,
+            target: row.get(4)?,
+            parameters: row.get(5)?,
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            audit_id: row.get(0)?,
+            acted_by: row.get(1)?,
+            acted_at: row.get(2)?,
+            action: row.get(3)?,
+            target: row.get(4)?,
+            parameters: row.get(5)?,
+        })
+    }
+}
+
+/// Filtres du journal ; un champ absent ne filtre pas
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuditFilter {
+    pub action: Option<AdminAction>,
+    /// Utilisateur SQL, sans tenir compte de la casse
+    pub acted_by: Option<String>,
+    /// Élément modifié, par exemple `movie:10` ou `sql_user:BOB`
+    pub target: Option<String>,
+    /// Horodatages Unix (secondes), bornes incluses
+    pub acted_at: ValueRange<i64>,
+}
@@ -0,0 +1,86 @@
+use oracle::{Error, Row};
+use serde::{Deserialize, Serialize};
+
+use super::movie::MovieShort;
+
+/// Type d'une caractéristique de contenu (colonne `kind` de Content_Features)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ContentFeatureKind {
+    Genre,
+    Keyword,
+    Term,
+}
+
+impl ContentFeatureKind {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "G" => ContentFeatureKind::Genre,
+            "K" => ContentFeatureKind::Keyword,
+            _ => ContentFeatureKind::Term,
+        }
+    }
+}
+
+/// Caractéristique commune à deux films et son poids dans la similarité
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SharedFeature {
+    pub kind: ContentFeatureKind,
+    pub name: String,
+    pub weight: f64,
+}
+
+impl SharedFeature {
+    /// Ligne `(tmdb_id, kind, name, weight)` : renvoie aussi le film concerné
+    pub fn from_row(row: Row) -> Result<(i32, Self), Error> {
+        let kind: String = row.get(1)?;
+        Ok((
+            row.get(0)?,
+            Self {
+                kind: ContentFeatureKind::from_code(&kind),
+                name: row.get(2)?,
+                weight: row.get(3)?,
+            },
+        ))
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<(i32, Self)> {
+        let kind: String = row.get(1)?;
+        Ok((
+            row.get(0)?,
+            Self {
+                kind: ContentFeatureKind::from_code(&kind),
+                name: row.get(2)?,
+                weight: row.get(3)?,
+            },
+        ))
+    }
+}
+
+/// Film proche par son contenu : similarité (Jaccard pondéré) et caractéristiques qui l'expliquent
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContentMatch {
+    #[serde(flatten)]
+    pub movie: MovieShort,
+    pub similarity: f64,
+    pub shared_features: Vec<SharedFeature>,
+}
+
+impl ContentMatch {
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        let similarity: f64 = row.get(6)?;
+
+        Ok(Self {
+            movie: MovieShort::from_row(row)?,
+            similarity,
+            shared_features: Vec::new(), // Lues à part dans Content_Features
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            movie: MovieShort::from_sqlite_row(row)?,
+            similarity: row.get(6)?,
+            shared_features: Vec::new(),
+        })
+    }
+}
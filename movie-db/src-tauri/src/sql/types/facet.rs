@@ -0,0 +1,85 @@
+use oracle::{Error, Row};
+use serde::{Deserialize, Serialize};
+
+/// Attribut multivalué d'un film, normalisé en table de référence et table d'association
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MovieFacet {
+    Keyword,
+    Company,
+    Country,
+    Language,
+}
+
+impl MovieFacet {
+    pub const ALL: [MovieFacet; 4] = [
+        MovieFacet::Keyword,
+        MovieFacet::Company,
+        MovieFacet::Country,
+        MovieFacet::Language,
+    ];
+
+    /// Table de référence (une ligne par valeur)
+    pub fn table(&self) -> &'static str {
+        match self {
+            MovieFacet::Keyword => "Keywords",
+            MovieFacet::Company => "Companies",
+            MovieFacet::Country => "Countries",
+            MovieFacet::Language => "Languages",
+        }
+    }
+
+    /// Table d'association avec `TMDB_movie_dataset`
+    pub fn junction(&self) -> &'static str {
+        match self {
+            MovieFacet::Keyword => "Movie_Keywords",
+            MovieFacet::Company => "Movie_Companies",
+            MovieFacet::Country => "Movie_Countries",
+            MovieFacet::Language => "Movie_Languages",
+        }
+    }
+
+    pub fn id_column(&self) -> &'static str {
+        match self {
+            MovieFacet::Keyword => "keyword_id",
+            MovieFacet::Company => "company_id",
+            MovieFacet::Country => "country_id",
+            MovieFacet::Language => "language_id",
+        }
+    }
+
+    /// Colonne brute de `TMDB_movie_dataset` d'où proviennent les valeurs
+    pub fn source_column(&self) -> &'static str {
+        match self {
+            MovieFacet::Keyword => "keywords",
+            MovieFacet::Company => "production_companies",
+            MovieFacet::Country => "production_countries",
+            MovieFacet::Language => "spoken_languages",
+        }
+    }
+}
+
+/// Valeur d'un attribut (un mot-clé, une société...) et nombre de films associés
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FacetValue {
+    pub id: i32,
+    pub name: String,
+    pub movie_count: u32,
+}
+
+impl FacetValue {
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        Ok(Self {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            movie_count: row.get(2)?,
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            movie_count: row.get(2)?,
+        })
+    }
+}
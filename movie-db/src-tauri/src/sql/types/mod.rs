@@ -1,6 +1,14 @@
 pub mod movie;
 pub mod genre;
+pub mod content;
+pub mod facet;
+pub mod movie_lens_user;
+pub mod tag_moderation;
+pub mod trash;
+pub mod audit;
 pub mod search_filter;
+pub mod search_query;
+pub mod page;
 
 pub mod sql_user;
 
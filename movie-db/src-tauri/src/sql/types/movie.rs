@@ -9,6 +9,8 @@ pub struct MovieShort {
     pub vote_average: f64,
 
     pub poster_path: Option<String>,
+    /// Score pondéré (moyenne bayésienne, table Movie_Weighted_Ratings) ; absent tant qu'il n'est pas calculé
+    pub weighted_rating: Option<f64>,
 }
 
 impl MovieShort {
@@ -22,11 +24,114 @@ impl MovieShort {
             poster_path: match row.get(4) {
                 Ok(path) => Some(path),
                 Err(_) => None
-            } // Colonne 5 : poster_path
+            }, // Colonne 5 : poster_path
+            weighted_rating: row.get(5)?, // Colonne 6 : weighted_rating
+        })
+    }
+
+    /// Équivalent de `from_row` pour le backend SQLite
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            movie_id: row.get(0)?,
+            tmdb_id: row.get(1)?,
+            title: row.get(2)?,
+            vote_average: row.get(3)?,
+            poster_path: row.get(4)?,
+            weighted_rating: row.get(5)?,
+        })
+    }
+}
+
+// Nombre de titres proposés par défaut et au maximum par l'autocomplétion
+pub const DEFAULT_COMPLETION_LIMIT: u32 = 10;
+pub const MAX_COMPLETION_LIMIT: u32 = 25;
+
+/// Titre proposé par l'autocomplétion
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TitleCompletion {
+    pub movie_id: i32,
+    pub tmdb_id: i32,
+    pub title: String,
+    pub release_year: Option<i32>,
+    pub poster_path: Option<String>,
+}
+
+impl TitleCompletion {
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        Ok(Self {
+            movie_id: row.get(0)?,
+            tmdb_id: row.get(1)?,
+            title: row.get(2)?,
+            release_year: row.get(3)?,
+            poster_path: row.get(4)?,
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            movie_id: row.get(0)?,
+            tmdb_id: row.get(1)?,
+            title: row.get(2)?,
+            release_year: row.get(3)?,
+            poster_path: row.get(4)?,
         })
     }
 }
 
+/// Résultat de la recherche plein texte : le film, son score et l'extrait trouvé
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MovieMatch {
+    #[serde(flatten)]
+    pub movie: MovieShort,
+    pub score: f64,
+    pub snippet: Option<String>,
+}
+
+// Nombre de films similaires renvoyés par défaut et au maximum (voisins conservés par film)
+pub const DEFAULT_SIMILAR_LIMIT: u32 = 10;
+pub const MAX_SIMILAR_LIMIT: u32 = movie_db_models::similarity::NEIGHBOUR_COUNT as u32;
+
+/// Film proche d'après les notes : similarité et nombre d'utilisateurs ayant noté les deux films
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimilarMovie {
+    #[serde(flatten)]
+    pub movie: MovieShort,
+    pub similarity: f64,
+    pub co_ratings: u32,
+}
+
+impl SimilarMovie {
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        let similarity: f64 = row.get(6)?;
+        let co_ratings: u32 = row.get(7)?;
+
+        Ok(Self {
+            movie: MovieShort::from_row(row)?,
+            similarity,
+            co_ratings,
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            movie: MovieShort::from_sqlite_row(row)?,
+            similarity: row.get(6)?,
+            co_ratings: row.get(7)?,
+        })
+    }
+}
+
+// Nombre de films recommandés par défaut et au maximum
+pub const DEFAULT_RECOMMENDATION_COUNT: u32 = 10;
+pub const MAX_RECOMMENDATION_COUNT: u32 = 50;
+
+/// Film recommandé à un utilisateur MovieLens, avec la note prédite par le modèle
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Recommendation {
+    #[serde(flatten)]
+    pub movie: MovieShort,
+    pub score: f64,
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MovieDetails {
@@ -43,9 +148,11 @@ pub struct MovieDetails {
 
     pub overview: String,
     pub poster_path: String,
-    pub genres: String,
+    pub genres: Vec<String>,
 
     pub keywords: String,
+    /// Version de la ligne, incrémentée à chaque modification (concurrence optimiste de `update_movie`)
+    pub version: i64,
 }
 
 impl MovieDetails {
@@ -71,12 +178,235 @@ impl MovieDetails {
             }, // Colonne 10 : backdrop_path
             overview: row.get(10)?, // Colonne 11 : overview
             poster_path: row.get(11)?, // Colonne 12 : poster_path
-            genres: row.get(12)?, // Colonne 13 : genres
-            keywords: row.get(13)?, // Colonne 14 : keywords
+            genres: Vec::new(), // Lus à part dans Movie_Genres
+            keywords: row.get::<_, Option<String>>(12)?.unwrap_or_default(), // Colonne 13 : keywords (absents des films ajoutés)
+            version: row.get(13)?, // Colonne 14 : row_version
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            movie_id: row.get(0)?,
+            tmdb_id: row.get(1)?,
+            title: row.get(2)?,
+            vote_average: row.get(3)?,
+            vote_count: row.get(4)?,
+            status: row.get(5)?,
+            release_date: row.get(6)?,
+            runtime: row.get(7)?,
+            adult: row.get::<_, i32>(8)? == 1,
+            backdrop_path: row.get(9)?,
+            overview: row.get(10)?,
+            poster_path: row.get(11)?,
+            genres: Vec::new(),
+            keywords: row.get::<_, Option<String>>(12)?.unwrap_or_default(),
+            version: row.get(13)?,
         })
     }
 }
 
+// Bornes des champs saisis par `update_movie` et `add_movie`, vérifiées aussi par les procédures UpdateMovie et AddMovie
+pub const MAX_TITLE_LENGTH: usize = 768;
+pub const MAX_OVERVIEW_LENGTH: usize = 4000;
+pub const MAX_RUNTIME: i32 = 1440;
+pub const MAX_POSTER_PATH_LENGTH: usize = 255;
+pub const MAX_GENRE_LENGTH: usize = 64;
+
+/// Modification partielle d'un film par un administrateur : les champs absents sont conservés.
+/// `version` est celle des détails lus avant la modification ; si le film a changé depuis,
+/// la modification est refusée au lieu d'écraser celle d'un autre administrateur.
+#[derive(Debug, Deserialize)]
+pub struct MovieUpdate {
+    pub version: i64,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub overview: Option<String>,
+    /// Format `AAAA-MM-JJ`
+    #[serde(default)]
+    pub release_date: Option<String>,
+    #[serde(default)]
+    pub runtime: Option<i32>,
+    #[serde(default)]
+    pub poster_path: Option<String>,
+}
+
+impl MovieUpdate {
+    /// Vérifie les champs renseignés (titre et résumé sans les espaces de début et de fin)
+    pub fn validate(mut self) -> Result<Self, String> {
+        self.title = self.title.map(|title| title.trim().to_string());
+        self.overview = self.overview.map(|overview| overview.trim().to_string());
+        self.release_date = self.release_date.map(|date| date.trim().to_string());
+        self.poster_path = self.poster_path.map(|path| path.trim().to_string());
+
+        if self.title.is_none()
+            && self.overview.is_none()
+            && self.release_date.is_none()
+            && self.runtime.is_none()
+            && self.poster_path.is_none()
+        {
+            return Err("Aucun champ à modifier".to_string());
+        }
+
+        if let Some(title) = &self.title {
+            check_title(title)?;
+        }
+        if let Some(overview) = &self.overview {
+            check_overview(overview)?;
+        }
+        if let Some(date) = &self.release_date {
+            check_release_date(date)?;
+        }
+        if let Some(runtime) = self.runtime {
+            check_runtime(runtime)?;
+        }
+        if let Some(path) = &self.poster_path {
+            check_poster_path(path)?;
+        }
+
+        Ok(self)
+    }
+}
+
+/// Nouveau film ajouté par un administrateur, avec son lien MovieLens.
+/// L'identifiant MovieLens est attribué à l'insertion (le plus grand existant + 1).
+#[derive(Debug, Deserialize)]
+pub struct NewMovie {
+    pub tmdb_id: i32,
+    /// `tt0114709` ou `0114709` ; conservé sans le préfixe `tt`, comme dans MovieLens_Links
+    pub imdb_id: String,
+    pub title: String,
+    pub overview: String,
+    /// Format `AAAA-MM-JJ`
+    pub release_date: String,
+    pub runtime: i32,
+    pub poster_path: String,
+    /// Code ISO 639-1 (`en`, `fr`...)
+    #[serde(default)]
+    pub original_language: Option<String>,
+    #[serde(default)]
+    pub adult: bool,
+    /// Noms des genres, créés s'ils n'existent pas encore
+    #[serde(default)]
+    pub genres: Vec<String>,
+}
+
+impl NewMovie {
+    /// Vérifie les champs obligatoires et normalise l'identifiant IMDb, la langue et les genres
+    pub fn validate(mut self) -> Result<Self, String> {
+        self.title = self.title.trim().to_string();
+        self.overview = self.overview.trim().to_string();
+        self.release_date = self.release_date.trim().to_string();
+        self.poster_path = self.poster_path.trim().to_string();
+
+        if self.tmdb_id <= 0 {
+            return Err(format!("Identifiant TMDB invalide : {}", self.tmdb_id));
+        }
+
+        let imdb_id = self.imdb_id.trim();
+        let digits = imdb_id.strip_prefix("tt").unwrap_or(imdb_id);
+        if !(7..=10).contains(&digits.len()) || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Identifiant IMDb invalide : {} (format attendu tt0114709)", imdb_id));
+        }
+        self.imdb_id = digits.to_string();
+
+        check_title(&self.title)?;
+        check_overview(&self.overview)?;
+        check_release_date(&self.release_date)?;
+        check_runtime(self.runtime)?;
+        check_poster_path(&self.poster_path)?;
+
+        self.original_language = self
+            .original_language
+            .map(|language| language.trim().to_lowercase())
+            .filter(|language| !language.is_empty());
+        if let Some(language) = &self.original_language {
+            if language.len() != 2 || !language.chars().all(|c| c.is_ascii_lowercase()) {
+                return Err(format!("Langue originale invalide : {} (code ISO 639-1 attendu)", language));
+            }
+        }
+
+        // Les genres sont transmis à la procédure sous forme de liste séparée par des virgules
+        let mut genres: Vec<String> = Vec::new();
+        for genre in self.genres.iter().map(|genre| genre.trim()).filter(|genre| !genre.is_empty()) {
+            if genre.contains(',') || genre.chars().count() > MAX_GENRE_LENGTH {
+                return Err(format!("Genre invalide : {} (sans virgule, {} caractères au plus)", genre, MAX_GENRE_LENGTH));
+            }
+            if !genres.iter().any(|name| name == genre) {
+                genres.push(genre.to_string());
+            }
+        }
+        self.genres = genres;
+
+        Ok(self)
+    }
+}
+
+fn check_title(title: &str) -> Result<(), String> {
+    if title.is_empty() || title.chars().count() > MAX_TITLE_LENGTH {
+        return Err(format!("Titre invalide : il doit contenir entre 1 et {} caractères", MAX_TITLE_LENGTH));
+    }
+    Ok(())
+}
+
+fn check_overview(overview: &str) -> Result<(), String> {
+    if overview.is_empty() || overview.chars().count() > MAX_OVERVIEW_LENGTH {
+        return Err(format!("Résumé invalide : il doit contenir entre 1 et {} caractères", MAX_OVERVIEW_LENGTH));
+    }
+    Ok(())
+}
+
+fn check_release_date(date: &str) -> Result<(), String> {
+    if !is_valid_date(date) {
+        return Err(format!("Date de sortie invalide : {} (format attendu AAAA-MM-JJ)", date));
+    }
+    Ok(())
+}
+
+fn check_runtime(runtime: i32) -> Result<(), String> {
+    if !(0..=MAX_RUNTIME).contains(&runtime) {
+        return Err(format!("Durée invalide : {} (entre 0 et {} minutes)", runtime, MAX_RUNTIME));
+    }
+    Ok(())
+}
+
+fn check_poster_path(path: &str) -> Result<(), String> {
+    if !path.starts_with('/') || path.len() > MAX_POSTER_PATH_LENGTH || path.contains(char::is_whitespace) {
+        return Err(format!(
+            "Chemin d'affiche invalide : {} (chemin TMDB commençant par '/', sans espace, {} caractères au plus)",
+            path, MAX_POSTER_PATH_LENGTH
+        ));
+    }
+    Ok(())
+}
+
+/// Date `AAAA-MM-JJ` existante
+fn is_valid_date(date: &str) -> bool {
+    let parts: Vec<&str> = date.split('-').collect();
+    let [year, month, day] = parts.as_slice() else {
+        return false;
+    };
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 || !date.chars().all(|c| c.is_ascii_digit() || c == '-') {
+        return false;
+    }
+
+    let (year, month, day): (u32, u32, u32) = match (year.parse(), month.parse(), day.parse()) {
+        (Ok(year), Ok(month), Ok(day)) => (year, month, day),
+        _ => return false,
+    };
+
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return false,
+    };
+
+    year >= 1 && (1..=days).contains(&day)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MovieRating {
     pub user_id: i32,
@@ -92,6 +422,94 @@ impl MovieRating {
             timestamp: row.get(2)?,
         })
     }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            user_id: row.get(0)?,
+            rating: row.get(1)?,
+            timestamp: row.get(2)?,
+        })
+    }
+}
+
+// Échelle des notes MovieLens : de 0.5 à 5 par demi-étoile
+pub const RATING_STEP: f32 = 0.5;
+pub const RATING_BUCKET_COUNT: usize = 10;
+
+/// Note saisie par un administrateur : sur l'échelle MovieLens, vérifiée aussi par les procédures
+pub fn check_rating(rating: f32) -> Result<(), String> {
+    let steps = rating / RATING_STEP;
+    if !(1.0..=RATING_BUCKET_COUNT as f32).contains(&steps) || steps.fract() != 0.0 {
+        return Err(format!("Note invalide : {} (de 0.5 à 5 par demi-étoile)", rating));
+    }
+    Ok(())
+}
+
+/// Identifiant d'utilisateur MovieLens saisi par un administrateur ; un nouvel identifiant crée l'utilisateur
+pub fn check_user_id(user_id: i32) -> Result<(), String> {
+    if user_id <= 0 {
+        return Err(format!("Identifiant utilisateur invalide : {}", user_id));
+    }
+    Ok(())
+}
+
+/// Nombre de notes égales à `rating`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RatingBucket {
+    pub rating: f32,
+    pub count: u32,
+}
+
+/// Statistiques des notes MovieLens d'un film, calculées en SQL ; vides si le film n'a aucune note
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RatingSummary {
+    pub count: u32,
+    pub mean: Option<f64>,
+    pub median: Option<f64>,
+    pub std_dev: Option<f64>,
+    /// Une entrée par demi-étoile de 0.5 à 5, y compris les notes jamais données
+    pub histogram: Vec<RatingBucket>,
+}
+
+impl RatingSummary {
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        Ok(Self {
+            count: row.get(0)?,
+            mean: row.get(1)?,
+            median: row.get(2)?,
+            std_dev: row.get(3)?,
+            histogram: Vec::new(), // Lu à part, voir `with_histogram`
+        })
+    }
+
+    /// SQLite n'a ni `MEDIAN` ni `STDDEV_POP` : la requête renvoie la variance, dont on prend la racine
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        let variance: Option<f64> = row.get(3)?;
+
+        Ok(Self {
+            count: row.get(0)?,
+            mean: row.get(1)?,
+            median: row.get(2)?,
+            std_dev: variance.map(|variance| variance.max(0.0).sqrt()),
+            histogram: Vec::new(),
+        })
+    }
+
+    /// Complète l'histogramme à partir des lignes `(rating, count)`, sans omettre les notes absentes
+    pub fn with_histogram(mut self, counts: Vec<(f32, u32)>) -> Self {
+        self.histogram = (1..=RATING_BUCKET_COUNT)
+            .map(|step| {
+                let rating = step as f32 * RATING_STEP;
+                let count = counts
+                    .iter()
+                    .filter(|(value, _)| (value - rating).abs() < RATING_STEP / 2.0)
+                    .map(|(_, count)| count)
+                    .sum();
+                RatingBucket { rating, count }
+            })
+            .collect();
+        self
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -101,6 +519,18 @@ pub struct MovieTag {
     pub timestamp: i64,
 }
 
+// Taille de la colonne tag (VARCHAR2(255), en octets)
+pub const MAX_TAG_LENGTH: usize = 255;
+
+/// Tag saisi par un administrateur, sans les espaces de début et de fin
+pub fn normalize_tag(tag: &str) -> Result<String, String> {
+    let tag = tag.trim();
+    if tag.is_empty() || tag.len() > MAX_TAG_LENGTH {
+        return Err(format!("Tag invalide : il doit contenir entre 1 et {} octets", MAX_TAG_LENGTH));
+    }
+    Ok(tag.to_string())
+}
+
 impl MovieTag {
     pub fn from_row(row: Row) -> Result<Self, Error> {
         Ok(Self {
@@ -109,6 +539,14 @@ impl MovieTag {
             timestamp: row.get(2)?,
         })
     }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            user_id: row.get(0)?,
+            tag: row.get(1)?,
+            timestamp: row.get(2)?,
+        })
+    }
 }
 
 
@@ -116,6 +554,8 @@ impl MovieTag {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Movie {
     pub details: MovieDetails,
-    pub ratings: Vec<MovieRating>,
+    pub rating_summary: RatingSummary,
+    /// Notes une à une, lues seulement sur demande (plusieurs dizaines de milliers pour les films populaires)
+    pub ratings: Option<Vec<MovieRating>>,
     pub tags: Vec<MovieTag>,
 }
\ No newline at end of file
@@ -0,0 +1,221 @@
+use oracle::{Error, Row};
+use serde::{Deserialize, Serialize};
+
+use super::page::Page;
+
+// Nombre de tags les plus utilisés renvoyés avec le profil
+pub const TOP_TAG_COUNT: u32 = 10;
+// Atténue l'affinité des genres peu notés : écart * n / (n + AFFINITY_SHRINKAGE)
+pub const AFFINITY_SHRINKAGE: f64 = 5.0;
+
+/// Note d'un utilisateur MovieLens ; `title` est absent si le film n'a pas de correspondance TMDB
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserRating {
+    pub movie_id: i32,
+    pub title: Option<String>,
+    pub rating: f32,
+    pub timestamp: i64,
+}
+
+impl UserRating {
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        Ok(Self {
+            movie_id: row.get(0)?,
+            title: row.get(1)?,
+            rating: row.get(2)?,
+            timestamp: row.get(3)?,
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            movie_id: row.get(0)?,
+            title: row.get(1)?,
+            rating: row.get(2)?,
+            timestamp: row.get(3)?,
+        })
+    }
+}
+
+/// Tag posé par un utilisateur MovieLens
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserTag {
+    pub movie_id: i32,
+    pub title: Option<String>,
+    pub tag: String,
+    pub timestamp: i64,
+}
+
+impl UserTag {
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        Ok(Self {
+            movie_id: row.get(0)?,
+            title: row.get(1)?,
+            tag: row.get(2)?,
+            timestamp: row.get(3)?,
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            movie_id: row.get(0)?,
+            title: row.get(1)?,
+            tag: row.get(2)?,
+            timestamp: row.get(3)?,
+        })
+    }
+}
+
+/// Préférence d'un utilisateur pour un genre : écart entre sa note moyenne sur le genre
+/// et sa note moyenne générale, atténué quand il a noté peu de films du genre
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GenreAffinity {
+    pub genre_id: i32,
+    pub name: String,
+    pub rating_count: u32,
+    pub average_rating: f64,
+    pub affinity: f64,
+}
+
+impl GenreAffinity {
+    /// Ligne `(genre_id, name, rating_count, average_rating)` ; l'affinité est calculée par `rank`
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        Ok(Self {
+            genre_id: row.get(0)?,
+            name: row.get(1)?,
+            rating_count: row.get(2)?,
+            average_rating: row.get(3)?,
+            affinity: 0.0,
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            genre_id: row.get(0)?,
+            name: row.get(1)?,
+            rating_count: row.get(2)?,
+            average_rating: row.get(3)?,
+            affinity: 0.0,
+        })
+    }
+
+    /// Calcule l'affinité de chaque genre et trie les genres préférés en premier
+    pub fn rank(mut genres: Vec<Self>, user_average: f64) -> Vec<Self> {
+        for genre in &mut genres {
+            let count = genre.rating_count as f64;
+            genre.affinity = (genre.average_rating - user_average) * count / (count + AFFINITY_SHRINKAGE);
+        }
+
+        genres.sort_by(|a, b| b.affinity.total_cmp(&a.affinity).then(a.genre_id.cmp(&b.genre_id)));
+        genres
+    }
+}
+
+/// Tag et nombre de fois où l'utilisateur l'a posé
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TagUsage {
+    pub tag: String,
+    pub count: u32,
+}
+
+impl TagUsage {
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        Ok(Self {
+            tag: row.get(0)?,
+            count: row.get(1)?,
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            tag: row.get(0)?,
+            count: row.get(1)?,
+        })
+    }
+}
+
+/// Nombre de notes et de tags d'un utilisateur, avec leurs dates extrêmes
+#[derive(Debug)]
+pub struct UserSummary {
+    pub user_id: i32,
+    pub rating_count: u32,
+    pub average_rating: Option<f64>,
+    pub first_rating: Option<i64>,
+    pub last_rating: Option<i64>,
+    pub tag_count: u32,
+    pub first_tag: Option<i64>,
+    pub last_tag: Option<i64>,
+}
+
+impl UserSummary {
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        Ok(Self {
+            user_id: row.get(0)?,
+            rating_count: row.get(1)?,
+            average_rating: row.get(2)?,
+            first_rating: row.get(3)?,
+            last_rating: row.get(4)?,
+            tag_count: row.get(5)?,
+            first_tag: row.get(6)?,
+            last_tag: row.get(7)?,
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            user_id: row.get(0)?,
+            rating_count: row.get(1)?,
+            average_rating: row.get(2)?,
+            first_rating: row.get(3)?,
+            last_rating: row.get(4)?,
+            tag_count: row.get(5)?,
+            first_tag: row.get(6)?,
+            last_tag: row.get(7)?,
+        })
+    }
+
+    pub fn into_profile(
+        self,
+        genres: Vec<GenreAffinity>,
+        top_tags: Vec<TagUsage>,
+        ratings: Page<UserRating>,
+        tags: Page<UserTag>,
+    ) -> MovieLensUserProfile {
+        let first_activity = [self.first_rating, self.first_tag].into_iter().flatten().min();
+        let last_activity = [self.last_rating, self.last_tag].into_iter().flatten().max();
+
+        let genre_affinities = match self.average_rating {
+            Some(average) => GenreAffinity::rank(genres, average),
+            None => Vec::new(),
+        };
+
+        MovieLensUserProfile {
+            user_id: self.user_id,
+            rating_count: self.rating_count,
+            tag_count: self.tag_count,
+            average_rating: self.average_rating,
+            first_activity,
+            last_activity,
+            genre_affinities,
+            top_tags,
+            ratings,
+            tags,
+        }
+    }
+}
+
+/// Profil d'un utilisateur MovieLens, consulté avant de supprimer un compte suspect
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MovieLensUserProfile {
+    pub user_id: i32,
+    pub rating_count: u32,
+    pub tag_count: u32,
+    pub average_rating: Option<f64>,
+    /// Horodatages Unix de la première et de la dernière note ou tag
+    pub first_activity: Option<i64>,
+    pub last_activity: Option<i64>,
+    pub genre_affinities: Vec<GenreAffinity>,
+    pub top_tags: Vec<TagUsage>,
+    pub ratings: Page<UserRating>,
+    pub tags: Page<UserTag>,
+}
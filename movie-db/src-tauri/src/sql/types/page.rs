@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+// Taille de page par défaut et taille maximale acceptée
+pub const DEFAULT_PAGE_SIZE: u32 = 50;
+pub const MAX_PAGE_SIZE: u32 = 200;
+
+/// Demande de page : `cursor` (pagination par clé) est prioritaire sur `offset`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PageRequest {
+    pub size: Option<u32>,
+    pub offset: Option<u32>,
+    pub cursor: Option<String>,
+}
+
+impl PageRequest {
+    pub fn size(&self) -> u32 {
+        self.size.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+/// Page de résultats renvoyée au frontend
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u32,
+    pub next_cursor: Option<String>,
+    /// Titres proches proposés quand la recherche ne renvoie aucun film
+    #[serde(default)]
+    pub suggestions: Vec<String>,
+}
+
+/// Valeur d'une clé de tri du dernier élément d'une page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SortValue {
+    Number(f64),
+    Text(String),
+}
+
+/// Position après le dernier élément d'une page ; opaque pour le frontend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageCursor {
+    pub sort: String,
+    /// Une valeur par clé de tri, dans l'ordre des clés
+    pub values: Vec<SortValue>,
+    /// Identifiant qui départage les égalités : film, utilisateur, valeur d'attribut, suppression ou entrée du journal
+    pub tiebreak_id: i32,
+}
+
+impl PageCursor {
+    pub fn encode(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, String> {
+        serde_json::from_str(cursor).map_err(|_| "Curseur de pagination invalide".to_string())
+    }
+
+    /// Décode le curseur d'une demande de page et vérifie qu'il a été produit par le même tri
+    pub fn decode_for(cursor: Option<&str>, sort_name: &str, key_count: usize) -> Result<Option<Self>, String> {
+        let cursor = match cursor {
+            Some(cursor) => Self::decode(cursor)?,
+            None => return Ok(None),
+        };
+
+        if cursor.sort != sort_name || cursor.values.len() != key_count {
+            return Err("Le curseur ne correspond pas au tri demandé".to_string());
+        }
+        Ok(Some(cursor))
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+/// Tris prédéfinis proposés par l'interface, équivalents à une liste de `SortOrder`
 #[derive(Debug, Serialize, Deserialize)]
 pub enum SearchFilter {
     Alphabetical,
@@ -10,14 +11,105 @@ pub enum SearchFilter {
     Unknow
 }
 
+/// Colonne de `MovieShortView` sur laquelle une recherche peut être triée
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SortField {
+    Title,
+    Popularity,
+    ReleaseDate,
+    VoteAverage,
+    VoteCount,
+    Runtime,
+    Revenue,
+    WeightedRating,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+/// Critère de tri d'une recherche ; les critères suivants départagent les égalités
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SortOrder {
+    pub field: SortField,
+    pub direction: SortDirection,
+}
+
+/// Colonne de tri de `MovieShortView` ; `movie_id` départage toujours les égalités
+#[derive(Debug, Clone, Copy)]
+pub struct SortKey {
+    pub name: &'static str,
+    pub column: &'static str,
+    pub descending: bool,
+    pub numeric: bool,
+}
+
+impl SortField {
+    pub fn key(&self, direction: SortDirection) -> SortKey {
+        let (name, column, numeric) = match self {
+            SortField::Title => ("title", "title", false),
+            SortField::Popularity => ("popularity", "popularity", true),
+            SortField::ReleaseDate => ("release_date", "release_date", false),
+            SortField::VoteAverage => ("vote_average", "vote_average", true),
+            SortField::VoteCount => ("vote_count", "vote_count", true),
+            SortField::Runtime => ("runtime", "runtime", true),
+            SortField::Revenue => ("revenue", "revenue", true),
+            SortField::WeightedRating => ("weighted_rating", "weighted_rating", true),
+        };
+
+        SortKey {
+            name,
+            column,
+            descending: direction == SortDirection::Desc,
+            numeric,
+        }
+    }
+}
+
+impl SortOrder {
+    pub fn new(field: SortField, direction: SortDirection) -> Self {
+        Self { field, direction }
+    }
+
+    pub fn key(&self) -> SortKey {
+        self.field.key(self.direction)
+    }
+}
+
+/// Colonne de tri des notes ou des tags d'un film
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ActivitySortField {
+    Timestamp,
+    Rating,
+    User,
+}
+
+/// Tri des notes ou des tags d'un film ; par défaut les plus récents d'abord
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ActivitySort {
+    pub field: ActivitySortField,
+    pub direction: SortDirection,
+}
+
+impl Default for ActivitySort {
+    fn default() -> Self {
+        Self {
+            field: ActivitySortField::Timestamp,
+            direction: SortDirection::Desc,
+        }
+    }
+}
+
 impl SearchFilter {
-    pub fn to_view_name(&self) -> &'static str {
+    pub fn sort_orders(&self) -> Vec<SortOrder> {
         match self {
-            SearchFilter::Alphabetical => "MovieShortView_Alphabetical",
-            SearchFilter::Popular => "MovieShortView_ByPopularity",
-            SearchFilter::Latest => "MovieShortView_ByReleaseDate",
-            SearchFilter::TopRated => "MovieShortView_ByRating",
-            _ => "MovieShortView",
+            SearchFilter::Alphabetical => vec![SortOrder::new(SortField::Title, SortDirection::Asc)],
+            SearchFilter::Popular => vec![SortOrder::new(SortField::Popularity, SortDirection::Desc)],
+            SearchFilter::Latest => vec![SortOrder::new(SortField::ReleaseDate, SortDirection::Desc)],
+            SearchFilter::TopRated => vec![SortOrder::new(SortField::WeightedRating, SortDirection::Desc)],
+            _ => Vec::new(),
         }
     }
     /*fn from(&self, str: &str ) -> Self {
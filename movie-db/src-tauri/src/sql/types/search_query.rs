@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+use super::facet::MovieFacet;
+
+/// Un film doit avoir au moins un (`Any`) ou tous (`All`) les genres demandés
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum GenreMatch {
+    #[default]
+    Any,
+    All,
+}
+
+/// Intervalle inclusif, chaque borne est facultative
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ValueRange<T> {
+    pub min: Option<T>,
+    pub max: Option<T>,
+}
+
+/// Critères de recherche avancée ; un champ absent ne filtre pas
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchQuery {
+    pub title: String,
+    /// Identifiants des genres renvoyés par `get_genres`
+    pub genres: Vec<i32>,
+    pub genre_match: GenreMatch,
+
+    /// Identifiants renvoyés par `get_facet_values` ; un film doit en avoir au moins un
+    pub keywords: Vec<i32>,
+    pub companies: Vec<i32>,
+    pub countries: Vec<i32>,
+    pub languages: Vec<i32>,
+
+    pub release_year: ValueRange<i32>,
+    pub runtime: ValueRange<i32>,
+
+    pub min_vote_count: Option<i32>,
+    pub vote_average: ValueRange<f64>,
+
+    pub original_language: Option<String>,
+    pub status: Option<String>,
+    pub include_adult: bool,
+}
+
+impl Default for SearchQuery {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            genres: Vec::new(),
+            genre_match: GenreMatch::Any,
+            keywords: Vec::new(),
+            companies: Vec::new(),
+            countries: Vec::new(),
+            languages: Vec::new(),
+            release_year: ValueRange::default(),
+            runtime: ValueRange::default(),
+            min_vote_count: None,
+            vote_average: ValueRange::default(),
+            original_language: None,
+            status: None,
+            include_adult: true,
+        }
+    }
+}
+
+impl SearchQuery {
+    pub fn facet_ids(&self, facet: MovieFacet) -> &[i32] {
+        match facet {
+            MovieFacet::Keyword => &self.keywords,
+            MovieFacet::Company => &self.companies,
+            MovieFacet::Country => &self.countries,
+            MovieFacet::Language => &self.languages,
+        }
+    }
+
+    pub fn facet_ids_mut(&mut self, facet: MovieFacet) -> &mut Vec<i32> {
+        match facet {
+            MovieFacet::Keyword => &mut self.keywords,
+            MovieFacet::Company => &mut self.companies,
+            MovieFacet::Country => &mut self.countries,
+            MovieFacet::Language => &mut self.languages,
+        }
+    }
+}
+
+/// Filtres des notes ou des tags d'un film ; un champ absent ne filtre pas
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActivityFilter {
+    /// Notes seulement
+    pub rating: ValueRange<f64>,
+    /// Tags seulement : le tag contient ce texte, sans tenir compte de la casse
+    pub tag: Option<String>,
+    /// Horodatages Unix (secondes), bornes incluses
+    pub timestamp: ValueRange<i64>,
+}
@@ -24,4 +24,12 @@ impl SqlUser {
             created_at: row.get(2)?,
         })
     }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            username: row.get(0)?,
+            is_admin: row.get::<_, i32>(1)? == 1,
+            created_at: row.get(2)?,
+        })
+    }
 }
\ No newline at end of file
@@ -41,6 +41,14 @@ impl TopUserItem {
             num_tags: row.get(2)?,
         })
     }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<TopUserItem> {
+        Ok(TopUserItem {
+            user_id: row.get(0)?,
+            num_ratings: row.get(1)?,
+            num_tags: row.get(2)?,
+        })
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -60,4 +68,13 @@ impl TopMovieProfit {
             profit: row.get(3)?,
         })
     }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<TopMovieProfit> {
+        Ok(TopMovieProfit {
+            movie_id: row.get(0)?,
+            title: row.get(1)?,
+            poster_path: row.get(2)?,
+            profit: row.get(3)?,
+        })
+    }
 }
\ No newline at end of file
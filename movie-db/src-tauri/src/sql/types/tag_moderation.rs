@@ -0,0 +1,129 @@
+use std::fmt;
+
+use oracle::{Error, Row};
+use serde::{Deserialize, Serialize};
+
+use super::movie_lens_user::TagUsage;
+
+// Nombre d'exemples renvoyés avec un aperçu
+pub const DEFAULT_MODERATION_SAMPLE_SIZE: u32 = 20;
+pub const MAX_MODERATION_SAMPLE_SIZE: u32 = 200;
+// Nombre de textes de tag les plus touchés renvoyés avec le résumé
+pub const MODERATION_TOP_TAG_COUNT: usize = 10;
+// Bornes du motif, pour garder une expression compilable rapidement
+pub const MAX_MODERATION_PATTERN_LENGTH: usize = 1000;
+pub const MAX_MODERATION_WORDS: usize = 500;
+
+/// Motif recherché dans le texte des tags
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum TagPattern {
+    /// Sous-chaîne, sans tenir compte de la casse
+    Substring { text: String },
+    /// Expression régulière (syntaxe du crate `regex`), sensible à la casse sauf avec `(?i)`
+    Regex { pattern: String },
+    /// Mots entiers d'une liste, sans tenir compte de la casse
+    Words { words: Vec<String> },
+}
+
+/// Traitement des tags trouvés
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum TagModerationAction {
+    Delete,
+    /// Remplace chaque occurrence du motif ; `$1`, `$nom`... désignent les groupes d'une expression régulière.
+    /// Un tag vide après remplacement est supprimé
+    Rewrite { replacement: String },
+}
+
+/// Règle de modération : un motif et son traitement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagModeration {
+    pub pattern: TagPattern,
+    pub action: TagModerationAction,
+}
+
+/// Tag de MovieLens_Tags, identifié par (movie_id, user_id, timestamp) et son texte
+pub struct TagRow {
+    pub movie_id: i32,
+    pub user_id: i32,
+    pub timestamp: i64,
+    pub tag: String,
+}
+
+impl TagRow {
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        Ok(Self {
+            movie_id: row.get(0)?,
+            user_id: row.get(1)?,
+            timestamp: row.get(2)?,
+            tag: row.get(3)?,
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            movie_id: row.get(0)?,
+            user_id: row.get(1)?,
+            timestamp: row.get(2)?,
+            tag: row.get(3)?,
+        })
+    }
+}
+
+/// Tag trouvé par une règle ; `new_tag` est absent si le tag est supprimé
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagModerationSample {
+    pub movie_id: i32,
+    pub user_id: i32,
+    pub timestamp: i64,
+    pub tag: String,
+    pub new_tag: Option<String>,
+}
+
+/// Comptes des tags trouvés par une règle
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TagModerationSummary {
+    pub matched_count: u32,
+    pub deleted_count: u32,
+    pub rewritten_count: u32,
+    pub distinct_tags: u32,
+    pub user_count: u32,
+    pub movie_count: u32,
+    /// Textes de tag les plus touchés, du plus fréquent au moins fréquent
+    pub top_tags: Vec<TagUsage>,
+}
+
+/// Aperçu d'une règle, sans modification de la base
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TagModerationPreview {
+    #[serde(flatten)]
+    pub summary: TagModerationSummary,
+    pub samples: Vec<TagModerationSample>,
+}
+
+/// Rapport d'une modération appliquée
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TagModerationReport {
+    #[serde(flatten)]
+    pub summary: TagModerationSummary,
+    /// Timestamp Unix du début de la modération
+    pub started_at: i64,
+    pub duration_ms: u64,
+}
+
+impl fmt::Display for TagModerationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} tags trouvés ({} textes distincts, {} utilisateurs, {} films) : {} supprimés, {} réécrits en {} ms",
+            self.summary.matched_count,
+            self.summary.distinct_tags,
+            self.summary.user_count,
+            self.summary.movie_count,
+            self.summary.deleted_count,
+            self.summary.rewritten_count,
+            self.duration_ms
+        )
+    }
+}
@@ -0,0 +1,88 @@
+use oracle::{Error, Row};
+use serde::{Deserialize, Serialize};
+
+/// Élément supprimé : utilisateur MovieLens, lien MovieLens <-> TMDB ou tag
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TrashKind {
+    User,
+    Movie,
+    Tag,
+}
+
+impl TrashKind {
+    /// Valeur de la colonne `kind` de MovieLens_Deletions
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrashKind::User => "User",
+            TrashKind::Movie => "Movie",
+            TrashKind::Tag => "Tag",
+        }
+    }
+
+    pub fn parse(kind: &str) -> Self {
+        match kind {
+            "User" => TrashKind::User,
+            "Movie" => TrashKind::Movie,
+            _ => TrashKind::Tag,
+        }
+    }
+}
+
+/// Élément de la corbeille, avec le nombre de notes et de tags supprimés en cascade.
+/// `title` est le titre TMDB du film supprimé ou tagué ; `tag` le texte d'un tag supprimé
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TrashItem {
+    pub deletion_id: i32,
+    pub kind: TrashKind,
+    pub user_id: Option<i32>,
+    pub movie_id: Option<i32>,
+    pub title: Option<String>,
+    pub tag: Option<String>,
+    /// Utilisateur SQL à l'origine de la suppression ; absent pour une suppression faite hors de l'application en SQLite
+    pub deleted_by: Option<String>,
+    pub deleted_at: i64,
+    pub rating_count: u32,
+    pub tag_count: u32,
+}
+
+impl TrashItem {
+    pub fn from_row(row: Row) -> Result<Self, Error> {
+        let kind: String = row.get(1)?;
+        Ok(Self {
+            deletion_id: row.get(0)?,
+            kind: TrashKind::parse(&kind),
+            user_id: row.get(2)?,
+            movie_id: row.get(3)?,
+            title: row.get(4)?,
+            tag: row.get(5)?,
+            deleted_by: row.get(6)?,
+            deleted_at: row.get(7)?,
+            rating_count: row.get(8)?,
+            tag_count: row.get(9)?,
+        })
+    }
+
+    pub fn from_sqlite_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        let kind: String = row.get(1)?;
+        Ok(Self {
+            deletion_id: row.get(0)?,
+            kind: TrashKind::parse(&kind),
+            user_id: row.get(2)?,
+            movie_id: row.get(3)?,
+            title: row.get(4)?,
+            tag: row.get(5)?,
+            deleted_by: row.get(6)?,
+            deleted_at: row.get(7)?,
+            rating_count: row.get(8)?,
+            tag_count: row.get(9)?,
+        })
+    }
+}
+
+/// Résultat d'une restauration : les notes et tags déjà présents de nouveau ne sont pas dupliqués
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TrashRestore {
+    pub deletion_id: i32,
+    pub restored_ratings: u32,
+    pub restored_tags: u32,
+}
@@ -0,0 +1,40 @@
+import type { ValueRange } from "./search";
+
+type AdminAction =
+    | "CreateSqlUser"
+    | "DeleteSqlUser"
+    | "DeleteMovieLensUser"
+    | "DeleteMovieLensTag"
+    | "AddMovie"
+    | "UpdateMovie"
+    | "AddMovieLensRating"
+    | "UpdateMovieLensRating"
+    | "AddMovieLensTag"
+    | "RenameMovieLensTag"
+    | "ApplyTagModeration"
+    | "RestoreTrashItem";
+
+// target : élément modifié (movie:10, movielens_user:42, sql_user:BOB, trash:5), null pour une modération
+// parameters : autres arguments sous la forme "clé=valeur, ..." ; aucun mot de passe n'est journalisé
+interface AuditEntry {
+    audit_id: number;
+    acted_by: string;
+    acted_at: number;
+    action: AdminAction;
+    target: string | null;
+    parameters: string | null;
+}
+
+// acted_by ignore la casse ; acted_at : timestamps Unix, bornes incluses
+interface AuditFilter {
+    action?: AdminAction | null;
+    acted_by?: string | null;
+    target?: string | null;
+    acted_at?: ValueRange;
+}
+
+export {
+    type AdminAction,
+    type AuditEntry,
+    type AuditFilter,
+}
@@ -1,27 +1,85 @@
 import { invoke } from "@tauri-apps/api/core";
-import type { Movie, MovieShort } from "./movie";
-import { filter_to_string, type SearchFilter } from "./search";
+import type { ContentMatch, Movie, MovieDetails, MovieMatch, MovieRating, MovieTag, MovieShort, MovieUpdate, NewMovie, Recommendation, SimilarMovie, TitleCompletion } from "./movie";
+import { filter_to_string, type ActivityFilter, type ActivitySort, type FacetValue, type Genre, type MovieFacet, type SearchFilter, type SearchQuery, type SortOrder } from "./search";
 import type { Stats } from "./stats";
 import type { SqlUserList, SqlUserProps } from "./sql_user";
+import type { Page, PageRequest } from "./page";
+import type { MovieLensUserProfile } from "./movie_lens_user";
+import type { TagModeration, TagModerationPreview, TagModerationReport } from "./tag_moderation";
+import type { TrashItem, TrashKind, TrashRestore } from "./trash";
+import type { AuditEntry, AuditFilter } from "./audit";
 
 
-async function getAllMovies(genre: string, query: string, filter: SearchFilter) {
+// `sort` remplace le tri prédéfini `filter` s'il est renseigné
+async function getAllMovies(query: SearchQuery, filter: SearchFilter, page: PageRequest = {}, sort: SortOrder[] | null = null) {
     console.log(`Search args: {
-        genre: ${genre},
-        query: ${query},
-        filter: ${filter_to_string(filter)}
+        query: ${JSON.stringify(query)},
+        filter: ${filter_to_string(filter)},
+        sort: ${JSON.stringify(sort)},
+        page: ${JSON.stringify(page)}
     }`);
     
-    return await invoke<MovieShort[]>("get_all_movies", {
-        genre,
+    return await invoke<Page<MovieShort>>("get_all_movies", {
         query,
         filter: filter_to_string(filter),
+        sort,
+        page,
     });
 }
 
-async function getMovie(movie_id: string) {
+async function searchMoviesFullText(query: SearchQuery, page: PageRequest = {}) {
+    return await invoke<Page<MovieMatch>>("search_movies_full_text", { query, page });
+}
+
+async function searchMoviesFuzzy(query: SearchQuery, page: PageRequest = {}) {
+    return await invoke<Page<MovieMatch>>("search_movies_fuzzy", { query, page });
+}
+
+async function autocompleteTitles(prefix: string, limit?: number) {
+    return await invoke<TitleCompletion[]>("autocomplete_titles", { prefix, limit });
+}
+
+async function getMovie(movie_id: string, includeRatings: boolean = false) {
     const movie_id_number = parseInt(movie_id);
-    return await invoke<Movie>("get_movie", { id: movie_id_number });
+    return await invoke<Movie>("get_movie", { id: movie_id_number, includeRatings });
+}
+
+async function getMovieRatingsPage(movieId: number, filter: ActivityFilter = {}, sort: ActivitySort | null = null, page: PageRequest = {}) {
+    return await invoke<Page<MovieRating>>("get_movie_ratings_page", { movieId, filter, sort, page });
+}
+
+async function getMovieTagsPage(movieId: number, filter: ActivityFilter = {}, sort: ActivitySort | null = null, page: PageRequest = {}) {
+    return await invoke<Page<MovieTag>>("get_movie_tags_page", { movieId, filter, sort, page });
+}
+
+async function getSimilarMoviesByRatings(movie_id: number, limit?: number) {
+    return await invoke<SimilarMovie[]>("get_similar_movies_by_ratings", { movieId: movie_id, limit });
+}
+
+async function getSimilarMoviesByContent(movie_id: number, limit?: number) {
+    return await invoke<ContentMatch[]>("get_similar_movies_by_content", { movieId: movie_id, limit });
+}
+
+async function getRecommendationsForUser(user_id: number, n?: number) {
+    return await invoke<Recommendation[]>("get_recommendations_for_user", { userId: user_id, n });
+}
+
+async function getGenres() {
+    return await invoke<Genre[]>("get_genres");
+}
+
+async function getFacetValues(facet: MovieFacet, name: string | null = null, page: PageRequest = {}) {
+    return await invoke<Page<FacetValue>>("get_facet_values", { facet, name, page });
+}
+
+async function getMoviesByFacet(facet: MovieFacet, id: number, filter: SearchFilter, page: PageRequest = {}, sort: SortOrder[] | null = null) {
+    return await invoke<Page<MovieShort>>("get_movies_by_facet", {
+        facet,
+        id,
+        filter: filter_to_string(filter),
+        sort,
+        page,
+    });
 }
 
 async function getStats(): Promise<Stats> {
@@ -46,6 +104,48 @@ async function deleteSqlUser(username: string){
     return await invoke("delete_sql_user", { username });
 }
 
+async function getMovieLensUser(userId: number, ratingsPage: PageRequest = {}, tagsPage: PageRequest = {}) {
+    return await invoke<MovieLensUserProfile | null>("get_movie_lens_user", { userId, ratingsPage, tagsPage });
+}
+
+// Rejeté si l'identifiant TMDB ou IMDb existe déjà
+async function addMovie(movie: NewMovie) {
+    return await invoke<MovieDetails>("add_movie", { movie });
+}
+
+// Rejetée si le film a été modifié depuis la lecture de `update.version`
+async function updateMovie(movieId: number, update: MovieUpdate) {
+    return await invoke<MovieDetails>("update_movie", { movieId, update });
+}
+
+// L'utilisateur MovieLens est créé s'il n'existe pas
+async function addMovieLensRating(movieId: number, userId: number, rating: number) {
+    return await invoke<MovieRating>("add_movie_lens_rating", { movieId, userId, rating });
+}
+
+async function updateMovieLensRating(movieId: number, userId: number, rating: number) {
+    return await invoke<MovieRating>("update_movie_lens_rating", { movieId, userId, rating });
+}
+
+// L'utilisateur MovieLens est créé s'il n'existe pas
+async function addMovieLensTag(movieId: number, userId: number, tag: string) {
+    return await invoke<MovieTag>("add_movie_lens_tag", { movieId, userId, tag });
+}
+
+// Le tag garde son timestamp, qui l'identifie
+async function renameMovieLensTag(movieId: number, userId: number, timestamp: number, tag: string) {
+    return await invoke<MovieTag>("rename_movie_lens_tag", { movieId, userId, timestamp, tag });
+}
+
+async function previewTagModeration(moderation: TagModeration, sampleSize: number | null = null) {
+    return await invoke<TagModerationPreview>("preview_tag_moderation", { moderation, sampleSize });
+}
+
+// expectedMatches : matched_count de l'aperçu ; la modération est refusée si les tags ont changé depuis
+async function applyTagModeration(moderation: TagModeration, expectedMatches: number | null = null) {
+    return await invoke<TagModerationReport>("apply_tag_moderation", { moderation, expectedMatches });
+}
+
 async function deleteMovieLensUser(id: number){
     return await invoke("delete_movie_lens_user", { id });
 }
@@ -54,9 +154,33 @@ async function deleteMovieLensTag(movieId: number, userId: number, timestamp: nu
     return await invoke("delete_movie_lens_tag", { movieId, userId, timestamp });
 }
 
+// kind null : tous les éléments de la corbeille
+async function getTrash(kind: TrashKind | null = null, page: PageRequest = {}) {
+    return await invoke<Page<TrashItem>>("get_trash", { kind, page });
+}
+
+async function restoreTrashItem(deletionId: number) {
+    return await invoke<TrashRestore>("restore_trash_item", { deletionId });
+}
+
+async function getAuditLog(filter: AuditFilter = {}, page: PageRequest = {}) {
+    return await invoke<Page<AuditEntry>>("get_audit_log", { filter, page });
+}
+
 export {
     getAllMovies,
+    searchMoviesFullText,
+    searchMoviesFuzzy,
+    autocompleteTitles,
     getMovie,
+    getMovieRatingsPage,
+    getMovieTagsPage,
+    getSimilarMoviesByRatings,
+    getSimilarMoviesByContent,
+    getRecommendationsForUser,
+    getGenres,
+    getFacetValues,
+    getMoviesByFacet,
 
     getStats,
 
@@ -64,6 +188,18 @@ export {
     getSqlUsers,
     deleteSqlUser,
 
+    getMovieLensUser,
+    addMovie,
+    updateMovie,
+    addMovieLensRating,
+    updateMovieLensRating,
+    addMovieLensTag,
+    renameMovieLensTag,
+    previewTagModeration,
+    applyTagModeration,
     deleteMovieLensUser,
     deleteMovieLensTag,
+    getTrash,
+    restoreTrashItem,
+    getAuditLog,
 }
\ No newline at end of file
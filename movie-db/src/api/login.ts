@@ -11,8 +11,8 @@ interface SqlUserChoice {
     username?: string;
 }
 
-async function login(user: SqlUserCredentials) {
-    return invoke("login_user", { user });
+async function login(user: SqlUserCredentials, profile: string) {
+    return invoke("login_user", { user, profile });
 }
 
 async function logout() {
@@ -5,6 +5,47 @@ interface MovieShort {
     vote_average: number;
     poster_path: string;
     popularity: number;
+    // Score pondéré (moyenne bayésienne) utilisé par le classement « Top Rated »
+    weighted_rating: number | null;
+}
+
+// Résultat de la recherche plein texte
+interface MovieMatch extends MovieShort {
+    score: number;
+    snippet: string | null;
+}
+
+// Titre proposé par l'autocomplétion
+interface TitleCompletion {
+    movie_id: number;
+    tmdb_id: number;
+    title: string;
+    release_year: number | null;
+    poster_path: string | null;
+}
+
+// Film proche d'après les notes MovieLens
+interface SimilarMovie extends MovieShort {
+    similarity: number;
+    co_ratings: number;
+}
+
+// Caractéristique commune à deux films : genre, mot-clé ou terme du résumé
+interface SharedFeature {
+    kind: "Genre" | "Keyword" | "Term";
+    name: string;
+    weight: number;
+}
+
+// Film proche par son contenu, avec les caractéristiques qui expliquent le rapprochement
+interface ContentMatch extends MovieShort {
+    similarity: number;
+    shared_features: SharedFeature[];
+}
+
+// Film recommandé à un utilisateur MovieLens, avec la note prédite
+interface Recommendation extends MovieShort {
+    score: number;
 }
 
 interface MovieDetails extends MovieShort {
@@ -16,8 +57,34 @@ interface MovieDetails extends MovieShort {
     adult: boolean,
     backdrop_path: string,
     overview: string,
-    genres: string,
+    genres: string[],
     keywords: string,
+    // Version de la ligne, à renvoyer dans MovieUpdate
+    version: number,
+}
+
+// Nouveau film ajouté par un administrateur ; imdb_id au format "tt0114709"
+interface NewMovie {
+    tmdb_id: number;
+    imdb_id: string;
+    title: string;
+    overview: string;
+    release_date: string;
+    runtime: number;
+    poster_path: string;
+    original_language?: string;
+    adult?: boolean;
+    genres?: string[];
+}
+
+// Modification d'un film par un administrateur : seuls les champs renseignés sont modifiés
+interface MovieUpdate {
+    version: number;
+    title?: string;
+    overview?: string;
+    release_date?: string;
+    runtime?: number;
+    poster_path?: string;
 }
 
 interface MovieRating {
@@ -32,9 +99,26 @@ interface MovieTag {
     timestamp: number;
 }
 
+// Nombre de notes MovieLens égales à rating (de 0.5 à 5 par demi-étoile)
+interface RatingBucket {
+    rating: number;
+    count: number;
+}
+
+// Statistiques des notes MovieLens d'un film ; null si le film n'a aucune note
+interface RatingSummary {
+    count: number;
+    mean: number | null;
+    median: number | null;
+    std_dev: number | null;
+    histogram: RatingBucket[];
+}
+
 interface Movie {
     details: MovieDetails;
-    ratings: MovieRating[];
+    rating_summary: RatingSummary;
+    // Présentes seulement si demandées à getMovie
+    ratings: MovieRating[] | null;
     tags: MovieTag[];
 }
 
@@ -42,17 +126,38 @@ function getPosterPath(path: string, width: number = 500) {
     return `https://image.tmdb.org/t/p/w${width}/${path}`;
 }
 
+// Découpe un extrait en segments, les termes trouvés sont entourés de <mark>
+function splitSnippet(snippet: string) {
+    return snippet
+        .split(/(<mark>.*?<\/mark>)/)
+        .filter((part) => part.length > 0)
+        .map((part) => part.startsWith("<mark>")
+            ? { text: part.slice(6, -7), match: true }
+            : { text: part, match: false });
+}
+
 function getBackdropPath(path: string) {
     return `https://image.tmdb.org/t/p/w1280/${path}`;
 }
 
 export {
     type MovieDetails,
+    type MovieUpdate,
+    type NewMovie,
     type MovieShort,
+    type MovieMatch,
+    type TitleCompletion,
+    type SimilarMovie,
+    type SharedFeature,
+    type ContentMatch,
+    type Recommendation,
     type MovieRating,
+    type RatingBucket,
+    type RatingSummary,
     type MovieTag,
     type Movie,
 
     getPosterPath,
     getBackdropPath,
+    splitSnippet,
 }
\ No newline at end of file
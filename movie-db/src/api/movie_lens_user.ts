@@ -0,0 +1,51 @@
+import type { Page } from "./page";
+
+// Note d'un utilisateur MovieLens ; title est null si le film n'a pas de correspondance TMDB
+interface UserRating {
+    movie_id: number;
+    title: string | null;
+    rating: number;
+    timestamp: number;
+}
+
+interface UserTag {
+    movie_id: number;
+    title: string | null;
+    tag: string;
+    timestamp: number;
+}
+
+// Écart entre la note moyenne de l'utilisateur sur le genre et sa note moyenne générale
+interface GenreAffinity {
+    genre_id: number;
+    name: string;
+    rating_count: number;
+    average_rating: number;
+    affinity: number;
+}
+
+interface TagUsage {
+    tag: string;
+    count: number;
+}
+
+interface MovieLensUserProfile {
+    user_id: number;
+    rating_count: number;
+    tag_count: number;
+    average_rating: number | null;
+    first_activity: number | null;
+    last_activity: number | null;
+    genre_affinities: GenreAffinity[];
+    top_tags: TagUsage[];
+    ratings: Page<UserRating>;
+    tags: Page<UserTag>;
+}
+
+export {
+    type UserRating,
+    type UserTag,
+    type GenreAffinity,
+    type TagUsage,
+    type MovieLensUserProfile,
+}
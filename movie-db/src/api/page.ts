@@ -0,0 +1,18 @@
+interface PageRequest {
+    size?: number;
+    offset?: number;
+    cursor?: string | null;
+}
+
+interface Page<T> {
+    items: T[];
+    total: number;
+    next_cursor: string | null;
+    // Titres proches proposés quand la recherche ne renvoie aucun film
+    suggestions: string[];
+}
+
+export {
+    type PageRequest,
+    type Page,
+}
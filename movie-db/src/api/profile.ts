@@ -0,0 +1,62 @@
+import { invoke } from "@tauri-apps/api/core";
+import type { SqlUserCredentials } from "./login";
+
+interface PoolConfig {
+    min_connections: number;
+    max_connections: number;
+    connection_increment: number;
+}
+
+interface OracleProfile {
+    kind: "Oracle";
+    host: string;
+    port: number;
+    service_name?: string | null;
+    tns_alias?: string | null;
+    connect_descriptor?: string | null;
+}
+
+interface SqliteProfile {
+    kind: "Sqlite";
+    path: string;
+}
+
+interface ConnectionProfile {
+    name: string;
+    backend: OracleProfile | SqliteProfile;
+    pool?: PoolConfig;
+}
+
+async function listConnectionProfiles() {
+    return await invoke<ConnectionProfile[]>("list_connection_profiles");
+}
+
+async function addConnectionProfile(profile: ConnectionProfile) {
+    return await invoke("add_connection_profile", { profile });
+}
+
+async function editConnectionProfile(name: string, profile: ConnectionProfile) {
+    return await invoke("edit_connection_profile", { name, profile });
+}
+
+async function removeConnectionProfile(name: string) {
+    return await invoke("remove_connection_profile", { name });
+}
+
+// Renvoie le nom de l'utilisateur connecté si le profil est valide
+async function testConnectionProfile(profile: ConnectionProfile, user: SqlUserCredentials) {
+    return await invoke<string>("test_connection_profile", { profile, user });
+}
+
+export {
+    listConnectionProfiles,
+    addConnectionProfile,
+    editConnectionProfile,
+    removeConnectionProfile,
+    testConnectionProfile,
+
+    type PoolConfig,
+    type OracleProfile,
+    type SqliteProfile,
+    type ConnectionProfile,
+};
@@ -28,31 +28,116 @@ function filter_to_string(filter: SearchFilter) {
     }
 }
 
+// Tri explicite : chaque critère départage les égalités du précédent
+type SortField = "Title" | "Popularity" | "ReleaseDate" | "VoteAverage" | "VoteCount" | "Runtime" | "Revenue" | "WeightedRating";
 
-const genresList = [
-    "Action",
-    "Adventure",
-    "Animation",
-    "Comedy",
-    "Crime",
-    "Documentary",
-    "Drama",
-    "Family",
-    "Fantasy",
-    "History",
-    "Horror",
-    "Music",
-    "Mystery",
-    "Romance",
-    "ScienceFiction",
-    "Thriller",
-    "TVMovie",
-    "War",
-    "Western"
+type SortDirection = "Asc" | "Desc";
+
+interface SortOrder {
+    field: SortField;
+    direction: SortDirection;
+}
+
+// Genre du catalogue renvoyé par `get_genres`
+interface Genre {
+    genre_id: number;
+    name: string;
+    movie_count: number;
+}
+
+type GenreMatch = "Any" | "All";
+
+// Attributs normalisés parcourus avec `get_facet_values`
+type MovieFacet = "Keyword" | "Company" | "Country" | "Language";
+
+interface FacetValue {
+    id: number;
+    name: string;
+    movie_count: number;
+}
+
+interface ValueRange {
+    min: number | null;
+    max: number | null;
+}
+
+// Critères de la recherche avancée, un champ vide ne filtre pas
+interface SearchQuery {
+    title: string;
+    genres: number[];
+    genre_match: GenreMatch;
+    keywords: number[];
+    companies: number[];
+    countries: number[];
+    languages: number[];
+    release_year: ValueRange;
+    runtime: ValueRange;
+    min_vote_count: number | null;
+    vote_average: ValueRange;
+    original_language: string;
+    status: string;
+    include_adult: boolean;
+}
+
+const statusList = [
+    "Released",
+    "Post Production",
+    "In Production",
+    "Planned",
+    "Rumored",
+    "Canceled"
 ]
 
+function defaultSearchQuery(): SearchQuery {
+    return {
+        title: "",
+        genres: [],
+        genre_match: "Any",
+        keywords: [],
+        companies: [],
+        countries: [],
+        languages: [],
+        release_year: { min: null, max: null },
+        runtime: { min: null, max: null },
+        min_vote_count: null,
+        vote_average: { min: null, max: null },
+        original_language: "",
+        status: "",
+        include_adult: true,
+    };
+}
+
+// Tri des notes et des tags d'un film (`Rating` pour les notes seulement)
+type ActivitySortField = "Timestamp" | "Rating" | "User";
+
+interface ActivitySort {
+    field: ActivitySortField;
+    direction: SortDirection;
+}
+
+// Filtres des notes et des tags d'un film ; timestamp en secondes Unix
+interface ActivityFilter {
+    rating?: ValueRange;
+    tag?: string | null;
+    timestamp?: ValueRange;
+}
+
 export {
     SearchFilter,
-    genresList,
-    filter_to_string
+    statusList,
+    filter_to_string,
+    defaultSearchQuery,
+
+    type SortField,
+    type SortDirection,
+    type SortOrder,
+    type Genre,
+    type GenreMatch,
+    type MovieFacet,
+    type FacetValue,
+    type ValueRange,
+    type SearchQuery,
+    type ActivitySortField,
+    type ActivitySort,
+    type ActivityFilter,
 }
\ No newline at end of file
@@ -0,0 +1,55 @@
+import type { TagUsage } from "./movie_lens_user";
+
+// Substring et Words ignorent la casse ; Regex suit la syntaxe du crate regex ((?i) pour ignorer la casse)
+type TagPattern =
+    | { kind: "Substring"; text: string }
+    | { kind: "Regex"; pattern: string }
+    | { kind: "Words"; words: string[] };
+
+// Rewrite remplace chaque occurrence ; un tag vide après remplacement est supprimé
+type TagModerationAction =
+    | { kind: "Delete" }
+    | { kind: "Rewrite"; replacement: string };
+
+interface TagModeration {
+    pattern: TagPattern;
+    action: TagModerationAction;
+}
+
+// new_tag est null si le tag est supprimé
+interface TagModerationSample {
+    movie_id: number;
+    user_id: number;
+    timestamp: number;
+    tag: string;
+    new_tag: string | null;
+}
+
+interface TagModerationSummary {
+    matched_count: number;
+    deleted_count: number;
+    rewritten_count: number;
+    distinct_tags: number;
+    user_count: number;
+    movie_count: number;
+    top_tags: TagUsage[];
+}
+
+interface TagModerationPreview extends TagModerationSummary {
+    samples: TagModerationSample[];
+}
+
+interface TagModerationReport extends TagModerationSummary {
+    started_at: number;
+    duration_ms: number;
+}
+
+export {
+    type TagPattern,
+    type TagModerationAction,
+    type TagModeration,
+    type TagModerationSample,
+    type TagModerationSummary,
+    type TagModerationPreview,
+    type TagModerationReport,
+}
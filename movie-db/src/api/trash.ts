@@ -0,0 +1,29 @@
+type TrashKind = "User" | "Movie" | "Tag";
+
+// title : titre TMDB du film supprimé ou tagué ; tag : texte d'un tag supprimé
+// deleted_by est null pour une suppression faite hors de l'application en SQLite
+interface TrashItem {
+    deletion_id: number;
+    kind: TrashKind;
+    user_id: number | null;
+    movie_id: number | null;
+    title: string | null;
+    tag: string | null;
+    deleted_by: string | null;
+    deleted_at: number;
+    rating_count: number;
+    tag_count: number;
+}
+
+// Les notes et tags présents de nouveau depuis la suppression ne sont pas comptés
+interface TrashRestore {
+    deletion_id: number;
+    restored_ratings: number;
+    restored_tags: number;
+}
+
+export {
+    type TrashKind,
+    type TrashItem,
+    type TrashRestore,
+}